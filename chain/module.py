@@ -1,4 +1,5 @@
 import requests
+import ipaddress
 import json
 import queue
 import re
@@ -1231,7 +1232,7 @@ class Chain:
         key: str,
         name: str=None,
         url: str = None,
-        metadata: str = None,
+        metadata: dict = None,
         delegation_fee: int = None,
         validator_weight_fee = None,
         subnet = 2,
@@ -1254,9 +1255,9 @@ class Chain:
         delegation_fee = delegation_fee or module.get('stake_delegation_fee', 10)
         params = {
             "name": name,
-            "address": url,
+            "address": self.url2address(url),
             "stake_delegation_fee": delegation_fee,
-            "metadata": metadata,
+            "metadata": self.metadata2changes(metadata),
             'validator_weight_fee': validator_weight_fee,
             'netuid': subnet,
         }
@@ -1274,7 +1275,7 @@ class Chain:
         module_key : str = None , 
         name: str = None,
         key: Keypair = None,
-        metadata: str = 'NA',
+        metadata: dict = None,
         subnet: str = 2,
         wait_for_finalization = False,
         public = False,
@@ -1299,10 +1300,10 @@ class Chain:
             url = ip +':'+ port
         params = {
             "network_name": self.resolve_subnet_name(subnet),
-            "address":  url,
+            "address":  self.url2address(url),
             "name": name,
             "module_key": module_key,
-            "metadata": metadata,
+            "metadata": self.metadata2changes(metadata),
         }
         return  self.compose_call("register", params=params, key=key, wait_for_finalization=wait_for_finalization)
 
@@ -1337,14 +1338,14 @@ class Chain:
 
         return response
     
-    def register_subnet(self, name: str, metadata: str = None,  key: Keypair=None) -> ExtrinsicReceipt:
+    def register_subnet(self, name: str, metadata: dict = None,  key: Keypair=None) -> ExtrinsicReceipt:
         """
         Registers a new subnet in the network.
 
         Args:
             key (Keypair): The keypair used for registering the subnet.
             name (str): The name of the subnet to be registered.
-            metadata (str, optional): Additional metadata for the subnet. Defaults to None.
+            metadata (dict, optional): Metadata entries for the subnet, e.g. `{'website': ...}`. Defaults to None.
 
         Returns:
             ExtrinsicReceipt: A receipt of the subnet registration transaction.
@@ -1355,7 +1356,7 @@ class Chain:
 
         params = {
             "name": name,
-            "metadata": metadata,
+            "metadata": self.metadata2changes(metadata),
         }
         response = self.compose_call("register_subnet", params=params, key=key)
         return response
@@ -1444,7 +1445,7 @@ class Chain:
         assert any([k in original_params for k in params.keys()]), f'Invalid params {params.keys()}'
         params["netuid"] = subnet
         params['vote_mode'] = params.pop('governance_configuration')['vote_mode']
-        params["metadata"] = params.pop("metadata", None)
+        params["metadata"] = self.metadata2changes(params.pop("metadata", None))
         return self.compose_call(fn="update_subnet",params=params,key=key)
 
     def metadata(self, subnet=2) -> str:
@@ -2350,6 +2351,9 @@ class Chain:
                         ("MaxAllowedValidators", params),
                         ("ModuleBurnConfig", params),
                         ("SubnetMetadata", params),
+                        ("SubnetRegistrationMode", params),
+                        ("SubnetPruningStrategy", params),
+                        ("SubnetActivitySource", params),
                     ],
                     "GovernanceModule": [
                         ("SubnetGovernanceConfig", params),
@@ -2382,6 +2386,9 @@ class Chain:
                 "max_allowed_validators": bulk_query.get("MaxAllowedValidators", {}),
                 "module_burn_config": bulk_query.get("ModuleBurnConfig", {}),
                 "metadata": bulk_query.get("SubnetMetadata", {}),
+                "registration_mode": bulk_query.get("SubnetRegistrationMode", {}),
+                "pruning_strategy": bulk_query.get("SubnetPruningStrategy", {}),
+                "activity_source": bulk_query.get("SubnetActivitySource", {}),
             }
 
             results: dict[int, SubnetParamsWithEmission] = {}
@@ -2390,6 +2397,9 @@ class Chain:
                 'min_validator_stake': self.to_nanos(50_000),
                 'max_allowed_validators': 50,
                 'maximum_set_weight_calls_per_epoch': 30,
+                'registration_mode': 'Open',
+                'pruning_strategy': 'EmissionScore',
+                'activity_source': 'Weights',
             }
             subnet_map_keys = list(subnet_maps.keys())
             netuids = list(subnet_maps["name"].keys())
@@ -2465,6 +2475,7 @@ class Chain:
                     "proposal_reward_treasury_allocation": int(global_config["proposal_reward_treasury_allocation"]),
                     "max_proposal_reward_treasury_allocation": int(global_config["max_proposal_reward_treasury_allocation"]),
                     "proposal_reward_interval": int(global_config["proposal_reward_interval"]),
+                    "enactment_delay": int(global_config["enactment_delay"]),
                 },
             }
 
@@ -2732,14 +2743,14 @@ class Chain:
                                ).json()
         module = {**module['result']['stats'], **module['result']['params']}
         module['name'] = self.vec82str(module['name'])
-        module['url'] = self.vec82str(module.pop('address'))
+        module['url'] = self.address2url(module.pop('address'))
         module['dividends'] = module['dividends'] / U16_MAX
         module['incentive'] = module['incentive'] / U16_MAX
         module['stake_from'] = {k:self.format_amount(v, fmt=fmt) for k,v in module['stake_from'].items()}
         module['stake'] = sum([v / 10**9 for k,v in module['stake_from'].items() ])
         module['emission'] = self.format_amount(module['emission'], fmt=fmt)
         module['key'] = module.pop('controller', None)
-        module['metadata'] = self.vec82str(module.pop('metadata', []))
+        module['metadata'] = self.changes2metadata(module.pop('metadata', []))
         module['vote_staleness'] = (block or self.block()) - module['last_update']
         return module
     mod = module
@@ -2749,6 +2760,66 @@ class Chain:
         x = x or []
         return ''.join([chr(ch) for ch in x]).strip()
 
+    @staticmethod
+    def url2address(url: str) -> dict:
+        """
+        Converts an `<ip>:<port>`, `<host>:<port>` or multiaddr url into a `ModuleAddress`.
+        """
+        if url.startswith('/'):
+            return {'Multiaddr': url}
+        host, port = url.rsplit(':', 1)
+        host, port = host.strip('[]'), int(port)
+        try:
+            ip = ipaddress.ip_address(host)
+        except ValueError:
+            return {'Dns': {'host': host, 'port': port}}
+        kind = 'Ipv4' if ip.version == 4 else 'Ipv6'
+        return {kind: {'ip': list(ip.packed), 'port': port}}
+
+    @classmethod
+    def address2url(cls, address: dict) -> str:
+        """
+        Converts a `ModuleAddress` back into a url, the inverse of `url2address`.
+        """
+        if not address:
+            return ''
+        (kind, value), = address.items()
+        if kind == 'Multiaddr':
+            return cls.vec82str(value)
+        if kind == 'Dns':
+            return f"{cls.vec82str(value['host'])}:{value['port']}"
+        ip = ipaddress.ip_address(bytes(value['ip']))
+        host = str(ip) if ip.version == 4 else f'[{ip}]'
+        return f"{host}:{value['port']}"
+
+    METADATA_KEYS = {'ipfs_cid': 'IpfsCid', 'website': 'Website', 'api_schema': 'ApiSchema', 'version': 'Version'}
+
+    @classmethod
+    def metadata2changes(cls, metadata) -> list:
+        """
+        Converts `{key: value}` metadata into `MetadataChanges`, a `None` value clears the key.
+        A plain string is set under the `legacy` key.
+        """
+        if not metadata:
+            return []
+        if isinstance(metadata, list):  # already changes, or a queried map to write back as is
+            return metadata
+        if isinstance(metadata, str):
+            metadata = {'legacy': metadata}
+        return [(cls.METADATA_KEYS.get(k, {'Custom': k}), v) for k, v in metadata.items()]
+
+    @classmethod
+    def changes2metadata(cls, metadata) -> dict:
+        """
+        Converts a `MetadataMap` into `{key: value}`, the inverse of `metadata2changes`.
+        """
+        names = {v: k for k, v in cls.METADATA_KEYS.items()}
+        result = {}
+        for key, value in (metadata or []):
+            name = names[key] if isinstance(key, str) else cls.vec82str(key['Custom'])
+            result[name] = cls.vec82str(value)
+        return result
+
     def netuids(self,  update=False, block=None) -> Dict[int, str]:
         return list(self.netuid2subnet( update=update, block=block).keys())
 
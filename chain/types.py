@@ -35,6 +35,7 @@ class DisplayGovernanceConfiguration(TypedDict):
     proposal_reward_treasury_allocation: float
     max_proposal_reward_treasury_allocation: float
     proposal_reward_interval: int
+    enactment_delay: int
 
 
 class GovernanceConfiguration(TypedDict):
@@ -44,6 +45,17 @@ class GovernanceConfiguration(TypedDict):
     proposal_reward_treasury_allocation: float
     max_proposal_reward_treasury_allocation: int
     proposal_reward_interval: int
+    enactment_delay: int
+
+
+class BurnRefundCurve(TypedDict):
+    initial_refund: int
+    decay_period: int
+
+
+class BurnDestination(TypedDict):
+    treasury: int
+    founder: int
 
 
 class DisplayBurnConfiguration(TypedDict):
@@ -53,6 +65,8 @@ class DisplayBurnConfiguration(TypedDict):
     target_registrations_interval: int
     target_registrations_per_interval: int
     max_registrations_per_interval: int
+    refund_curve: BurnRefundCurve | None
+    destination: BurnDestination
 
 from dataclasses import dataclass
 
@@ -70,6 +84,8 @@ class BurnConfiguration(TypedDict):
     target_registrations_interval: int
     target_registrations_per_interval: int
     max_registrations_per_interval: int
+    refund_curve: BurnRefundCurve | None
+    destination: BurnDestination
 
 
 class NetworkParams(TypedDict):
@@ -140,6 +156,9 @@ class SubnetParams(TypedDict):
     max_allowed_validators: int
     module_burn_config: BurnConfiguration
     subnet_metadata: str 
+    registration_mode: str
+    pruning_strategy: str
+    activity_source: str
 
 
 class DisplaySubnetParams(TypedDict):
@@ -161,6 +180,9 @@ class DisplaySubnetParams(TypedDict):
     max_allowed_validators: int 
     module_burn_config: DisplayBurnConfiguration
     subnet_metadata: str
+    registration_mode: str
+    pruning_strategy: str
+    activity_source: str
     emission: float
 
 # redundant "TypedDict" inheritance because of pdoc warns.
@@ -177,7 +199,7 @@ class ModuleInfo(TypedDict):
     uid: int
     key: Ss58Address
     name: str
-    address: str  # "<ip>:<port>"
+    address: dict[str, Any]  # ModuleAddress, e.g. {"Ipv4": {"ip": [0, 0, 0, 0], "port": 8888}}
     emission: int
     incentive: int
     dividends: int
@@ -186,7 +208,7 @@ class ModuleInfo(TypedDict):
     last_update: int  # block number
     stake: int
     delegation_fee: int
-    metadata: str
+    metadata: dict[str, str]
 
 
 class ModuleInfoWithBalance(ModuleInfo):
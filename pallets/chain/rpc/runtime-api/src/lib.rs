@@ -27,12 +27,22 @@ pub struct ModuleStats {
     pub weights: Vec<(u16, u16)>, // Vec of (uid, weight)
 }
 
+#[derive(Decode, Encode, PartialEq, Eq, Clone, Debug, TypeInfo, Serialize, Deserialize)]
+pub enum ModuleAddress {
+    Ipv4 { ip: [u8; 4], port: u16 },
+    Ipv6 { ip: [u8; 16], port: u16 },
+    Dns { host: Vec<u8>, port: u16 },
+    Multiaddr(Vec<u8>),
+}
+
 #[derive(Decode, Encode, PartialEq, Eq, Clone, Debug, TypeInfo, Serialize, Deserialize)]
 pub struct ModuleParams {
     pub name: Vec<u8>,
-    pub address: Vec<u8>,
+    /// `None` if the module's legacy address could not be migrated.
+    pub address: Option<ModuleAddress>,
     pub delegation_fee: Percent,
-    pub metadata: Option<Vec<u8>>,
+    /// The metadata entries, keyed by their name, e.g. `ipfs_cid`.
+    pub metadata: Vec<(Vec<u8>, Vec<u8>)>,
 }
 
 #[derive(Decode, Encode, PartialEq, Eq, Clone, Debug, TypeInfo, Serialize, Deserialize)]
@@ -41,8 +51,19 @@ pub struct ModuleInfo {
     pub stats: ModuleStats,
 }
 
+/// Subnet parameters announced by the founder, waiting for the notice period to be over.
+#[derive(Decode, Encode, PartialEq, Eq, Clone, Debug, TypeInfo, Serialize, Deserialize)]
+pub struct PendingSubnetParams {
+    /// The SCALE encoded `SubnetParams` of the chain pallet.
+    pub params: Vec<u8>,
+    pub apply_block: u64,
+}
+
 sp_api::decl_runtime_apis! {
     pub trait ChainRuntimeApi {
         fn get_module_info(key: AccountId, netuid: u16) -> ModuleInfo;
+        fn get_subnet_generation(netuid: u16) -> u64;
+        fn get_uid_for_name(netuid: u16, name: Vec<u8>) -> Option<u16>;
+        fn get_pending_subnet_params(netuid: u16) -> Option<PendingSubnetParams>;
     }
 }
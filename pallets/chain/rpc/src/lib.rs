@@ -10,7 +10,7 @@ use sp_runtime::{
     MultiSignature,
 };
 use std::sync::Arc;
-use chain_runtime_api::ModuleInfo;
+use chain_runtime_api::{ModuleInfo, PendingSubnetParams};
 pub use chain_runtime_api::ChainRuntimeApi;
 
 type Signature = MultiSignature;
@@ -30,6 +30,24 @@ pub trait ChainApi<BlockHash> {
         netuid: u16,
         at: Option<BlockHash>,
     ) -> RpcResult<ModuleInfo>;
+
+    #[method(name = "chain_getSubnetGeneration")]
+    fn get_subnet_generation(&self, netuid: u16, at: Option<BlockHash>) -> RpcResult<u64>;
+
+    #[method(name = "chain_getUidForName")]
+    fn get_uid_for_name(
+        &self,
+        netuid: u16,
+        name: Vec<u8>,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Option<u16>>;
+
+    #[method(name = "chain_getPendingSubnetParams")]
+    fn get_pending_subnet_params(
+        &self,
+        netuid: u16,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Option<PendingSubnetParams>>;
 }
 
 pub struct ChainPallet<C, Block> {
@@ -64,6 +82,40 @@ where
         let value = api.get_module_info(at, key, netuid).map_err(runtime_error_into_rpc_err);
         Ok(value.unwrap())
     }
+
+    fn get_subnet_generation(
+        &self,
+        netuid: u16,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<u64> {
+        let api = self.client.runtime_api();
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+        api.get_subnet_generation(at, netuid).map_err(runtime_error_into_rpc_err)
+    }
+
+    fn get_uid_for_name(
+        &self,
+        netuid: u16,
+        name: Vec<u8>,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Option<u16>> {
+        let api = self.client.runtime_api();
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+        api.get_uid_for_name(at, netuid, name).map_err(runtime_error_into_rpc_err)
+    }
+
+    fn get_pending_subnet_params(
+        &self,
+        netuid: u16,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Option<PendingSubnetParams>> {
+        let api = self.client.runtime_api();
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+        api.get_pending_subnet_params(at, netuid).map_err(runtime_error_into_rpc_err)
+    }
 }
 
 const RUNTIME_ERROR: i32 = 1;
@@ -5,6 +5,7 @@ use frame_benchmarking::{account, benchmarks};
 use frame_system::RawOrigin;
 pub use pallet::*;
 use sp_arithmetic::per_things::Percent;
+use sp_core::{hashing::blake2_256, H256};
 use sp_std::vec::Vec;
 
 fn register_mock<T: Config>(
@@ -12,7 +13,10 @@ fn register_mock<T: Config>(
     module_key: T::AccountId,
     name: Vec<u8>,
 ) -> Result<(), &'static str> {
-    let address = "test".as_bytes().to_vec();
+    let address = ModuleAddress::Dns {
+        host: "test".as_bytes().to_vec(),
+        port: 30333,
+    };
     let network = "testnet".as_bytes().to_vec();
 
     let enough_stake = 10000000000000u64;
@@ -20,8 +24,8 @@ fn register_mock<T: Config>(
         &key,
         ChainMod::<T>::u64_to_balance(SubnetBurn::<T>::get() + enough_stake).unwrap(),
     );
-    let network_metadata = Some("networkmetadata".as_bytes().to_vec());
-    let metadata = Some("metadata".as_bytes().to_vec());
+    let network_metadata = vec![(MetadataKey::Website, Some(b"networkmetadata".to_vec()))];
+    let metadata = vec![(MetadataKey::Website, Some("metadata".as_bytes().to_vec()))];
     let _ = ChainMod::<T>::register_subnet(
         RawOrigin::Signed(key.clone()).into(),
         network.clone(),
@@ -168,9 +172,9 @@ benchmarks! {
         let _ = ChainMod::<T>::register_subnet(
             RawOrigin::Signed(key.clone()).into(),
             "test".as_bytes().to_vec(),
-           None,
+           Vec::new(),
         );
-    }: register(RawOrigin::Signed(key.clone()), "test".as_bytes().to_vec(), "test".as_bytes().to_vec(), "test".as_bytes().to_vec(),  module_key.clone(), Some("metadata".as_bytes().to_vec()))
+    }: register(RawOrigin::Signed(key.clone()), "test".as_bytes().to_vec(), "test".as_bytes().to_vec(), ModuleAddress::Dns { host: "test".as_bytes().to_vec(), port: 30333 }, module_key.clone(), vec![(MetadataKey::Website, Some("metadata".as_bytes().to_vec()))])
 
     // 8
     deregister {
@@ -189,9 +193,9 @@ benchmarks! {
         let stake = 100000000000000u64;
         register_mock::<T>(caller.clone(), caller.clone(), "test".as_bytes().to_vec())?;
         let name = "updated_name".as_bytes().to_vec();
-        let address = "updated_address".as_bytes().to_vec();
+        let address = ModuleAddress::Dns { host: "updated-address".as_bytes().to_vec(), port: 30333 };
         let delegation_fee = Some(Percent::from_percent(5));
-        let metadata = Some("updated_metadata".as_bytes().to_vec());
+        let metadata = vec![(MetadataKey::Website, Some("updated_metadata".as_bytes().to_vec()))];
         let netuid = ChainMod::<T>::get_netuid_for_name("testnet".as_bytes()).unwrap();
     }: update_module(RawOrigin::Signed(caller), netuid, name, address, delegation_fee, delegation_fee, metadata)
 
@@ -208,7 +212,7 @@ benchmarks! {
         params.founder,
         params.founder_share,
         params.name.clone(),
-        params.metadata.clone(),
+        vec![(MetadataKey::Website, Some("testmetadata".as_bytes().to_vec()))],
         params.immunity_period,
         params.incentive_ratio,
         params.max_allowed_uids,
@@ -222,6 +226,9 @@ benchmarks! {
         params.module_burn_config,
         params.min_validator_stake,
         params.max_allowed_validators,
+        params.registration_mode,
+        params.pruning_strategy,
+        params.activity_source,
     )
     // 11
 
@@ -233,6 +240,147 @@ benchmarks! {
             &key,
             ChainMod::<T>::u64_to_balance(stake + SubnetBurn::<T>::get() + 2000).unwrap(),
         );
-    }: register_subnet(RawOrigin::Signed(key.clone()), "testnet".as_bytes().to_vec(), Some(b"testmetadata".to_vec()))
+    }: register_subnet(RawOrigin::Signed(key.clone()), "testnet".as_bytes().to_vec(), vec![(MetadataKey::Website, Some(b"testmetadata".to_vec()))])
+
+    // 13
+    commit_registration {
+        let key: T::AccountId = account("Alice", 0, 1);
+        let stake = 100000000000000u64;
+        ChainMod::<T>::add_balance_to_account(
+            &key,
+            ChainMod::<T>::u64_to_balance(stake + SubnetBurn::<T>::get() + 2000).unwrap(),
+        );
+        let _ = ChainMod::<T>::register_subnet(
+            RawOrigin::Signed(key.clone()).into(),
+            "test".as_bytes().to_vec(),
+            Vec::new(),
+        );
+        let netuid = ChainMod::<T>::get_netuid_for_name("test".as_bytes()).unwrap();
+    }: commit_registration(RawOrigin::Signed(key.clone()), netuid, H256::repeat_byte(1))
+
+    // 14
+    reveal_registration {
+        let key: T::AccountId = account("Alice", 0, 1);
+        let module_key: T::AccountId = account("ModuleKey", 0, 2);
+        let stake = 100000000000000u64;
+        ChainMod::<T>::add_balance_to_account(
+            &key,
+            ChainMod::<T>::u64_to_balance(stake + SubnetBurn::<T>::get() + 2000).unwrap(),
+        );
+        let _ = ChainMod::<T>::register_subnet(
+            RawOrigin::Signed(key.clone()).into(),
+            "test".as_bytes().to_vec(),
+            Vec::new(),
+        );
+        let netuid = ChainMod::<T>::get_netuid_for_name("test".as_bytes()).unwrap();
+        let name = "test".as_bytes().to_vec();
+        let address = ModuleAddress::Dns { host: "test".as_bytes().to_vec(), port: 30333 };
+        let metadata = vec![(MetadataKey::Website, Some("metadata".as_bytes().to_vec()))];
+        let salt = [7u8; 32];
+        let commitment = H256(blake2_256(
+            &(&key, netuid, &name, &address, &module_key, &metadata, salt).encode(),
+        ));
+        ChainMod::<T>::commit_registration(RawOrigin::Signed(key.clone()).into(), netuid, commitment)?;
+        frame_system::Pallet::<T>::set_block_number(frame_system::Pallet::<T>::block_number() + 1u32.into());
+    }: reveal_registration(RawOrigin::Signed(key.clone()), netuid, name, address, module_key.clone(), metadata, salt)
+
+    // 15
+    add_to_allowlist {
+        let caller: T::AccountId = account("Alice", 0, 1);
+        let module_key: T::AccountId = account("ModuleKey", 0, 2);
+        register_mock::<T>(caller.clone(), caller.clone(), "test".as_bytes().to_vec())?;
+        let netuid = ChainMod::<T>::get_netuid_for_name("testnet".as_bytes()).unwrap();
+    }: add_to_allowlist(RawOrigin::Signed(caller), netuid, module_key)
+
+    // 16
+    remove_from_allowlist {
+        let caller: T::AccountId = account("Alice", 0, 1);
+        let module_key: T::AccountId = account("ModuleKey", 0, 2);
+        register_mock::<T>(caller.clone(), caller.clone(), "test".as_bytes().to_vec())?;
+        let netuid = ChainMod::<T>::get_netuid_for_name("testnet".as_bytes()).unwrap();
+        ChainMod::<T>::add_to_allowlist(RawOrigin::Signed(caller.clone()).into(), netuid, module_key.clone())?;
+    }: remove_from_allowlist(RawOrigin::Signed(caller), netuid, module_key)
+
+    // 17
+    register_multiple {
+        let r in 1 .. MAX_MULTIPLE_REGISTRATIONS;
+
+        let key: T::AccountId = account("Alice", 0, 1);
+        let module_key: T::AccountId = account("ModuleKey", 0, 2);
+        let stake = 100000000000000u64;
+        ChainMod::<T>::add_balance_to_account(
+            &key,
+            ChainMod::<T>::u64_to_balance(stake + r as u64 * SubnetBurn::<T>::get() + 2000).unwrap(),
+        );
+        MaxRegistrationsPerBlock::<T>::set(u16::MAX);
+        let networks: Vec<_> = (0..r as u8)
+            .map(|i| [b"test".as_slice(), &[b'a'.saturating_add(i)]].concat())
+            .collect();
+        for network in &networks {
+            let _ = ChainMod::<T>::register_subnet(
+                RawOrigin::Signed(key.clone()).into(),
+                network.clone(),
+                Vec::new(),
+            );
+        }
+        let registrations = networks
+            .into_iter()
+            .map(|network| {
+                let address = ModuleAddress::Dns { host: "test".as_bytes().to_vec(), port: 30333 };
+                (network, "test".as_bytes().to_vec(), address, vec![(MetadataKey::Website, Some("metadata".as_bytes().to_vec()))])
+            })
+            .collect::<Vec<_>>()
+            .try_into()
+            .unwrap();
+    }: register_multiple(RawOrigin::Signed(key.clone()), module_key.clone(), registrations)
+
+    // 18
+    propose_founder_transfer {
+        let caller: T::AccountId = account("Alice", 0, 1);
+        let new_founder: T::AccountId = account("Bob", 0, 2);
+        register_mock::<T>(caller.clone(), caller.clone(), "test".as_bytes().to_vec())?;
+        let netuid = ChainMod::<T>::get_netuid_for_name("testnet".as_bytes()).unwrap();
+    }: propose_founder_transfer(RawOrigin::Signed(caller), netuid, new_founder)
+
+    // 19
+    accept_founder_transfer {
+        let caller: T::AccountId = account("Alice", 0, 1);
+        let new_founder: T::AccountId = account("Bob", 0, 2);
+        register_mock::<T>(caller.clone(), caller.clone(), "test".as_bytes().to_vec())?;
+        let netuid = ChainMod::<T>::get_netuid_for_name("testnet".as_bytes()).unwrap();
+        ChainMod::<T>::propose_founder_transfer(RawOrigin::Signed(caller).into(), netuid, new_founder.clone())?;
+    }: accept_founder_transfer(RawOrigin::Signed(new_founder), netuid)
+
+    // 20
+    dissolve_subnet {
+        let caller: T::AccountId = account("Alice", 0, 1);
+        register_mock::<T>(caller.clone(), caller.clone(), "test".as_bytes().to_vec())?;
+        let netuid = ChainMod::<T>::get_netuid_for_name("testnet".as_bytes()).unwrap();
+    }: dissolve_subnet(RawOrigin::Signed(caller), netuid)
+
+    // 21
+    heartbeat {
+        let caller: T::AccountId = account("Alice", 0, 1);
+        register_mock::<T>(caller.clone(), caller.clone(), "test".as_bytes().to_vec())?;
+        let netuid = ChainMod::<T>::get_netuid_for_name("testnet".as_bytes()).unwrap();
+        frame_system::Pallet::<T>::set_block_number(frame_system::Pallet::<T>::block_number() + u32::from(Tempo::<T>::get(netuid)).into());
+    }: heartbeat(RawOrigin::Signed(caller), netuid)
+
+    // 22
+    approve_module_registration {
+        let caller: T::AccountId = account("Alice", 0, 1);
+        let module_key: T::AccountId = account("ModuleKey", 0, 2);
+        register_mock::<T>(caller.clone(), caller.clone(), "test".as_bytes().to_vec())?;
+        let netuid = ChainMod::<T>::get_netuid_for_name("testnet".as_bytes()).unwrap();
+    }: approve_module_registration(RawOrigin::Signed(caller), netuid, module_key)
+
+    // 23
+    revoke_module_registration_approval {
+        let caller: T::AccountId = account("Alice", 0, 1);
+        let module_key: T::AccountId = account("ModuleKey", 0, 2);
+        register_mock::<T>(caller.clone(), caller.clone(), "test".as_bytes().to_vec())?;
+        let netuid = ChainMod::<T>::get_netuid_for_name("testnet".as_bytes()).unwrap();
+        ChainMod::<T>::approve_module_registration(RawOrigin::Signed(caller.clone()).into(), netuid, module_key.clone())?;
+    }: revoke_module_registration_approval(RawOrigin::Signed(caller), netuid, module_key)
 
 }
@@ -5,7 +5,9 @@
 use frame_support::{
     dispatch, ensure,
     pallet_macros::import_section,
-    traits::{tokens::WithdrawReasons, ConstU32, Currency, ExistenceRequirement},
+    traits::{
+        tokens::WithdrawReasons, ConstU32, Currency, ExistenceRequirement, ReservableCurrency,
+    },
     PalletId,
 };
 
@@ -31,7 +33,9 @@ mod benchmarking;
 // ---------------------------------
 pub mod macros;
 pub mod math;
+pub mod pause;
 pub mod network {
+    pub mod deposit;
     pub mod module;
     pub mod registration;
     pub mod staking;
@@ -40,6 +44,7 @@ pub mod network {
 pub mod params {
     pub mod burn;
     pub mod global;
+    pub mod metadata;
     pub mod module;
     pub mod subnet;
 }
@@ -49,11 +54,22 @@ pub mod rpc;
 pub mod selections;
 pub mod weights;
 
-pub use crate::params::{
-    burn::{BurnType, GeneralBurnConfiguration},
-    global::GlobalParams,
-    module::{ModuleChangeset, ModuleParams},
-    subnet::{DefaultSubnetParams, SubnetChangeset, SubnetParams},
+pub use crate::{
+    network::{
+        registration::{MultipleRegistrations, RegistrationCommit, MAX_MULTIPLE_REGISTRATIONS},
+        subnet::SubnetState,
+    },
+    params::{
+        burn::{BurnDestination, BurnRefundCurve, BurnType, GeneralBurnConfiguration},
+        global::GlobalParams,
+        metadata::{MetadataChanges, MetadataKey, MetadataMap, MetadataOwner},
+        module::{canonical_module_name, ModuleAddress, ModuleChangeset, ModuleParams},
+        subnet::{
+            ActivitySource, DefaultSubnetParams, PruningStrategy, RegistrationMode,
+            SubnetChangeset, SubnetParams,
+        },
+    },
+    pause::PausableOperation,
 };
 use selections::{config, dispatches, errors, events, genesis, hooks};
 
@@ -77,7 +93,7 @@ pub mod pallet {
     use frame_system::pallet_prelude::*;
     use pallet_governance_api::{GovernanceConfiguration, VoteMode};
     use sp_arithmetic::per_things::Percent;
-    use sp_core::{ConstU16, ConstU64, ConstU8};
+    use sp_core::{ConstU16, ConstU64, ConstU8, H256};
     pub use sp_std::{vec, vec::Vec};
     use substrate_fixed::types::I64F64;
 
@@ -87,10 +103,10 @@ pub mod pallet {
     pub type AccountIdOf<T> = <T as frame_system::Config>::AccountId;
 
     #[cfg(feature = "testnet")]
-    const STORAGE_VERSION: StorageVersion = StorageVersion::new(34);
+    const STORAGE_VERSION: StorageVersion = StorageVersion::new(41);
 
     #[cfg(not(feature = "testnet"))]
-    const STORAGE_VERSION: StorageVersion = StorageVersion::new(15);
+    const STORAGE_VERSION: StorageVersion = StorageVersion::new(22);
 
     #[pallet::pallet]
     #[pallet::storage_version(STORAGE_VERSION)]
@@ -105,9 +121,14 @@ pub mod pallet {
             Uids,
             Keys,
             Name,
+            ModuleNameIndex,
             Address,
             Metadata,
+            ModuleStorageDeposit,
             RegistrationBlock,
+            ModuleAllowlist,
+            ModuleRegistrationApprovals,
+            RegistrationBurnEscrow,
         },
         maps: {
             BondsMovingAverage: u64 = 900_000,
@@ -122,6 +143,7 @@ pub mod pallet {
             MaximumSetWeightCallsPerEpoch,
             SubnetNames,
             SubnetMetadata,
+            SubnetStorageDeposit,
             N,
             Founder,
             IncentiveRatio: u16 = 50,
@@ -144,7 +166,14 @@ pub mod pallet {
             Dividends,
             Emission,
             LastUpdate,
-            SubnetRegistrationBlock
+            SubnetRegistrationBlock,
+            SubnetRegistrationMode,
+            SubnetPruningStrategy,
+            SubnetActivitySource,
+            LastHeartbeat,
+            PendingFounder,
+            SubnetStates,
+            PendingSubnetParams
         }
     );
 
@@ -158,6 +187,7 @@ pub mod pallet {
             Incentive: u64 = 0,
             Dividends: u64 = 0,
             LastUpdate: u64 = Pallet::<T>::get_current_block_number(),
+            LastHeartbeat: u64 = Pallet::<T>::get_current_block_number(),
             Rank: u64 = 0,
             Trust: u64 = 0,
             ValidatorPermits: bool = false,
@@ -170,7 +200,7 @@ pub mod pallet {
             },
             required: {
                 RegistrationBlock: u64 = Pallet::<T>::get_current_block_number(),
-                Address: Vec<u8> = Vec::<u8>::new(),
+                Address,
                 Name: Vec<u8> = Vec::<u8>::new(),
                 Bonds: Vec<(u16, u16)> = Vec::<(u16, u16)>::new(),
             }
@@ -183,8 +213,9 @@ pub mod pallet {
         // Put here every module-related double map, that has no uid association. first key is netuid, second key is key of module (not uid!)
         key_only_storages: {
             SetWeightCallsPerEpoch: u16,
-            Metadata: Vec<u8>,
-            WeightSettingDelegation: DelegationInfo<T::AccountId>
+            Metadata: MetadataMap,
+            WeightSettingDelegation: DelegationInfo<T::AccountId>,
+            RegistrationBurnEscrow: (T::AccountId, u64)
         }
     );
 
@@ -237,8 +268,13 @@ pub mod pallet {
     pub type SubnetNames<T: Config> = StorageMap<_, Identity, u16, Vec<u8>, ValueQuery>;
 
     #[pallet::storage]
-    pub type SubnetMetadata<T: Config> =
-        StorageMap<_, Identity, u16, BoundedVec<u8, ConstU32<120>>>;
+    pub type SubnetMetadata<T: Config> = StorageMap<_, Identity, u16, MetadataMap>;
+
+    /// The deposit reserved for the subnet name and metadata, along with the account that paid
+    /// it. Subnets registered before deposits existed have none until their name or metadata
+    /// changes
+    #[pallet::storage]
+    pub type SubnetStorageDeposit<T: Config> = StorageMap<_, Identity, u16, (T::AccountId, u64)>;
 
     #[pallet::storage]
     pub type N<T> = StorageMap<_, Identity, u16, u16, ValueQuery>;
@@ -252,6 +288,52 @@ pub mod pallet {
     pub type Founder<T: Config> =
         StorageMap<_, Identity, u16, T::AccountId, ValueQuery, DefaultKey<T>>;
 
+    /// Account the subnet is being handed over to, and the last block it can accept the transfer
+    #[pallet::storage]
+    pub type PendingFounder<T: Config> = StorageMap<_, Identity, u16, (T::AccountId, u64)>;
+
+    /// Number of blocks a pending founder has to accept the subnet transfer
+    #[pallet::storage]
+    pub type FounderTransferPeriod<T> = StorageValue<_, u64, ValueQuery, ConstU64<75_600>>;
+
+    /// Whether the subnet is running or winding down
+    #[pallet::storage]
+    pub type SubnetStates<T> = StorageMap<_, Identity, u16, SubnetState, ValueQuery>;
+
+    /// Number of blocks a dissolving subnet keeps paying out before it is removed
+    #[pallet::storage]
+    pub type SubnetDissolutionPeriod<T> = StorageValue<_, u64, ValueQuery, ConstU64<10_800>>;
+
+    /// Subnet parameters announced by the founder, and the block they apply at
+    #[pallet::storage]
+    pub type PendingSubnetParams<T: Config> = StorageMap<_, Identity, u16, (SubnetParams<T>, u64)>;
+
+    /// Number of blocks changes to the subnet economics are announced before they apply
+    #[pallet::storage]
+    pub type SubnetParamsNoticePeriod<T> = StorageValue<_, u64, ValueQuery, ConstU64<10_800>>;
+
+    /// Decides who can register modules on the subnet
+    #[pallet::storage]
+    pub type SubnetRegistrationMode<T> = StorageMap<_, Identity, u16, RegistrationMode, ValueQuery>;
+
+    /// Decides which module is evicted when a registration arrives on the full subnet
+    #[pallet::storage]
+    pub type SubnetPruningStrategy<T> = StorageMap<_, Identity, u16, PruningStrategy, ValueQuery>;
+
+    /// Decides what keeps modules of the subnet active
+    #[pallet::storage]
+    pub type SubnetActivitySource<T> = StorageMap<_, Identity, u16, ActivitySource, ValueQuery>;
+
+    /// Module keys the subnet founder allows to register on allowlist subnets
+    #[pallet::storage]
+    pub type ModuleAllowlist<T: Config> =
+        StorageDoubleMap<_, Identity, u16, Identity, T::AccountId, ()>;
+
+    /// Module keys the subnet founder approved to register once on founder approval subnets
+    #[pallet::storage]
+    pub type ModuleRegistrationApprovals<T: Config> =
+        StorageDoubleMap<_, Identity, u16, Identity, T::AccountId, ()>;
+
     #[pallet::storage]
     pub type IncentiveRatio<T: Config> =
         StorageMap<_, Identity, u16, u16, ValueQuery, IncentiveRatioDefaultValue>;
@@ -260,6 +342,12 @@ pub mod pallet {
     pub type ModuleBurnConfig<T: Config> =
         StorageMap<_, Identity, u16, GeneralBurnConfiguration<T>, ValueQuery>;
 
+    /// The refundable share of each module registration burn, held as reserved balance on the
+    /// account that paid it
+    #[pallet::storage]
+    pub type RegistrationBurnEscrow<T: Config> =
+        StorageDoubleMap<_, Identity, u16, Identity, T::AccountId, (T::AccountId, u64)>;
+
     #[pallet::storage]
     pub type RegistrationsThisInterval<T: Config> = StorageMap<_, Identity, u16, u16, ValueQuery>;
 
@@ -321,13 +409,29 @@ pub mod pallet {
     pub type Name<T: Config> =
         StorageDoubleMap<_, Twox64Concat, u16, Twox64Concat, u16, Vec<u8>, ValueQuery>;
 
+    /// The uid of every module name on a subnet, keyed by the canonical form of the name.
+    #[pallet::storage]
+    pub type ModuleNameIndex<T: Config> =
+        StorageDoubleMap<_, Identity, u16, Blake2_128Concat, Vec<u8>, u16>;
+
     #[pallet::storage]
     pub type Address<T: Config> =
-        StorageDoubleMap<_, Twox64Concat, u16, Twox64Concat, u16, Vec<u8>, ValueQuery>;
+        StorageDoubleMap<_, Twox64Concat, u16, Twox64Concat, u16, ModuleAddress>;
 
     #[pallet::storage]
     pub type Metadata<T: Config> =
-        StorageDoubleMap<_, Twox64Concat, u16, Twox64Concat, T::AccountId, Vec<u8>>;
+        StorageDoubleMap<_, Twox64Concat, u16, Twox64Concat, T::AccountId, MetadataMap>;
+
+    /// The deposit reserved for the module name, address and metadata, along with the account
+    /// that paid it. Modules registered before deposits existed have none until they are updated
+    #[pallet::storage]
+    pub type ModuleStorageDeposit<T: Config> =
+        StorageDoubleMap<_, Twox64Concat, u16, Twox64Concat, T::AccountId, (T::AccountId, u64)>;
+
+    /// Deposit reserved per byte a module or subnet stores, released when the module or subnet
+    /// is removed
+    #[pallet::storage]
+    pub type StorageDepositPerByte<T> = StorageValue<_, u64, ValueQuery, ConstU64<1_000_000>>;
 
     #[pallet::storage]
     #[pallet::getter(fn get_incentive_for)]
@@ -348,6 +452,10 @@ pub mod pallet {
     #[pallet::getter(fn get_last_update_for)]
     pub type LastUpdate<T: Config> = StorageMap<_, Identity, u16, Vec<u64>, ValueQuery>;
 
+    /// Block of the last heartbeat of each module, indexed by uid
+    #[pallet::storage]
+    pub type LastHeartbeat<T> = StorageMap<_, Identity, u16, Vec<u64>, ValueQuery>;
+
     #[pallet::storage]
     pub type SubnetRegistrationBlock<T: Config> = StorageMap<_, Identity, u16, u64>;
 
@@ -400,6 +508,30 @@ pub mod pallet {
     #[pallet::storage]
     pub type MaxRegistrationsPerBlock<T> = StorageValue<_, u16, ValueQuery, ConstU16<10>>;
 
+    /// Pending registration commitments, awaiting their reveal
+    #[pallet::storage]
+    pub type RegistrationCommits<T: Config> =
+        StorageMap<_, Identity, H256, RegistrationCommit<T::AccountId>>;
+
+    /// Maps the block a registration commitment expires at to the commitment
+    #[pallet::storage]
+    pub type RegistrationCommitExpiries<T> =
+        StorageDoubleMap<_, Identity, u64, Identity, H256, (), ValueQuery>;
+
+    /// Number of blocks a registration commitment can be revealed in
+    #[pallet::storage]
+    pub type RegistrationRevealPeriod<T> = StorageValue<_, u64, ValueQuery, ConstU64<100>>;
+
+    #[pallet::type_value]
+    pub fn DefaultRegistrationCommitFee<T: Config>() -> Percent {
+        Percent::from_percent(10)
+    }
+
+    /// Share of the burn kept when a registration commitment is not revealed in time
+    #[pallet::storage]
+    pub type RegistrationCommitFee<T> =
+        StorageValue<_, Percent, ValueQuery, DefaultRegistrationCommitFee<T>>;
+
     // --- Staking Storage ---
 
     /// Maps (from_account, to_account) to stake amount
@@ -422,6 +554,23 @@ pub mod pallet {
     #[pallet::storage]
     pub type SubnetGaps<T> = StorageValue<_, BTreeSet<u16>, ValueQuery>;
 
+    /// Removed subnets whose storage is still being cleaned up. Their IDs are only added to
+    /// `SubnetGaps` once the cleanup finishes.
+    #[pallet::storage]
+    pub type TombstonedSubnets<T> = StorageValue<_, BTreeSet<u16>, ValueQuery>;
+
+    /// Block at which a subnet gap became available
+    #[pallet::storage]
+    pub type SubnetGapFreedAt<T> = StorageMap<_, Identity, u16, u64>;
+
+    /// Number of blocks a freed netuid has to wait before a new subnet can take it
+    #[pallet::storage]
+    pub type NetuidReuseCooldown<T> = StorageValue<_, u64, ValueQuery, ConstU64<10_800>>;
+
+    /// Increases every time a new subnet takes the netuid, so clients can tell such subnets apart
+    #[pallet::storage]
+    pub type SubnetGeneration<T> = StorageMap<_, Identity, u16, u64, ValueQuery>;
+
     /// Minimum share percentage for subnet founders
     #[pallet::storage]
     pub type FloorFounderShare<T: Config> = StorageValue<_, u8, ValueQuery, ConstU8<8>>;
@@ -469,6 +618,10 @@ pub mod pallet {
     pub type WeightSettingDelegation<T: Config> =
         StorageDoubleMap<_, Identity, u16, Identity, T::AccountId, T::AccountId>;
 
+    /// Operations halted by an emergency pause, mapped to the block the pause expires at
+    #[pallet::storage]
+    pub type PausedOperations<T: Config> = StorageMap<_, Identity, PausableOperation, u64>;
+
     #[pallet::storage]
     pub type Bridged<T: Config> = StorageMap<_, Identity, T::AccountId, u64, ValueQuery>;
     // --- Module Fees ---
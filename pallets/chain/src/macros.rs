@@ -34,6 +34,8 @@
 /// * Default value implementations for maps where specified
 /// * Methods:
 ///   - `remove_storage`: Removes storage for a given netuid
+///   - `remove_storage_limited`: Removes at most `limit` entries of storage for a given netuid
+///   - `clear_limited`: Removes at most `limit` entries across all variants for a given netuid
 ///   - `all`: Returns a vector of all variants
 #[macro_export]
 macro_rules! define_subnet_includes {
@@ -78,6 +80,51 @@ macro_rules! define_subnet_includes {
                 }
             }
 
+            /// Returns the amount of removed entries and whether the storage is fully cleared.
+            pub fn remove_storage_limited<T: pallet::Config>(
+                self,
+                netuid: u16,
+                limit: u32,
+            ) -> (u32, bool) {
+                match self {
+                    $(
+                        Self::$d_variant => {
+                            let result = $d_variant::<T>::clear_prefix(netuid, limit, None);
+                            (result.loops, result.maybe_cursor.is_none())
+                        }
+                    )*
+                    $(
+                        Self::$m_variant => {
+                            if !$m_variant::<T>::contains_key(netuid) {
+                                return (0, true);
+                            }
+
+                            $m_variant::<T>::remove(netuid);
+                            (1, true)
+                        }
+                    )*
+                }
+            }
+
+            /// Returns the amount of removed entries and whether all storages are fully cleared.
+            pub fn clear_limited<T: pallet::Config>(netuid: u16, limit: u32) -> (u32, bool) {
+                let mut removed: u32 = 0;
+                for storage_type in Self::all() {
+                    let remaining = limit.saturating_sub(removed);
+                    if remaining == 0 {
+                        return (removed, false);
+                    }
+
+                    let (loops, done) = storage_type.remove_storage_limited::<T>(netuid, remaining);
+                    removed = removed.saturating_add(loops);
+                    if !done {
+                        return (removed, false);
+                    }
+                }
+
+                (removed, true)
+            }
+
             pub fn all() -> sp_std::vec::Vec<Self> {
                 use strum::IntoEnumIterator;
                 Self::iter().collect()
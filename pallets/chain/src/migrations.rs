@@ -1,4 +1,4 @@
-use crate::*;
+use crate::{params::metadata::legacy_metadata, *};
 use frame_support::{pallet_prelude::ValueQuery, traits::StorageVersion, Blake2_128Concat};
 use sp_runtime::Percent;
 
@@ -67,3 +67,421 @@ pub mod v15 {
         }
     }
 }
+
+pub mod v16 {
+    use frame_support::{traits::OnRuntimeUpgrade, weights::Weight};
+
+    use super::*;
+
+    #[cfg(feature = "testnet")]
+    const PREVIOUS_VERSION: u16 = 34;
+    #[cfg(not(feature = "testnet"))]
+    const PREVIOUS_VERSION: u16 = 15;
+
+    pub mod old_storage {
+        use super::*;
+
+        #[derive(Decode, Encode)]
+        pub struct GeneralBurnConfiguration {
+            pub min_burn: u64,
+            pub max_burn: u64,
+            pub adjustment_alpha: u64,
+            pub target_registrations_interval: u16,
+            pub target_registrations_per_interval: u16,
+            pub max_registrations_per_interval: u16,
+        }
+
+        impl From<GeneralBurnConfiguration> for v17::old_storage::GeneralBurnConfiguration {
+            fn from(old: GeneralBurnConfiguration) -> Self {
+                Self {
+                    min_burn: old.min_burn,
+                    max_burn: old.max_burn,
+                    adjustment_alpha: old.adjustment_alpha,
+                    target_registrations_interval: old.target_registrations_interval,
+                    target_registrations_per_interval: old.target_registrations_per_interval,
+                    max_registrations_per_interval: old.max_registrations_per_interval,
+                    refund_curve: None,
+                }
+            }
+        }
+
+        impl<T: Config> From<GeneralBurnConfiguration> for crate::GeneralBurnConfiguration<T> {
+            fn from(old: GeneralBurnConfiguration) -> Self {
+                v17::old_storage::GeneralBurnConfiguration::from(old).into()
+            }
+        }
+    }
+
+    /// Adds the optional burn refund curve to the subnet and module burn configurations.
+    pub struct MigrateToV16<T>(sp_std::marker::PhantomData<T>);
+
+    impl<T: Config> OnRuntimeUpgrade for MigrateToV16<T> {
+        fn on_runtime_upgrade() -> Weight {
+            let on_chain_version = StorageVersion::get::<Pallet<T>>();
+            if on_chain_version != PREVIOUS_VERSION {
+                log::info!("Storage v16 already updated");
+                return Weight::zero();
+            }
+
+            StorageVersion::new(PREVIOUS_VERSION.saturating_add(1)).put::<Pallet<T>>();
+
+            if let Err(err) = v17::old_storage::SubnetBurnConfig::<T>::translate(
+                |old: Option<old_storage::GeneralBurnConfiguration>| old.map(Into::into),
+            ) {
+                log::error!(
+                    "failed to add the refund curve to the subnet burn configuration: {err:?}"
+                );
+            }
+
+            let mut burn_configs = 0u64;
+            v17::old_storage::ModuleBurnConfig::<T>::translate(
+                |_netuid, old: old_storage::GeneralBurnConfiguration| {
+                    burn_configs = burn_configs.saturating_add(1);
+                    Some(old.into())
+                },
+            );
+
+            log::info!("Added the refund curve to {burn_configs} module burn configurations");
+
+            // The version and the subnet burn configuration, plus every module burn configuration
+            let entries = burn_configs.saturating_add(2);
+            T::DbWeight::get().reads_writes(entries, entries)
+        }
+    }
+}
+
+pub mod v17 {
+    use frame_support::{traits::OnRuntimeUpgrade, weights::Weight};
+
+    use super::*;
+
+    #[cfg(feature = "testnet")]
+    const PREVIOUS_VERSION: u16 = 35;
+    #[cfg(not(feature = "testnet"))]
+    const PREVIOUS_VERSION: u16 = 16;
+
+    pub mod old_storage {
+        use super::*;
+        use frame_support::{storage_alias, Identity};
+
+        #[derive(Decode, Encode)]
+        pub struct GeneralBurnConfiguration {
+            pub min_burn: u64,
+            pub max_burn: u64,
+            pub adjustment_alpha: u64,
+            pub target_registrations_interval: u16,
+            pub target_registrations_per_interval: u16,
+            pub max_registrations_per_interval: u16,
+            pub refund_curve: Option<BurnRefundCurve>,
+        }
+
+        impl<T: Config> From<GeneralBurnConfiguration> for crate::GeneralBurnConfiguration<T> {
+            fn from(old: GeneralBurnConfiguration) -> Self {
+                Self {
+                    min_burn: old.min_burn,
+                    max_burn: old.max_burn,
+                    adjustment_alpha: old.adjustment_alpha,
+                    target_registrations_interval: old.target_registrations_interval,
+                    target_registrations_per_interval: old.target_registrations_per_interval,
+                    max_registrations_per_interval: old.max_registrations_per_interval,
+                    refund_curve: old.refund_curve,
+                    destination: BurnDestination::default(),
+                    _pd: sp_std::marker::PhantomData,
+                }
+            }
+        }
+
+        #[storage_alias]
+        pub type SubnetBurnConfig<T: Config> = StorageValue<Pallet<T>, GeneralBurnConfiguration>;
+
+        #[storage_alias]
+        pub type ModuleBurnConfig<T: Config> =
+            StorageMap<Pallet<T>, Identity, u16, GeneralBurnConfiguration>;
+    }
+
+    /// Adds the burn destination to the subnet and module burn configurations.
+    pub struct MigrateToV17<T>(sp_std::marker::PhantomData<T>);
+
+    impl<T: Config> OnRuntimeUpgrade for MigrateToV17<T> {
+        fn on_runtime_upgrade() -> Weight {
+            let on_chain_version = StorageVersion::get::<Pallet<T>>();
+            if on_chain_version != PREVIOUS_VERSION {
+                log::info!("Storage v17 already updated");
+                return Weight::zero();
+            }
+
+            StorageVersion::new(PREVIOUS_VERSION.saturating_add(1)).put::<Pallet<T>>();
+
+            if let Err(err) = SubnetBurnConfig::<T>::translate(
+                |old: Option<old_storage::GeneralBurnConfiguration>| old.map(Into::into),
+            ) {
+                log::error!(
+                    "failed to add the destination to the subnet burn configuration: {err:?}"
+                );
+            }
+
+            let mut burn_configs = 0u64;
+            ModuleBurnConfig::<T>::translate(
+                |_netuid, old: old_storage::GeneralBurnConfiguration| {
+                    burn_configs = burn_configs.saturating_add(1);
+                    Some(old.into())
+                },
+            );
+
+            log::info!("Added the destination to {burn_configs} module burn configurations");
+
+            let entries = burn_configs.saturating_add(2);
+            T::DbWeight::get().reads_writes(entries, entries)
+        }
+    }
+}
+
+pub mod v18 {
+    use frame_support::{traits::OnRuntimeUpgrade, weights::Weight};
+
+    use super::*;
+
+    #[cfg(feature = "testnet")]
+    const PREVIOUS_VERSION: u16 = 36;
+    #[cfg(not(feature = "testnet"))]
+    const PREVIOUS_VERSION: u16 = 17;
+
+    /// Seeds module heartbeats from their last weight update.
+    pub struct MigrateToV18<T>(sp_std::marker::PhantomData<T>);
+
+    impl<T: Config> OnRuntimeUpgrade for MigrateToV18<T> {
+        fn on_runtime_upgrade() -> Weight {
+            let on_chain_version = StorageVersion::get::<Pallet<T>>();
+            if on_chain_version != PREVIOUS_VERSION {
+                log::info!("Storage v18 already updated");
+                return Weight::zero();
+            }
+
+            StorageVersion::new(PREVIOUS_VERSION.saturating_add(1)).put::<Pallet<T>>();
+
+            let mut seeded = 0u64;
+            for netuid in N::<T>::iter_keys() {
+                LastHeartbeat::<T>::insert(netuid, LastUpdate::<T>::get(netuid));
+                seeded = seeded.saturating_add(1);
+            }
+
+            log::info!("Seeded module heartbeats for {seeded} subnets");
+
+            // Each subnet key and its last updates are read, and its heartbeats written
+            T::DbWeight::get().reads_writes(
+                seeded.saturating_mul(2).saturating_add(1),
+                seeded.saturating_add(1),
+            )
+        }
+    }
+}
+
+pub mod v19 {
+    use frame_support::{traits::OnRuntimeUpgrade, weights::Weight};
+
+    use super::*;
+
+    #[cfg(feature = "testnet")]
+    const PREVIOUS_VERSION: u16 = 37;
+    #[cfg(not(feature = "testnet"))]
+    const PREVIOUS_VERSION: u16 = 18;
+
+    /// Parses the legacy free-form module addresses into their typed form. Addresses that can't be
+    /// parsed are dropped, and counted in the logs.
+    pub struct MigrateToV19<T>(sp_std::marker::PhantomData<T>);
+
+    impl<T: Config> OnRuntimeUpgrade for MigrateToV19<T> {
+        fn on_runtime_upgrade() -> Weight {
+            let on_chain_version = StorageVersion::get::<Pallet<T>>();
+            if on_chain_version != PREVIOUS_VERSION {
+                log::info!("Storage v19 already updated");
+                return Weight::zero();
+            }
+
+            StorageVersion::new(PREVIOUS_VERSION.saturating_add(1)).put::<Pallet<T>>();
+
+            let (mut typed, mut dropped) = (0u64, 0u64);
+            Address::<T>::translate(|netuid, uid, old: Vec<u8>| {
+                let address = ModuleAddress::from_legacy(&old);
+                if address.is_some() {
+                    typed = typed.saturating_add(1);
+                } else {
+                    log::warn!(
+                        "dropping unparseable address {old:?} of module {uid} on subnet {netuid}"
+                    );
+                    dropped = dropped.saturating_add(1);
+                }
+                address
+            });
+
+            log::info!("Migrated {typed} module addresses");
+            if dropped > 0 {
+                log::warn!("Dropped {dropped} module addresses that could not be parsed");
+            }
+
+            // Dropped addresses are removed, so they cost a write as well
+            let addresses = typed.saturating_add(dropped).saturating_add(1);
+            T::DbWeight::get().reads_writes(addresses, addresses)
+        }
+    }
+}
+
+pub mod v20 {
+    use frame_support::{traits::OnRuntimeUpgrade, weights::Weight, BoundedVec};
+
+    use super::*;
+
+    #[cfg(feature = "testnet")]
+    const PREVIOUS_VERSION: u16 = 38;
+    #[cfg(not(feature = "testnet"))]
+    const PREVIOUS_VERSION: u16 = 19;
+
+    /// Parses the legacy free-form module and subnet metadata into their structured form.
+    pub struct MigrateToV20<T>(sp_std::marker::PhantomData<T>);
+
+    impl<T: Config> OnRuntimeUpgrade for MigrateToV20<T> {
+        fn on_runtime_upgrade() -> Weight {
+            let on_chain_version = StorageVersion::get::<Pallet<T>>();
+            if on_chain_version != PREVIOUS_VERSION {
+                log::info!("Storage v20 already updated");
+                return Weight::zero();
+            }
+
+            StorageVersion::new(PREVIOUS_VERSION.saturating_add(1)).put::<Pallet<T>>();
+
+            let mut metadata_entries = 0u64;
+            Metadata::<T>::translate(|_netuid, _key, old: Vec<u8>| {
+                metadata_entries = metadata_entries.saturating_add(1);
+                Some(legacy_metadata(&old)).filter(|metadata| !metadata.is_empty())
+            });
+            SubnetMetadata::<T>::translate(|_netuid, old: BoundedVec<u8, ConstU32<120>>| {
+                metadata_entries = metadata_entries.saturating_add(1);
+                Some(legacy_metadata(&old)).filter(|metadata| !metadata.is_empty())
+            });
+
+            log::info!("Migrated {metadata_entries} module and subnet metadata entries");
+
+            let entries = metadata_entries.saturating_add(1);
+            T::DbWeight::get().reads_writes(entries, entries)
+        }
+    }
+}
+
+pub mod v21 {
+    use frame_support::{traits::OnRuntimeUpgrade, weights::Weight};
+    use scale_info::prelude::format;
+
+    use super::*;
+
+    #[cfg(feature = "testnet")]
+    const PREVIOUS_VERSION: u16 = 39;
+    #[cfg(not(feature = "testnet"))]
+    const PREVIOUS_VERSION: u16 = 20;
+
+    /// Appends the uid to the name, and a counter if that still clashes, cutting the name so it
+    /// stays within the maximum name length. Also returns the number of index lookups it took.
+    fn unique_module_name<T: Config>(netuid: u16, uid: u16, name: &[u8]) -> (Vec<u8>, u64) {
+        let max_length = MaxNameLength::<T>::get() as usize;
+        let mut attempt = 0u32;
+        loop {
+            let suffix = if attempt == 0 {
+                format!("-{uid}")
+            } else {
+                format!("-{uid}-{attempt}")
+            };
+            let kept = name.len().min(max_length.saturating_sub(suffix.len()));
+            let mut unique = name.get(..kept).unwrap_or_default().to_vec();
+            unique.extend_from_slice(suffix.as_bytes());
+
+            if !ModuleNameIndex::<T>::contains_key(netuid, canonical_module_name(&unique)) {
+                return (unique, u64::from(attempt).saturating_add(1));
+            }
+            attempt = attempt.saturating_add(1);
+        }
+    }
+
+    /// Builds the module name index. Modules whose name clashes with a look-alike on a lower uid
+    /// get their uid appended to the name, so every module ends up indexed.
+    pub struct MigrateToV21<T>(sp_std::marker::PhantomData<T>);
+
+    impl<T: Config> OnRuntimeUpgrade for MigrateToV21<T> {
+        fn on_runtime_upgrade() -> Weight {
+            let on_chain_version = StorageVersion::get::<Pallet<T>>();
+            if on_chain_version != PREVIOUS_VERSION {
+                log::info!("Storage v21 already updated");
+                return Weight::zero();
+            }
+
+            StorageVersion::new(PREVIOUS_VERSION.saturating_add(1)).put::<Pallet<T>>();
+
+            // Lower uids registered first, so they keep the name when look-alikes clash
+            let mut names: Vec<_> = Name::<T>::iter().collect();
+            names.sort_by_key(|(netuid, uid, _)| (*netuid, *uid));
+            let (mut indexed, mut renamed, mut lookups) = (0u64, 0u64, 0u64);
+            for (netuid, uid, name) in names {
+                let canonical = canonical_module_name(&name);
+                if !ModuleNameIndex::<T>::contains_key(netuid, &canonical) {
+                    ModuleNameIndex::<T>::insert(netuid, canonical, uid);
+                    indexed = indexed.saturating_add(1);
+                    continue;
+                }
+
+                let (unique, unique_lookups) = unique_module_name::<T>(netuid, uid, &name);
+                lookups = lookups.saturating_add(unique_lookups);
+                log::warn!(
+                    "renaming module {uid} on subnet {netuid} from {name:?} to {unique:?}, as its \
+                     name clashes with an indexed one"
+                );
+                ModuleNameIndex::<T>::insert(netuid, canonical_module_name(&unique), uid);
+                Name::<T>::insert(netuid, uid, unique);
+                renamed = renamed.saturating_add(1);
+            }
+
+            log::info!("Indexed {indexed} module names");
+            if renamed > 0 {
+                log::warn!("Renamed {renamed} modules whose names clashed with a look-alike");
+            }
+
+            // Every name is read and looked up in the index, and renamed modules also read the
+            // maximum name length and look up each name they try
+            let names = indexed.saturating_add(renamed);
+            let reads = names.saturating_mul(2).saturating_add(renamed).saturating_add(lookups);
+            let writes = indexed.saturating_add(renamed.saturating_mul(2));
+            T::DbWeight::get().reads_writes(reads.saturating_add(1), writes.saturating_add(1))
+        }
+    }
+}
+
+pub mod v22 {
+    use frame_support::{traits::OnRuntimeUpgrade, weights::Weight};
+
+    use super::*;
+
+    #[cfg(feature = "testnet")]
+    const PREVIOUS_VERSION: u16 = 40;
+    #[cfg(not(feature = "testnet"))]
+    const PREVIOUS_VERSION: u16 = 21;
+
+    /// Introduces storage deposits. Modules and subnets registered before deposits existed hold
+    /// none, and this upgrade doesn't reserve them either: doing so would walk every module in
+    /// one block and take funds from accounts that didn't act. They are exempt until their next
+    /// `update_module`, or the next subnet update that changes its name or metadata, which
+    /// reserves the full deposit like it would for any other entry. Until then what they store
+    /// is free, and a holder that can't afford the deposit can't change it.
+    pub struct MigrateToV22<T>(sp_std::marker::PhantomData<T>);
+
+    impl<T: Config> OnRuntimeUpgrade for MigrateToV22<T> {
+        fn on_runtime_upgrade() -> Weight {
+            let on_chain_version = StorageVersion::get::<Pallet<T>>();
+            if on_chain_version != PREVIOUS_VERSION {
+                log::info!("Storage v22 already updated");
+                return Weight::zero();
+            }
+
+            StorageVersion::new(PREVIOUS_VERSION.saturating_add(1)).put::<Pallet<T>>();
+            log::info!("Migrated to v22, existing entries pay their deposit on their next update");
+
+            T::DbWeight::get().reads_writes(1, 1)
+        }
+    }
+}
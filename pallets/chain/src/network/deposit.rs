@@ -0,0 +1,145 @@
+use crate::*;
+use sp_runtime::DispatchError;
+
+impl<T: Config> Pallet<T> {
+    /// The deposit held for storing `bytes` bytes.
+    pub fn storage_deposit(bytes: usize) -> u64 {
+        StorageDepositPerByte::<T>::get().saturating_mul(bytes as u64)
+    }
+
+    /// The bytes a module stores: its name, address and metadata.
+    pub fn module_storage_bytes(netuid: u16, uid: u16, key: &T::AccountId) -> usize {
+        let name = Name::<T>::get(netuid, uid).encoded_size();
+        let address = Address::<T>::get(netuid, uid).map_or(0, |address| address.encoded_size());
+        let metadata =
+            Metadata::<T>::get(netuid, key).map_or(0, |metadata| metadata.encoded_size());
+        name.saturating_add(address).saturating_add(metadata)
+    }
+
+    /// The bytes a subnet stores: its name and metadata.
+    pub fn subnet_storage_bytes(netuid: u16) -> usize {
+        let name = SubnetNames::<T>::get(netuid).encoded_size();
+        let metadata =
+            SubnetMetadata::<T>::get(netuid).map_or(0, |metadata| metadata.encoded_size());
+        name.saturating_add(metadata)
+    }
+
+    /// Releases the previous deposit to whoever paid it and reserves `deposit` from `payer`. The
+    /// previous deposit is kept as is if its amount doesn't change.
+    fn settle_storage_deposit(
+        previous: Option<(T::AccountId, u64)>,
+        payer: &T::AccountId,
+        deposit: u64,
+    ) -> Result<Option<(T::AccountId, u64)>, DispatchError> {
+        if let Some((previous_payer, previous_deposit)) = previous {
+            if previous_deposit == deposit {
+                return Ok(Some((previous_payer, previous_deposit)));
+            }
+            Self::release_storage_deposit(&previous_payer, previous_deposit);
+        }
+
+        if deposit == 0 {
+            return Ok(None);
+        }
+
+        let balance = Self::u64_to_balance(deposit).ok_or(Error::<T>::CouldNotConvertToBalance)?;
+        T::Currency::reserve(payer, balance)
+            .map_err(|_| Error::<T>::NotEnoughBalanceForStorageDeposit)?;
+        Ok(Some((payer.clone(), deposit)))
+    }
+
+    /// Checks `payer` can afford the deposit of a subnet storing `bytes` bytes, on top of what
+    /// it already holds for the subnet.
+    pub fn ensure_subnet_deposit_affordable(
+        netuid: u16,
+        payer: &T::AccountId,
+        bytes: usize,
+    ) -> DispatchResult {
+        let deposit = Self::storage_deposit(bytes);
+        let held = match SubnetStorageDeposit::<T>::get(netuid) {
+            Some((previous_payer, previous_deposit)) if previous_payer == *payer => {
+                previous_deposit
+            }
+            _ => 0,
+        };
+
+        let missing = deposit.saturating_sub(held);
+        if missing == 0 {
+            return Ok(());
+        }
+
+        let balance = Self::u64_to_balance(missing).ok_or(Error::<T>::CouldNotConvertToBalance)?;
+        ensure!(
+            T::Currency::can_reserve(payer, balance),
+            Error::<T>::NotEnoughBalanceForStorageDeposit
+        );
+        Ok(())
+    }
+
+    fn release_storage_deposit(payer: &T::AccountId, deposit: u64) {
+        if let Some(balance) = Self::u64_to_balance(deposit) {
+            let _ = T::Currency::unreserve(payer, balance);
+        }
+    }
+
+    /// Sizes the deposit of a module to what it currently stores, reserving any difference from
+    /// `payer`.
+    pub fn settle_module_deposit(
+        netuid: u16,
+        uid: u16,
+        key: &T::AccountId,
+        payer: &T::AccountId,
+    ) -> DispatchResult {
+        let deposit = Self::storage_deposit(Self::module_storage_bytes(netuid, uid, key));
+        let previous = ModuleStorageDeposit::<T>::take(netuid, key);
+        if let Some(deposit) = Self::settle_storage_deposit(previous, payer, deposit)? {
+            ModuleStorageDeposit::<T>::insert(netuid, key, deposit);
+        }
+        Ok(())
+    }
+
+    /// Sizes the deposit of a subnet to what it currently stores, reserving any difference from
+    /// `payer`.
+    pub fn settle_subnet_deposit(netuid: u16, payer: &T::AccountId) -> DispatchResult {
+        let deposit = Self::storage_deposit(Self::subnet_storage_bytes(netuid));
+        let previous = SubnetStorageDeposit::<T>::take(netuid);
+        if let Some(deposit) = Self::settle_storage_deposit(previous, payer, deposit)? {
+            SubnetStorageDeposit::<T>::insert(netuid, deposit);
+        }
+        Ok(())
+    }
+
+    /// Releases the deposit of a module leaving the subnet.
+    pub fn release_module_deposit(netuid: u16, key: &T::AccountId) {
+        if let Some((payer, deposit)) = ModuleStorageDeposit::<T>::take(netuid, key) {
+            Self::release_storage_deposit(&payer, deposit);
+        }
+    }
+
+    /// Releases up to `limit` deposits held for a removed subnet and its modules. Returns the
+    /// amount of released deposits and whether all of them are released.
+    pub fn release_storage_deposits(netuid: u16, limit: u32) -> (u32, bool) {
+        let deposits: Vec<_> =
+            ModuleStorageDeposit::<T>::iter_prefix(netuid).take(limit as usize).collect();
+
+        let mut released: u32 = 0;
+        for (key, (payer, deposit)) in deposits {
+            ModuleStorageDeposit::<T>::remove(netuid, &key);
+            Self::release_storage_deposit(&payer, deposit);
+            released = released.saturating_add(1);
+        }
+
+        if released >= limit {
+            let done = ModuleStorageDeposit::<T>::iter_prefix(netuid).next().is_none()
+                && !SubnetStorageDeposit::<T>::contains_key(netuid);
+            return (released, done);
+        }
+
+        if let Some((payer, deposit)) = SubnetStorageDeposit::<T>::take(netuid) {
+            Self::release_storage_deposit(&payer, deposit);
+            released = released.saturating_add(1);
+        }
+
+        (released, true)
+    }
+}
@@ -12,8 +12,49 @@ impl<T: Config> Pallet<T> {
     ) -> DispatchResult {
         let key = ensure_signed(origin)?;
         let uid: u16 = Self::get_uid_for_key(netuid, &key).ok_or(Error::<T>::ModuleDoesNotExist)?;
-        changeset.apply(netuid, key, uid)?;
-        Ok(())
+        changeset.apply(netuid, key.clone(), uid)?;
+        Self::settle_module_deposit(netuid, uid, &key, &key)
+    }
+
+    /// Records that the module is alive, without having to set weights. Modules can send one
+    /// heartbeat per epoch, the first one being allowed right after registration.
+    pub fn do_heartbeat(origin: T::RuntimeOrigin, netuid: u16) -> DispatchResult {
+        let key = ensure_signed(origin)?;
+        Self::ensure_subnet_active(netuid)?;
+        let uid: u16 = Self::get_uid_for_key(netuid, &key).ok_or(Error::<T>::ModuleDoesNotExist)?;
+
+        let current_block = Self::get_current_block_number();
+        let tempo = u64::from(Tempo::<T>::get(netuid));
+        let registration_block = RegistrationBlock::<T>::get(netuid, uid);
+
+        LastHeartbeat::<T>::try_mutate(netuid, |heartbeats| {
+            let last = heartbeats.get_mut(uid as usize).ok_or(Error::<T>::ModuleDoesNotExist)?;
+            ensure!(
+                *last <= registration_block || current_block.saturating_sub(*last) >= tempo,
+                Error::<T>::HeartbeatTooSoon
+            );
+
+            *last = current_block;
+            Ok(())
+        })
+    }
+
+    /// Block each module was last active at. On subnets counting heartbeats as activity, this is
+    /// the latest of the last weights and the last heartbeat.
+    pub fn get_last_activity_for(netuid: u16) -> Vec<u64> {
+        let last_update = LastUpdate::<T>::get(netuid);
+        if SubnetActivitySource::<T>::get(netuid) != ActivitySource::Heartbeats {
+            return last_update;
+        }
+
+        let last_heartbeat = LastHeartbeat::<T>::get(netuid);
+        last_update
+            .iter()
+            .enumerate()
+            .map(|(uid, updated)| {
+                last_heartbeat.get(uid).map_or(*updated, |heartbeat| (*updated).max(*heartbeat))
+            })
+            .collect()
     }
 
     pub fn append_module(
@@ -65,6 +106,20 @@ impl<T: Config> Pallet<T> {
             module_key
         );
 
+        // --- Release The Storage Deposit & Forfeit The Burn Escrow ---
+        Self::release_module_deposit(netuid, &module_key);
+        Self::forfeit_registration_escrow(netuid, &module_key);
+
+        // --- Update The Name Index ---
+        // The module under `replace_uid` is moved into `uid`, so its name has to follow it.
+        Self::unindex_module_name(netuid, uid);
+        if replace_uid != uid {
+            let replace_name = canonical_module_name(&Name::<T>::get(netuid, replace_uid));
+            if ModuleNameIndex::<T>::get(netuid, &replace_name) == Some(replace_uid) {
+                ModuleNameIndex::<T>::insert(netuid, replace_name, uid);
+            }
+        }
+
         // --- Remove All Module Related Storage ---
         StorageHandler::remove_all::<T>(netuid, uid, replace_uid, &module_key, &replace_key)?;
         <T as SubnetEmissionApi<T::AccountId>>::clear_module_includes(
@@ -4,10 +4,32 @@ use crate::*;
 use frame_support::{pallet_prelude::DispatchResult, sp_runtime::DispatchError};
 use frame_system::ensure_signed;
 use pallet_emission_api::SubnetConsensus;
-use sp_core::Get;
+use scale_info::TypeInfo;
+use sp_core::{hashing::blake2_256, Get, H256};
 use sp_runtime::BoundedVec;
 use substrate_fixed::types::I110F18;
 
+/// A registration commitment waiting to be revealed. The burn is held upfront, so the slot can
+/// be claimed once the registration details are revealed. Nothing in it discloses the module
+/// name, which is only claimed by the reveal.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, TypeInfo, Debug)]
+pub struct RegistrationCommit<AccountId> {
+    pub committer: AccountId,
+    pub netuid: u16,
+    pub burn: u64,
+    pub block: u64,
+}
+
+/// The maximum number of subnets a module can be registered on in a single call.
+pub const MAX_MULTIPLE_REGISTRATIONS: u32 = 16;
+
+/// The `(network_name, name, address, metadata)` of each registration of a module registered on
+/// multiple subnets at once.
+pub type MultipleRegistrations = BoundedVec<
+    (Vec<u8>, Vec<u8>, ModuleAddress, MetadataChanges),
+    ConstU32<MAX_MULTIPLE_REGISTRATIONS>,
+>;
+
 impl<T: Config> Pallet<T> {
     /// Default Rootnetwork subnet id
     const ROOTNET_ID: u16 = 0;
@@ -21,7 +43,7 @@ impl<T: Config> Pallet<T> {
     /// * `address` - The address of the module.
     /// * `stake` - The amount of stake to register with.
     /// * `module_key` - The account ID of the module.
-    /// * `metadata` - Optional metadata for the module.
+    /// * `metadata` - The metadata entries to set on the module.
     ///
     /// # Errors
     ///
@@ -30,10 +52,13 @@ impl<T: Config> Pallet<T> {
     /// * The caller's signature is invalid.
     /// * The maximum number of registrations per block has been reached.
     /// * The caller doesn't have enough balance to register.
+    /// * The caller doesn't have enough free balance to reserve the storage deposit.
     /// * The subnet name is too long when creating a new subnet.
     /// * The maximum number of registrations per interval has been reached.
     /// * The stake is insufficient for registration.
     /// * The module key is already registered.
+    /// * The subnet runs its own application process and the module key wasn't approved.
+    /// * The subnet registration mode doesn't allow the caller or module key to register.
     /// * The maximum number of modules per network has been reached.
     /// * The root network registration requirements are not met.
     ///
@@ -50,12 +75,14 @@ impl<T: Config> Pallet<T> {
         origin: T::RuntimeOrigin,
         network_name: Vec<u8>,
         name: Vec<u8>,
-        address: Vec<u8>,
+        address: ModuleAddress,
         module_key: T::AccountId,
-        metadata: Option<Vec<u8>>,
+        metadata: MetadataChanges,
     ) -> DispatchResult {
         let key = ensure_signed(origin.clone())?;
 
+        Self::ensure_not_paused(PausableOperation::Registration)?;
+
         ensure!(
             RegistrationsPerBlock::<T>::get() < MaxRegistrationsPerBlock::<T>::get(),
             Error::<T>::TooManyRegistrationsPerBlock
@@ -65,22 +92,239 @@ impl<T: Config> Pallet<T> {
             Self::get_netuid_for_name(&network_name).ok_or(Error::<T>::NetworkDoesNotExist)?;
 
         Self::validate_registration_request(netuid, &key, &module_key)?;
+        let burn = Self::charge_registration_burn(netuid, &key)?;
 
         Self::reserve_module_slot(netuid, &module_key)?;
 
-        let uid = Self::register_module(netuid, &module_key, name, address, metadata)?;
+        let uid = Self::register_module(netuid, &key, &module_key, name, address, metadata)?;
         Self::finalize_registration(netuid, uid, &module_key)?;
+        Self::settle_registration_burn(netuid, &key, &module_key, burn);
+
+        Ok(())
+    }
+
+    /// Registers the module key on several subnets at once. Either every registration succeeds or
+    /// none is made.
+    ///
+    /// # Arguments
+    ///
+    /// * `origin` - The origin of the call, must be a signed account.
+    /// * `module_key` - The account ID of the module.
+    /// * `registrations` - The `(network_name, name, address, metadata)` of each registration.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if:
+    ///
+    /// * The caller's signature is invalid.
+    /// * No registrations were given.
+    /// * The registrations would exceed the maximum number of registrations per block.
+    /// * Any of the subnets does not exist.
+    /// * The caller doesn't have enough balance to pay the summed burn of all subnets.
+    /// * Any of the regular registration requirements are not met.
+    pub fn do_register_multiple(
+        origin: T::RuntimeOrigin,
+        module_key: T::AccountId,
+        registrations: MultipleRegistrations,
+    ) -> DispatchResult {
+        let key = ensure_signed(origin)?;
+
+        Self::ensure_not_paused(PausableOperation::Registration)?;
+
+        ensure!(!registrations.is_empty(), Error::<T>::EmptyRegistrations);
+        let registrations_this_block = u16::try_from(registrations.len())
+            .map(|count| RegistrationsPerBlock::<T>::get().saturating_add(count))
+            .unwrap_or(u16::MAX);
+        ensure!(
+            registrations_this_block <= MaxRegistrationsPerBlock::<T>::get(),
+            Error::<T>::TooManyRegistrationsPerBlock
+        );
+
+        let netuids = registrations
+            .iter()
+            .map(|(network_name, ..)| {
+                let netuid = Self::get_netuid_for_name(network_name)
+                    .ok_or(Error::<T>::NetworkDoesNotExist)?;
+                Ok((netuid, Self::registration_burn(netuid)))
+            })
+            .collect::<Result<Vec<_>, Error<T>>>()?;
+
+        let total_burn = netuids.iter().fold(0u64, |total, (_, burn)| total.saturating_add(*burn));
+        ensure!(
+            Self::get_balance_u64(&key) >= total_burn,
+            Error::<T>::NotEnoughBalanceToRegister
+        );
+        Self::hold_registration_burn(&key, total_burn)?;
+
+        for ((netuid, burn), (_, name, address, metadata)) in netuids.into_iter().zip(registrations)
+        {
+            Self::validate_registration_request(netuid, &key, &module_key)?;
+
+            Self::reserve_module_slot(netuid, &module_key)?;
+
+            let uid = Self::register_module(netuid, &key, &module_key, name, address, metadata)?;
+            Self::finalize_registration(netuid, uid, &module_key)?;
+            Self::settle_registration_burn(netuid, &key, &module_key, burn);
+        }
 
         Ok(())
     }
 
+    /// Commits to a registration without disclosing its details, holding the subnet burn upfront.
+    /// The commitment is the `blake2_256` hash of the SCALE encoded
+    /// `(key, netuid, name, address, module_key, metadata, salt)` tuple.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if:
+    ///
+    /// * The caller's signature is invalid.
+    /// * Registrations are paused.
+    /// * The subnet does not exist.
+    /// * The commitment was already made.
+    /// * The caller doesn't have enough balance to pay the burn.
+    pub fn do_commit_registration(
+        origin: T::RuntimeOrigin,
+        netuid: u16,
+        commitment: H256,
+    ) -> DispatchResult {
+        let key = ensure_signed(origin)?;
+
+        Self::ensure_not_paused(PausableOperation::Registration)?;
+
+        ensure!(
+            Self::if_subnet_exist(netuid),
+            Error::<T>::NetworkDoesNotExist
+        );
+        Self::ensure_subnet_active(netuid)?;
+        ensure!(
+            !RegistrationCommits::<T>::contains_key(commitment),
+            Error::<T>::RegistrationCommitAlreadyExists
+        );
+
+        let burn = Self::charge_registration_burn(netuid, &key)?;
+
+        let block = Self::get_current_block_number();
+        let expires_at =
+            block.saturating_add(RegistrationRevealPeriod::<T>::get()).saturating_add(1);
+        RegistrationCommits::<T>::insert(
+            commitment,
+            RegistrationCommit {
+                committer: key.clone(),
+                netuid,
+                burn,
+                block,
+            },
+        );
+        RegistrationCommitExpiries::<T>::insert(expires_at, commitment, ());
+
+        Self::deposit_event(Event::RegistrationCommitted(key, netuid, commitment));
+        Ok(())
+    }
+
+    /// Reveals a registration commitment made in an earlier block and registers the module with
+    /// the burn already paid. Commitments that are not revealed within the reveal period are
+    /// refunded, minus the commit fee. The name goes to the first reveal or registration claiming
+    /// it, so a reveal finding it taken loses and gets the whole burn refunded instead.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if:
+    ///
+    /// * The caller's signature is invalid.
+    /// * Registrations are paused.
+    /// * The maximum number of registrations per block has been reached.
+    /// * No pending commitment of the caller matches the revealed details.
+    /// * The commitment was made in the current block.
+    /// * Any of the regular registration requirements are not met.
+    pub fn do_reveal_registration(
+        origin: T::RuntimeOrigin,
+        netuid: u16,
+        name: Vec<u8>,
+        address: ModuleAddress,
+        module_key: T::AccountId,
+        metadata: MetadataChanges,
+        salt: [u8; 32],
+    ) -> DispatchResult {
+        let key = ensure_signed(origin)?;
+
+        Self::ensure_not_paused(PausableOperation::Registration)?;
+
+        ensure!(
+            RegistrationsPerBlock::<T>::get() < MaxRegistrationsPerBlock::<T>::get(),
+            Error::<T>::TooManyRegistrationsPerBlock
+        );
+
+        let commitment = H256(blake2_256(
+            &(&key, netuid, &name, &address, &module_key, &metadata, salt).encode(),
+        ));
+        // The commitment binds the caller and the subnet, so nobody else can claim it
+        let commit = RegistrationCommits::<T>::take(commitment)
+            .ok_or(Error::<T>::RegistrationCommitNotFound)?;
+        ensure!(
+            Self::get_current_block_number() > commit.block,
+            Error::<T>::RegistrationRevealTooEarly
+        );
+
+        let expires_at = commit
+            .block
+            .saturating_add(RegistrationRevealPeriod::<T>::get())
+            .saturating_add(1);
+        RegistrationCommitExpiries::<T>::remove(expires_at, commitment);
+
+        // The name is only claimed now, by whichever reveal or registration gets to it first
+        if Self::get_uid_for_name(netuid, &name).is_some() {
+            Self::release_held_burn(&key, commit.burn);
+            Self::deposit_event(Event::RegistrationCommitRefunded(
+                key,
+                commitment,
+                commit.burn,
+            ));
+            return Ok(());
+        }
+
+        Self::validate_registration_request(netuid, &key, &module_key)?;
+
+        Self::reserve_module_slot(netuid, &module_key)?;
+
+        let uid = Self::register_module(netuid, &key, &module_key, name, address, metadata)?;
+        Self::finalize_registration(netuid, uid, &module_key)?;
+        Self::settle_registration_burn(netuid, &key, &module_key, commit.burn);
+
+        Ok(())
+    }
+
+    /// Refunds the commitments that were not revealed in time, keeping the commit fee.
+    pub fn clear_expired_registration_commits(block_number: u64) {
+        let fee = RegistrationCommitFee::<T>::get();
+
+        for (commitment, ()) in RegistrationCommitExpiries::<T>::drain_prefix(block_number) {
+            let Some(commit) = RegistrationCommits::<T>::take(commitment) else {
+                continue;
+            };
+
+            let commit_fee = fee.mul_floor(commit.burn);
+            let refund = commit.burn.saturating_sub(commit_fee);
+            Self::destroy_held_burn(&commit.committer, commit_fee);
+            Self::release_held_burn(&commit.committer, refund);
+
+            Self::deposit_event(Event::RegistrationCommitExpired(
+                commit.committer,
+                commitment,
+                refund,
+            ));
+        }
+    }
+
     pub fn do_register_subnet(
         origin: T::RuntimeOrigin,
         network_name: Vec<u8>,
-        network_metadata: Option<Vec<u8>>,
+        network_metadata: MetadataChanges,
     ) -> DispatchResult {
         let key = ensure_signed(origin.clone())?;
 
+        Self::ensure_not_paused(PausableOperation::Registration)?;
+
         if Self::get_netuid_for_name(&network_name).is_some() {
             return Err(Error::<T>::SubnetNameAlreadyExists.into());
         }
@@ -88,12 +332,11 @@ impl<T: Config> Pallet<T> {
         let bounded_name: BoundedVec<u8, ConstU32<256>> =
             network_name.to_vec().try_into().map_err(|_| Error::<T>::SubnetNameTooLong)?;
 
-        let network_metadata: Option<BoundedVec<u8, ConstU32<120>>> = match network_metadata {
-            Some(slice) => {
-                Some(slice.to_vec().try_into().map_err(|_| Error::<T>::SubnetNameTooLong)?)
-            }
-            None => None,
-        };
+        let network_metadata = Self::apply_metadata_changes(
+            MetadataMap::default(),
+            network_metadata,
+            MetadataOwner::Subnet,
+        )?;
 
         let params = SubnetParams {
             name: bounded_name,
@@ -110,7 +353,14 @@ impl<T: Config> Pallet<T> {
         )
         .map_err(|_| Error::<T>::NotEnoughBalanceToRegisterSubnet)?;
 
-        Self::add_subnet_from_registration(changeset)
+        Self::add_subnet_from_registration(changeset)?;
+
+        let netuid =
+            Self::get_netuid_for_name(&network_name).ok_or(Error::<T>::NetworkDoesNotExist)?;
+        let destination = SubnetBurnConfig::<T>::get().destination;
+        Self::distribute_burn(netuid, burn, destination);
+
+        Ok(())
     }
 
     /// Deregisters a module from the specified subnet.
@@ -142,7 +392,8 @@ impl<T: Config> Pallet<T> {
         let Some(uid) = Self::get_uid_for_key(netuid, &key) else {
             return Err(Error::<T>::ModuleDoesNotExist.into());
         };
-        // --- 3. Remove the module from the subnet.
+        // --- 3. Refund part of the burn and remove the module from the subnet.
+        Self::refund_registration_burn(netuid, uid, &key);
         Self::remove_module(netuid, uid, true)?;
         ensure!(
             !Self::key_registered(netuid, &key),
@@ -164,6 +415,8 @@ impl<T: Config> Pallet<T> {
         key: &T::AccountId,
         module_key: &T::AccountId,
     ) -> DispatchResult {
+        Self::ensure_subnet_active(netuid)?;
+
         let burn_config = ModuleBurnConfig::<T>::get(netuid);
         ensure!(
             RegistrationsThisInterval::<T>::get(netuid)
@@ -180,27 +433,198 @@ impl<T: Config> Pallet<T> {
             Error::<T>::KeyAlreadyRegistered
         );
 
-        let rootnet_id = T::get_consensus_netuid(SubnetConsensus::Root).unwrap_or(Self::ROOTNET_ID);
-        if netuid != rootnet_id {
-            let burn =
-                Self::u64_to_balance(Burn::<T>::get(netuid)).ok_or(Error::<T>::ArithmeticError)?;
-            Self::remove_balance_from_account(key, burn)
-                .map_err(|_| Error::<T>::NotEnoughBalanceToRegister)?;
+        ensure!(
+            T::is_approved_for_subnet(netuid, module_key),
+            Error::<T>::ModuleNotApprovedForSubnet
+        );
+
+        match SubnetRegistrationMode::<T>::get(netuid) {
+            RegistrationMode::Open => {}
+            RegistrationMode::Allowlist => ensure!(
+                ModuleAllowlist::<T>::contains_key(netuid, module_key),
+                Error::<T>::ModuleNotAllowlisted
+            ),
+            RegistrationMode::FounderApproval => ensure!(
+                Founder::<T>::get(netuid) == *key
+                    || ModuleRegistrationApprovals::<T>::contains_key(netuid, module_key),
+                Error::<T>::ModuleRegistrationNotApproved
+            ),
         }
 
         Ok(())
     }
 
+    /// The burn paid to register a module on the subnet. Registering on the rootnet is free.
+    fn registration_burn(netuid: u16) -> u64 {
+        let rootnet_id = T::get_consensus_netuid(SubnetConsensus::Root).unwrap_or(Self::ROOTNET_ID);
+        if netuid == rootnet_id {
+            0
+        } else {
+            Burn::<T>::get(netuid)
+        }
+    }
+
+    /// Holds the subnet burn on the payer until the registration is settled, returning its
+    /// amount.
+    fn charge_registration_burn(netuid: u16, key: &T::AccountId) -> Result<u64, DispatchError> {
+        let burn = Self::registration_burn(netuid);
+        Self::hold_registration_burn(key, burn)?;
+        Ok(burn)
+    }
+
+    /// Hands out the burn held for a registration as the subnet burn configuration says. The
+    /// refundable share of the destroyed part stays held on the payer as the registration
+    /// escrow, if the subnet has a refund curve.
+    fn settle_registration_burn(
+        netuid: u16,
+        payer: &T::AccountId,
+        module_key: &T::AccountId,
+        burn: u64,
+    ) {
+        if burn == 0 {
+            return;
+        }
+
+        let burn_config = ModuleBurnConfig::<T>::get(netuid);
+        let (to_treasury, to_founder) = burn_config.destination.split(burn);
+        let destroyed = burn.saturating_sub(to_treasury).saturating_sub(to_founder);
+        let escrowed = burn_config
+            .refund_curve
+            .map_or(0, |curve| curve.initial_refund.mul_floor(destroyed));
+
+        Self::pay_held_burn(payer, &T::get_dao_treasury_address(), to_treasury);
+        Self::pay_held_burn(payer, &Founder::<T>::get(netuid), to_founder);
+        Self::destroy_held_burn(payer, destroyed.saturating_sub(escrowed));
+        Self::deposit_event(Event::RegistrationBurnDistributed(
+            netuid,
+            destroyed,
+            to_treasury,
+            to_founder,
+        ));
+
+        if escrowed > 0 {
+            RegistrationBurnEscrow::<T>::insert(netuid, module_key, (payer.clone(), escrowed));
+        }
+    }
+
+    /// Refunds the account that paid the registration of a module leaving on its own, out of the
+    /// escrow held on it. Whatever the refund curve doesn't pay back is destroyed, just like the
+    /// escrow of pruned modules.
+    fn refund_registration_burn(netuid: u16, uid: u16, module_key: &T::AccountId) {
+        let Some((payer, escrowed)) = RegistrationBurnEscrow::<T>::take(netuid, module_key) else {
+            return;
+        };
+
+        let blocks_since_registration = Self::get_current_block_number()
+            .saturating_sub(RegistrationBlock::<T>::get(netuid, uid));
+        let refund = ModuleBurnConfig::<T>::get(netuid)
+            .refund_curve
+            .map_or(0, |curve| curve.refund(escrowed, blocks_since_registration));
+
+        Self::destroy_held_burn(&payer, escrowed.saturating_sub(refund));
+        if refund == 0 {
+            return;
+        }
+
+        Self::release_held_burn(&payer, refund);
+        Self::deposit_event(Event::RegistrationBurnRefunded(netuid, payer, refund));
+    }
+
+    /// Destroys the escrow of a module leaving the subnet without a refund.
+    pub fn forfeit_registration_escrow(netuid: u16, module_key: &T::AccountId) {
+        if let Some((payer, escrowed)) = RegistrationBurnEscrow::<T>::take(netuid, module_key) {
+            Self::destroy_held_burn(&payer, escrowed);
+        }
+    }
+
+    /// Destroys up to `limit` escrows left on a removed subnet. Returns the amount of destroyed
+    /// escrows and whether all of them are destroyed.
+    pub fn forfeit_registration_escrows(netuid: u16, limit: u32) -> (u32, bool) {
+        let escrows: Vec<_> =
+            RegistrationBurnEscrow::<T>::iter_prefix(netuid).take(limit as usize).collect();
+
+        let mut forfeited: u32 = 0;
+        for (module_key, (payer, escrowed)) in escrows {
+            RegistrationBurnEscrow::<T>::remove(netuid, &module_key);
+            Self::destroy_held_burn(&payer, escrowed);
+            forfeited = forfeited.saturating_add(1);
+        }
+
+        let done = RegistrationBurnEscrow::<T>::iter_prefix(netuid).next().is_none();
+        (forfeited, done)
+    }
+
+    /// Sends the treasury share of an already withdrawn subnet burn to the treasury, returning
+    /// the amount destroyed. Subnets are registered by their founders, so the founder share is
+    /// destroyed too.
+    fn distribute_burn(netuid: u16, burn: u64, destination: BurnDestination) -> u64 {
+        if burn == 0 {
+            return 0;
+        }
+
+        let (to_treasury, _) = destination.split(burn);
+        if let Some(balance) = Self::u64_to_balance(to_treasury) {
+            Self::add_balance_to_account(&T::get_dao_treasury_address(), balance);
+        }
+
+        let destroyed = burn.saturating_sub(to_treasury);
+        Self::deposit_event(Event::RegistrationBurnDistributed(
+            netuid,
+            destroyed,
+            to_treasury,
+            0,
+        ));
+
+        destroyed
+    }
+
+    /// Holds `burn` on the account as reserved balance.
+    fn hold_registration_burn(key: &T::AccountId, burn: u64) -> DispatchResult {
+        if burn == 0 {
+            return Ok(());
+        }
+
+        let balance = Self::u64_to_balance(burn).ok_or(Error::<T>::ArithmeticError)?;
+        T::Currency::reserve(key, balance)
+            .map_err(|_| Error::<T>::NotEnoughBalanceToRegister.into())
+    }
+
+    /// Hands `amount` of the burn held on `payer` over to `to`.
+    fn pay_held_burn(payer: &T::AccountId, to: &T::AccountId, amount: u64) {
+        if let Some(balance) = Self::u64_to_balance(amount).filter(|balance| *balance > 0) {
+            let (paid, _) = T::Currency::slash_reserved(payer, balance);
+            T::Currency::resolve_creating(to, paid);
+        }
+    }
+
+    /// Destroys `amount` of the burn held on `payer`.
+    fn destroy_held_burn(payer: &T::AccountId, amount: u64) {
+        if let Some(balance) = Self::u64_to_balance(amount).filter(|balance| *balance > 0) {
+            let _ = T::Currency::slash_reserved(payer, balance);
+        }
+    }
+
+    /// Gives `amount` of the burn held on `payer` back to it.
+    fn release_held_burn(payer: &T::AccountId, amount: u64) {
+        if let Some(balance) = Self::u64_to_balance(amount) {
+            let _ = T::Currency::unreserve(payer, balance);
+        }
+    }
+
+    /// Appends the module, with the storage deposit reserved from `payer`.
     fn register_module(
         netuid: u16,
+        payer: &T::AccountId,
         module_key: &T::AccountId,
         name: Vec<u8>,
-        address: Vec<u8>,
-        metadata: Option<Vec<u8>>,
+        address: ModuleAddress,
+        metadata: MetadataChanges,
     ) -> Result<u16, DispatchError> {
         let fees = DefaultValidatorFees::<T>::get();
         let module_changeset = ModuleChangeset::new(name, address, fees, metadata);
-        Self::append_module(netuid, module_key, module_changeset)
+        let uid = Self::append_module(netuid, module_key, module_changeset)?;
+        Self::settle_module_deposit(netuid, uid, module_key, payer)?;
+        Ok(uid)
     }
 
     fn finalize_registration(netuid: u16, uid: u16, module_key: &T::AccountId) -> DispatchResult {
@@ -213,6 +637,8 @@ impl<T: Config> Pallet<T> {
         RegistrationsThisInterval::<T>::mutate(netuid, |registrations| {
             *registrations = registrations.saturating_add(1);
         });
+        // Approvals are single-use
+        ModuleRegistrationApprovals::<T>::remove(netuid, module_key);
 
         Self::deposit_event(Event::ModuleRegistered(netuid, uid, module_key.clone()));
 
@@ -242,23 +668,16 @@ impl<T: Config> Pallet<T> {
     ///
     /// When `ignore_immunity` is true (e.g., during global deregistration), the function
     /// disregards the immunity period and considers all peers for pruning.
+    ///
+    /// This is the `EmissionScore` pruning strategy.
     pub fn get_lowest_uid(netuid: u16, ignore_immunity: bool) -> Option<u16> {
-        let current_block = Self::get_current_block_number();
-        let immunity_period = ImmunityPeriod::<T>::get(netuid) as u64;
         let emission_vec = Emission::<T>::get(netuid);
         let dividend_vec = Dividends::<T>::get(netuid);
         let incentive_vec = Incentive::<T>::get(netuid);
+        let validator_permits = ValidatorPermits::<T>::get(netuid);
 
-        let uids: Vec<_> = RegistrationBlock::<T>::iter_prefix(netuid)
-            .filter(move |&(uid, block_at_registration)| {
-                if ignore_immunity
-                    || current_block.saturating_sub(block_at_registration) >= immunity_period
-                {
-                    !*ValidatorPermits::<T>::get(netuid).get(uid as usize).unwrap_or(&false)
-                } else {
-                    false
-                }
-            })
+        let uids: Vec<_> = Self::prunable_uids(netuid, ignore_immunity)
+            .filter(|&(uid, _)| !*validator_permits.get(uid as usize).unwrap_or(&false))
             .map(|(uid, block_at_registration)| {
                 let emission =
                     I110F18::from_num(emission_vec.get(uid as usize).copied().unwrap_or_default());
@@ -298,26 +717,81 @@ impl<T: Config> Pallet<T> {
             .map(|(uid, _, _)| *uid)
     }
 
+    /// Picks the module to evict from the full subnet following its pruning strategy. Returns
+    /// `None` if no module can be evicted.
+    pub fn get_uid_to_prune(netuid: u16, ignore_immunity: bool) -> Option<u16> {
+        let strategy = SubnetPruningStrategy::<T>::get(netuid);
+
+        // Modules that stopped sending heartbeats go first on subnets tracking them
+        if strategy != PruningStrategy::NoEviction
+            && SubnetActivitySource::<T>::get(netuid) == ActivitySource::Heartbeats
+        {
+            if let Some(uid) = Self::get_inactive_uid(netuid, ignore_immunity) {
+                return Some(uid);
+            }
+        }
+
+        match strategy {
+            PruningStrategy::EmissionScore => Self::get_lowest_uid(netuid, ignore_immunity),
+            PruningStrategy::LowestStake => Self::prunable_uids(netuid, ignore_immunity)
+                .filter_map(|(uid, block_at_registration)| {
+                    let key = Keys::<T>::get(netuid, uid)?;
+                    Some((uid, Self::get_delegated_stake(&key), block_at_registration))
+                })
+                .min_by_key(|&(_, stake, block_at_registration)| (stake, block_at_registration))
+                .map(|(uid, _, _)| uid),
+            PruningStrategy::OldestFirst => Self::prunable_uids(netuid, ignore_immunity)
+                .min_by_key(|&(_, block_at_registration)| block_at_registration)
+                .map(|(uid, _)| uid),
+            PruningStrategy::NoEviction => None,
+        }
+    }
+
+    /// Picks the module that has been inactive the longest, among those past the activity cutoff.
+    fn get_inactive_uid(netuid: u16, ignore_immunity: bool) -> Option<u16> {
+        let current_block = Self::get_current_block_number();
+        let activity_cutoff = MaxWeightAge::<T>::get(netuid);
+        let last_activity = Self::get_last_activity_for(netuid);
+
+        Self::prunable_uids(netuid, ignore_immunity)
+            .filter_map(|(uid, _)| {
+                let last_active = last_activity.get(uid as usize).copied()?;
+                (current_block.saturating_sub(last_active) > activity_cutoff)
+                    .then_some((uid, last_active))
+            })
+            .min_by_key(|&(uid, last_active)| (last_active, uid))
+            .map(|(uid, _)| uid)
+    }
+
+    /// Modules past their immunity period, along with the block they registered at.
+    fn prunable_uids(netuid: u16, ignore_immunity: bool) -> impl Iterator<Item = (u16, u64)> {
+        let current_block = Self::get_current_block_number();
+        let immunity_period = ImmunityPeriod::<T>::get(netuid) as u64;
+
+        RegistrationBlock::<T>::iter_prefix(netuid).filter(move |&(_, block_at_registration)| {
+            ignore_immunity
+                || current_block.saturating_sub(block_at_registration) >= immunity_period
+        })
+    }
+
     pub fn add_subnet_from_registration(changeset: SubnetChangeset<T>) -> DispatchResult {
         let num_subnets: u16 = Self::get_total_subnets();
         let max_subnets: u16 = MaxAllowedSubnets::<T>::get();
 
         // RESERVE SUBNET SLOT
         // if we have not reached the max number of subnets, then we can start a new one
-        let target_subnet = if num_subnets >= max_subnets {
+        if num_subnets >= max_subnets {
             let lowest_emission_netuid = T::get_lowest_emission_netuid(false);
             let netuid = lowest_emission_netuid.ok_or(sp_runtime::DispatchError::Other(
                 "No valid netuid to deregister",
             ))?;
 
-            // if the stake is greater than the least staked network, then we can start a new one
+            // if the stake is greater than the least staked network, then we can start a new one.
+            // The removed netuid is only reused once its storage is cleaned up.
             Self::remove_subnet(netuid);
-            Some(netuid)
-        } else {
-            None
-        };
+        }
 
-        Self::add_subnet(changeset, target_subnet)?;
+        Self::add_subnet(changeset, None)?;
         Ok(())
     }
 
@@ -338,7 +812,8 @@ impl<T: Config> Pallet<T> {
     ///
     /// # Behavior
     ///
-    /// 1. If the subnet is minable and at capacity, it replaces the lowest priority node.
+    /// 1. If the subnet is minable and at capacity, it replaces the module picked by the subnet
+    ///    pruning strategy.
     /// 2. If the global module limit is reached, it removes a node from the lowest emission subnet.
     /// 3. Otherwise, it allows the new module to be added.
     pub fn reserve_module_slot(netuid: u16, key: &T::AccountId) -> DispatchResult {
@@ -362,16 +837,23 @@ impl<T: Config> Pallet<T> {
     }
 
     fn replace_lowest_priority_node(netuid: u16, ignore_immunity: bool) -> DispatchResult {
-        if let Some(uid) = Self::get_lowest_uid(netuid, ignore_immunity) {
+        ensure!(
+            SubnetPruningStrategy::<T>::get(netuid) != PruningStrategy::NoEviction,
+            Error::<T>::SubnetIsFull
+        );
+
+        if let Some(uid) = Self::get_uid_to_prune(netuid, ignore_immunity) {
             Self::remove_module(netuid, uid, false)
         } else {
             Err(Error::<T>::NetworkIsImmuned.into())
         }
     }
 
+    /// Evicts a module from the lowest emission subnet that allows evictions, picked by the
+    /// pruning strategy of that subnet.
     fn remove_from_lowest_emission_subnet() -> DispatchResult {
-        if let Some(subnet_id) = T::get_lowest_emission_netuid(true) {
-            if let Some(module_uid) = Self::get_lowest_uid(subnet_id, true) {
+        if let Some(subnet_id) = T::get_lowest_evictable_netuid() {
+            if let Some(module_uid) = Self::get_uid_to_prune(subnet_id, true) {
                 Self::remove_module(subnet_id, module_uid, true)
             } else {
                 Err(Error::<T>::NetworkIsImmuned.into())
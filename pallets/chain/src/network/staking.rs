@@ -175,6 +175,9 @@ impl<T: Config> Pallet<T> {
         // T::AccountId key information.
         let key = ensure_signed(origin)?;
 
+        // --- 1.1 Staking may be halted by an emergency pause.
+        Self::ensure_not_paused(PausableOperation::Staking)?;
+
         // --- 2. We check that the caller has enough balance to stake.
         ensure!(
             Self::has_enough_balance(&key, amount),
@@ -1,19 +1,31 @@
 use crate::*;
-use frame_support::pallet_prelude::DispatchResult;
+use frame_support::{pallet_prelude::DispatchResult, storage::with_storage_layer, traits::Get};
 use pallet_governance_api::GovernanceApi;
 use pallet_emission_api::{SubnetConsensus, SubnetEmissionApi};
+use scale_info::TypeInfo;
 use sp_runtime::DispatchError;
 use substrate_fixed::types::I64F64;
 
+/// Maximum amount of storage entries removed per block while cleaning up tombstoned subnets.
+const MAX_SUBNET_CLEANUP_BATCH: u32 = 1_000;
+
+/// Lifecycle of a subnet. Dissolving subnets take no new registrations nor weights, and are
+/// removed once their grace period is over.
+#[derive(Clone, Copy, TypeInfo, Decode, Encode, PartialEq, Eq, Debug, Default)]
+pub enum SubnetState {
+    #[default]
+    Active,
+    Dissolving {
+        removal_block: u64,
+    },
+}
+
 impl<T: Config> Pallet<T> {
     pub fn add_subnet(
         changeset: SubnetChangeset<T>,
         netuid: Option<u16>,
     ) -> Result<u16, DispatchError> {
-        let netuid = netuid.unwrap_or_else(|| match SubnetGaps::<T>::get().first().copied() {
-            Some(removed) => removed,
-            None => Self::get_total_subnets(),
-        });
+        let netuid = netuid.unwrap_or_else(Self::next_available_netuid);
 
         let name = changeset.params().name.clone();
         changeset.apply(netuid)?;
@@ -28,6 +40,10 @@ impl<T: Config> Pallet<T> {
         Burn::<T>::set(netuid, min_burn);
 
         SubnetGaps::<T>::mutate(|subnets| subnets.remove(&netuid));
+        SubnetGapFreedAt::<T>::remove(netuid);
+        SubnetGeneration::<T>::mutate(netuid, |generation| {
+            *generation = generation.saturating_add(1)
+        });
         T::create_yuma_subnet(netuid);
 
         // --- 6. Emit the new network event.
@@ -36,34 +52,57 @@ impl<T: Config> Pallet<T> {
         Ok(netuid)
     }
 
-    fn clear_subnet_includes(netuid: u16) {
-        for storage_type in SubnetIncludes::all() {
-            storage_type.remove_storage::<T>(netuid)
-        }
-    }
+    /// Picks the lowest gap whose reuse cooldown is over, or a never used netuid otherwise.
+    fn next_available_netuid() -> u16 {
+        let current_block = Self::get_current_block_number();
+        let cooldown = NetuidReuseCooldown::<T>::get();
+        let gaps = SubnetGaps::<T>::get();
 
-    pub fn clear_subnet_only_accounts_data(subnet_id: u16) {
-        // Get all accounts in the specified subnet
-        let subnet_accounts: BTreeSet<AccountIdOf<T>> =
-            Uids::<T>::iter_prefix(subnet_id).map(|(account, _)| account).collect();
+        let reusable = gaps.iter().copied().find(|netuid| {
+            SubnetGapFreedAt::<T>::get(netuid).map_or(true, |freed_at| {
+                current_block.saturating_sub(freed_at) >= cooldown
+            })
+        });
 
-        // Get all accounts from other subnets
-        let accounts_in_other_subnets: BTreeSet<AccountIdOf<T>> = Uids::<T>::iter()
-            .filter(|(net_id, _, _)| net_id != &subnet_id)
-            .map(|(_, account, _)| account)
-            .collect();
+        // Tombstoned subnets and cooling gaps still hold their netuid
+        reusable.unwrap_or_else(|| {
+            Self::get_total_subnets()
+                .saturating_add(TombstonedSubnets::<T>::get().len() as u16)
+                .saturating_add(gaps.len() as u16)
+        })
+    }
 
-        // Clear data for accounts that exist only in this subnet
-        subnet_accounts
-            .difference(&accounts_in_other_subnets)
-            .for_each(|subnet_only_account| {
+    fn clear_subnet_includes(netuid: u16, limit: u32) -> (u32, bool) {
+        SubnetIncludes::clear_limited::<T>(netuid, limit)
+    }
+
+    /// Clears the stake & validator fees of up to `limit` accounts of the subnet that are not
+    /// registered on any other subnet, removing their uids along the way. Returns the amount of
+    /// visited accounts and whether the subnet has no accounts left.
+    pub fn clear_subnet_only_accounts_data(subnet_id: u16, limit: u32) -> (u32, bool) {
+        let subnet_accounts: Vec<AccountIdOf<T>> =
+            Uids::<T>::iter_key_prefix(subnet_id).take(limit as usize).collect();
+
+        let mut visited: u32 = 0;
+        for account in subnet_accounts {
+            // The subnet is no longer live, so this only looks at the other subnets
+            if !Self::is_registered(None, &account) {
                 // Clear stakes
-                Self::remove_stake_from_storage(subnet_only_account);
+                Self::remove_stake_from_storage(&account);
                 // Clear validator fees
-                ValidatorFeeConfig::<T>::remove(subnet_only_account);
-            });
+                ValidatorFeeConfig::<T>::remove(&account);
+            }
+
+            Uids::<T>::remove(subnet_id, &account);
+            visited = visited.saturating_add(1);
+        }
+
+        let cleared = Uids::<T>::iter_key_prefix(subnet_id).next().is_none();
+        (visited, cleared)
     }
 
+    /// Removes the subnet from the live set right away. The rest of its storage is cleaned up in
+    /// batches by `cleanup_tombstoned_subnets`, after which the netuid can be reused.
     pub fn remove_subnet(netuid: u16) {
         if !Self::if_subnet_exist(netuid) {
             return;
@@ -73,25 +112,93 @@ impl<T: Config> Pallet<T> {
             return;
         }
 
-        // --- Delete Global-Subnet Storage ---
+        // --- Remove The Subnet From The Live Set ---
+
+        N::<T>::remove(netuid);
+        SubnetNames::<T>::remove(netuid);
+        Tempo::<T>::remove(netuid);
+
+        // --- Tombstone The Subnet & Emit The Event ---
+
+        TombstonedSubnets::<T>::mutate(|subnets| subnets.insert(netuid));
 
+        Self::deposit_event(Event::NetworkRemoved(netuid));
+    }
+
+    /// Deletes up to `limit` storage entries of a removed subnet. Returns the amount of removed
+    /// entries and whether the subnet storage is fully cleaned up.
+    fn cleanup_subnet(netuid: u16, limit: u32) -> (u32, bool) {
         // Potentially Remove Stake & Delegation Fee
         // Automatically remove the stake & delegation fee of modules that are only registered on
         // this subnet. This is because it's not desirable for module to be **globally**
         // unregistered with "active" stake storage or "active" delegation fee storage.
-        Self::clear_subnet_only_accounts_data(netuid);
+        let steps: [fn(u16, u32) -> (u32, bool); 6] = [
+            Self::clear_subnet_only_accounts_data,
+            // --- Release The Storage Deposits Of The Subnet & Its Modules ---
+            Self::release_storage_deposits,
+            // --- Destroy The Burn Escrows Left By Its Modules ---
+            Self::forfeit_registration_escrows,
+            // --- Delete Subnet Includes Storage For All Pallets ---
+            Self::clear_subnet_includes,
+            <T as GovernanceApi<T::AccountId>>::clear_subnet_includes,
+            <T as SubnetEmissionApi<T::AccountId>>::clear_subnet_includes,
+        ];
+
+        let mut removed: u32 = 0;
+        for step in steps {
+            let remaining = limit.saturating_sub(removed);
+            if remaining == 0 {
+                return (removed, false);
+            }
 
-        // --- Delete Subnet Includes Storage For All Pallets ---
+            let (loops, done) = step(netuid, remaining);
+            removed = removed.saturating_add(loops);
+            if !done {
+                return (removed, false);
+            }
+        }
 
-        Self::clear_subnet_includes(netuid);
-        <T as GovernanceApi<T::AccountId>>::clear_subnet_includes(netuid);
-        <T as SubnetEmissionApi<T::AccountId>>::clear_subnet_includes(netuid);
+        (removed, true)
+    }
 
-        // --- Mutate Subnet Gaps & Emit The Event ---
+    /// Cleans up the storage of tombstoned subnets in batches, as long as the weight budget
+    /// allows. Once a subnet is fully cleaned up, its netuid is made available for reuse.
+    pub fn cleanup_tombstoned_subnets(remaining_weight: Weight) -> Weight {
+        let db_weight = T::DbWeight::get();
+        let entry_weight = db_weight.reads_writes(1, 1);
 
-        SubnetGaps::<T>::mutate(|subnets| subnets.insert(netuid));
+        let mut used_weight = db_weight.reads(1);
+        if used_weight.any_gt(remaining_weight) {
+            return Weight::zero();
+        }
 
-        Self::deposit_event(Event::NetworkRemoved(netuid));
+        for netuid in TombstonedSubnets::<T>::get() {
+            let budget = remaining_weight.saturating_sub(used_weight);
+            let limit = budget
+                .ref_time()
+                .checked_div(entry_weight.ref_time())
+                .map_or(MAX_SUBNET_CLEANUP_BATCH, |limit| {
+                    limit.min(MAX_SUBNET_CLEANUP_BATCH.into()) as u32
+                });
+            if limit == 0 {
+                break;
+            }
+
+            let (removed, done) = Self::cleanup_subnet(netuid, limit);
+            used_weight = used_weight.saturating_add(entry_weight.saturating_mul(removed.into()));
+            if !done {
+                break;
+            }
+
+            TombstonedSubnets::<T>::mutate(|subnets| subnets.remove(&netuid));
+            SubnetGaps::<T>::mutate(|subnets| subnets.insert(netuid));
+            SubnetGapFreedAt::<T>::insert(netuid, Self::get_current_block_number());
+            used_weight = used_weight.saturating_add(db_weight.writes(3));
+
+            Self::deposit_event(Event::NetworkCleanedUp(netuid));
+        }
+
+        used_weight
     }
 
     pub fn do_update_subnet(
@@ -110,13 +217,239 @@ impl<T: Config> Pallet<T> {
         // --2. Ensury Authority - only the founder can update the network on authority mode.
         ensure!(Founder::<T>::get(netuid) == key, Error::<T>::NotFounder);
 
-        // -4. Apply the changeset.
-        changeset.apply(netuid)?;
+        // -4. Apply the changeset. Changes to the subnet economics are only announced, and
+        // apply once the notice period is over. A new update replaces the announced one, and an
+        // update keeping the current economics cancels it.
+        let current = Self::subnet_params(netuid);
+        let params = SubnetParams::clone(changeset.params());
+        let notice_period = SubnetParamsNoticePeriod::<T>::get();
+
+        if notice_period == 0 || params.clone().with_non_economic_fields_of(&current) == current {
+            if PendingSubnetParams::<T>::take(netuid).is_some() {
+                Self::deposit_event(Event::SubnetParamsChangeCancelled(netuid));
+            }
+            changeset.apply(netuid)?;
+            return Ok(());
+        }
+
+        let immediate = current.with_non_economic_fields_of(&params);
+        SubnetChangeset::update(netuid, immediate)?.apply(netuid)?;
+
+        let apply_block = Self::get_current_block_number().saturating_add(notice_period);
+        PendingSubnetParams::<T>::insert(netuid, (params, apply_block));
+        Self::deposit_event(Event::SubnetParamsChangeScheduled(netuid, apply_block));
 
         // --- 5. Ok and done.
         Ok(())
     }
 
+    /// Applies the announced subnet parameters whose notice period is over. The fields that
+    /// don't affect the subnet economics keep their latest values.
+    pub fn apply_scheduled_subnet_params(block_number: u64) {
+        let due: Vec<_> = PendingSubnetParams::<T>::iter()
+            .filter(|(_, (_, apply_block))| *apply_block <= block_number)
+            .collect();
+
+        for (netuid, (params, _)) in due {
+            PendingSubnetParams::<T>::remove(netuid);
+
+            let params = params.with_non_economic_fields_of(&Self::subnet_params(netuid));
+            let applied =
+                with_storage_layer(|| SubnetChangeset::update(netuid, params)?.apply(netuid));
+            if applied.is_err() {
+                Self::deposit_event(Event::SubnetParamsChangeDropped(netuid));
+            }
+        }
+    }
+
+    /// Allows the module key to register on the subnet while it is in `Allowlist` mode. Only the
+    /// subnet founder can manage the allowlist.
+    pub fn do_add_to_allowlist(
+        origin: T::RuntimeOrigin,
+        netuid: u16,
+        module_key: T::AccountId,
+    ) -> DispatchResult {
+        Self::ensure_subnet_founder(origin, netuid)?;
+        ensure!(
+            !ModuleAllowlist::<T>::contains_key(netuid, &module_key),
+            Error::<T>::ModuleAlreadyAllowlisted
+        );
+
+        ModuleAllowlist::<T>::insert(netuid, &module_key, ());
+        Self::deposit_event(Event::ModuleAllowlisted(netuid, module_key));
+        Ok(())
+    }
+
+    /// Takes the module key off the subnet allowlist. Modules already registered are kept.
+    pub fn do_remove_from_allowlist(
+        origin: T::RuntimeOrigin,
+        netuid: u16,
+        module_key: T::AccountId,
+    ) -> DispatchResult {
+        Self::ensure_subnet_founder(origin, netuid)?;
+        ensure!(
+            ModuleAllowlist::<T>::contains_key(netuid, &module_key),
+            Error::<T>::ModuleNotAllowlisted
+        );
+
+        ModuleAllowlist::<T>::remove(netuid, &module_key);
+        Self::deposit_event(Event::ModuleRemovedFromAllowlist(netuid, module_key));
+        Ok(())
+    }
+
+    /// Approves the module key to register once on the subnet while it is in `FounderApproval`
+    /// mode. Anyone can then pay for the registration, which uses the approval up.
+    pub fn do_approve_module_registration(
+        origin: T::RuntimeOrigin,
+        netuid: u16,
+        module_key: T::AccountId,
+    ) -> DispatchResult {
+        Self::ensure_subnet_founder(origin, netuid)?;
+        ensure!(
+            !ModuleRegistrationApprovals::<T>::contains_key(netuid, &module_key),
+            Error::<T>::ModuleRegistrationAlreadyApproved
+        );
+
+        ModuleRegistrationApprovals::<T>::insert(netuid, &module_key, ());
+        Self::deposit_event(Event::ModuleRegistrationApproved(netuid, module_key));
+        Ok(())
+    }
+
+    /// Withdraws a registration approval the module key hasn't used yet.
+    pub fn do_revoke_module_registration_approval(
+        origin: T::RuntimeOrigin,
+        netuid: u16,
+        module_key: T::AccountId,
+    ) -> DispatchResult {
+        Self::ensure_subnet_founder(origin, netuid)?;
+        ensure!(
+            ModuleRegistrationApprovals::<T>::contains_key(netuid, &module_key),
+            Error::<T>::ModuleRegistrationNotApproved
+        );
+
+        ModuleRegistrationApprovals::<T>::remove(netuid, &module_key);
+        Self::deposit_event(Event::ModuleRegistrationApprovalRevoked(netuid, module_key));
+        Ok(())
+    }
+
+    /// Proposes to hand the subnet over to another account. The transfer only happens once the
+    /// new founder accepts it, so a mistyped account can't take the subnet and its founder
+    /// share. A new proposal replaces the pending one.
+    pub fn do_propose_founder_transfer(
+        origin: T::RuntimeOrigin,
+        netuid: u16,
+        new_founder: T::AccountId,
+    ) -> DispatchResult {
+        Self::ensure_subnet_founder(origin, netuid)?;
+        Self::propose_founder_transfer(netuid, new_founder);
+        Ok(())
+    }
+
+    pub fn propose_founder_transfer(netuid: u16, new_founder: T::AccountId) {
+        let expires_at =
+            Self::get_current_block_number().saturating_add(FounderTransferPeriod::<T>::get());
+        PendingFounder::<T>::insert(netuid, (&new_founder, expires_at));
+        Self::deposit_event(Event::FounderTransferProposed(
+            netuid,
+            new_founder,
+            expires_at,
+        ));
+    }
+
+    /// Makes the caller the founder of the subnet, if it is the pending founder and the
+    /// transfer didn't expire yet.
+    pub fn do_accept_founder_transfer(origin: T::RuntimeOrigin, netuid: u16) -> DispatchResult {
+        let key = ensure_signed(origin)?;
+
+        let (pending_founder, expires_at) =
+            PendingFounder::<T>::get(netuid).ok_or(Error::<T>::NoPendingFounderTransfer)?;
+        ensure!(pending_founder == key, Error::<T>::NotPendingFounder);
+        ensure!(
+            Self::get_current_block_number() <= expires_at,
+            Error::<T>::FounderTransferExpired
+        );
+
+        PendingFounder::<T>::remove(netuid);
+        let previous_founder = Founder::<T>::get(netuid);
+        Founder::<T>::insert(netuid, &key);
+
+        Self::deposit_event(Event::FounderTransferred(netuid, previous_founder, key));
+        Ok(())
+    }
+
+    /// Starts winding the subnet down. The emission of the current epoch is paid out on the
+    /// weights set so far, which are frozen from then on. Modules keep their registrations and the
+    /// subnet keeps running epochs on those weights, until it is removed at the end of the grace
+    /// period.
+    pub fn do_dissolve_subnet(origin: T::RuntimeOrigin, netuid: u16) -> DispatchResult {
+        Self::ensure_subnet_founder(origin, netuid)?;
+        Self::ensure_subnet_active(netuid)?;
+        ensure!(
+            T::can_remove_subnet(netuid),
+            Error::<T>::CannotDissolveSubnet
+        );
+
+        T::distribute_pending_emission(netuid);
+
+        let removal_block =
+            Self::get_current_block_number().saturating_add(SubnetDissolutionPeriod::<T>::get());
+        SubnetStates::<T>::insert(netuid, SubnetState::Dissolving { removal_block });
+
+        Self::deposit_event(Event::SubnetDissolving(netuid, removal_block));
+        Ok(())
+    }
+
+    pub fn ensure_subnet_active(netuid: u16) -> DispatchResult {
+        ensure!(
+            SubnetStates::<T>::get(netuid) == SubnetState::Active,
+            Error::<T>::SubnetDissolving
+        );
+        Ok(())
+    }
+
+    /// Fails if the netuid was taken by another subnet than the one the caller expects.
+    pub fn ensure_subnet_generation(netuid: u16, expected_generation: u64) -> DispatchResult {
+        ensure!(
+            SubnetGeneration::<T>::get(netuid) == expected_generation,
+            Error::<T>::SubnetGenerationMismatch
+        );
+        Ok(())
+    }
+
+    /// Pays out the pending emission of dissolving subnets that reached their removal block, and
+    /// removes them. Stake on modules registered only on those subnets goes back to the stakers.
+    pub fn remove_dissolved_subnets(block_number: u64) {
+        let dissolved: Vec<u16> = SubnetStates::<T>::iter()
+            .filter_map(|(netuid, state)| match state {
+                SubnetState::Dissolving { removal_block } if removal_block <= block_number => {
+                    Some(netuid)
+                }
+                _ => None,
+            })
+            .collect();
+
+        for netuid in dissolved {
+            // The subnet might have moved to a consensus that can't be removed in the meantime
+            SubnetStates::<T>::remove(netuid);
+            if !T::can_remove_subnet(netuid) {
+                continue;
+            }
+
+            T::distribute_pending_emission(netuid);
+            Self::remove_subnet(netuid);
+        }
+    }
+
+    fn ensure_subnet_founder(origin: T::RuntimeOrigin, netuid: u16) -> DispatchResult {
+        let key = ensure_signed(origin)?;
+        ensure!(
+            SubnetNames::<T>::contains_key(netuid),
+            Error::<T>::NetuidDoesNotExist
+        );
+        ensure!(Founder::<T>::get(netuid) == key, Error::<T>::NotFounder);
+        Ok(())
+    }
+
     // --- Setters ---
 
     pub fn set_max_allowed_uids(netuid: u16, max_allowed_uids: u16) -> DispatchResult {
@@ -164,6 +497,11 @@ impl<T: Config> Pallet<T> {
     pub fn get_uid_for_key(netuid: u16, key: &T::AccountId) -> Option<u16> {
         Uids::<T>::get(netuid, key)
     }
+    /// Returns the uid of the module with this name in the network, look-alike names included.
+    #[inline]
+    pub fn get_uid_for_name(netuid: u16, name: &[u8]) -> Option<u16> {
+        ModuleNameIndex::<T>::get(netuid, canonical_module_name(name))
+    }
 
     pub fn get_current_block_number() -> u64 {
         TryInto::try_into(<frame_system::Pallet<T>>::block_number())
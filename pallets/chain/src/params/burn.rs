@@ -1,9 +1,47 @@
 use crate::*;
 use frame_support::pallet_prelude::MaxEncodedLen;
 use scale_info::TypeInfo;
+use sp_arithmetic::per_things::{Perbill, Percent};
 use sp_core::Get;
 use sp_runtime::DispatchError;
 
+/// Refunds part of the registration burn to modules that deregister on their own. The refund
+/// decays linearly with the blocks passed since the module registered.
+#[derive(Clone, Copy, TypeInfo, Decode, Encode, PartialEq, Eq, Debug, MaxEncodedLen)]
+pub struct BurnRefundCurve {
+    /// share of the burn refunded right after registering, which is
+    /// held on the payer instead of being burned
+    pub initial_refund: Percent,
+    /// blocks after registration at which the refund reaches zero
+    pub decay_period: u64,
+}
+
+impl BurnRefundCurve {
+    /// The part of the escrowed burn refunded after the given amount of blocks.
+    pub fn refund(&self, escrowed: u64, blocks_since_registration: u64) -> u64 {
+        let remaining = self.decay_period.saturating_sub(blocks_since_registration);
+        Perbill::from_rational(remaining, self.decay_period).mul_floor(escrowed)
+    }
+}
+
+/// Where a registration burn goes. Whatever is not sent to the DAO treasury or to the subnet
+/// founder is destroyed.
+#[derive(Clone, Copy, TypeInfo, Decode, Encode, PartialEq, Eq, Debug, Default, MaxEncodedLen)]
+pub struct BurnDestination {
+    /// share of the burn sent to the DAO treasury
+    pub treasury: Percent,
+    /// share of the burn sent to the subnet founder, only used for
+    /// module registrations, as subnets are registered by their founders
+    pub founder: Percent,
+}
+
+impl BurnDestination {
+    /// Splits the burn into the amounts sent to the treasury and to the founder.
+    pub fn split(&self, burn: u64) -> (u64, u64) {
+        (self.treasury.mul_floor(burn), self.founder.mul_floor(burn))
+    }
+}
+
 /// This struct is used for both global (Subnet Burn) and MAP parameters (Module Burn)
 #[derive(
     Clone, TypeInfo, Decode, Encode, PartialEq, Eq, frame_support::DebugNoBound, MaxEncodedLen,
@@ -24,6 +62,11 @@ pub struct GeneralBurnConfiguration<T> {
     pub target_registrations_per_interval: u16,
     /// the maximum number of registrations accepted per interval
     pub max_registrations_per_interval: u16,
+    /// optional refund of the burn on voluntary deregistration,
+    /// only used for module registrations
+    pub refund_curve: Option<BurnRefundCurve>,
+    /// where the burn goes, destroyed by default
+    pub destination: BurnDestination,
     pub _pd: PhantomData<T>,
 }
 
@@ -47,6 +90,8 @@ impl<T: Config> GeneralBurnConfiguration<T> {
             target_registrations_interval: 5_400,
             target_registrations_per_interval: 1,
             max_registrations_per_interval: T::DefaultMaxSubnetRegistrationsPerInterval::get(),
+            refund_curve: None,
+            destination: BurnDestination::default(),
             _pd: PhantomData,
         }
     }
@@ -59,6 +104,8 @@ impl<T: Config> GeneralBurnConfiguration<T> {
             target_registrations_interval: 142,
             target_registrations_per_interval: 3,
             max_registrations_per_interval: T::DefaultMaxRegistrationsPerInterval::get(),
+            refund_curve: None,
+            destination: BurnDestination::default(),
             _pd: PhantomData,
         }
     }
@@ -79,6 +126,8 @@ impl<T: Config> GeneralBurnConfiguration<T> {
             target_registrations_interval,
             target_registrations_per_interval,
             max_registrations_per_interval,
+            refund_curve,
+            destination,
             _pd: _,
         } = self;
 
@@ -104,6 +153,14 @@ impl<T: Config> GeneralBurnConfiguration<T> {
             max_registrations_per_interval >= target_registrations_per_interval,
             Error::<T>::InvalidMaxRegistrationsPerInterval
         );
+        if let Some(curve) = refund_curve {
+            ensure!(curve.decay_period > 0, Error::<T>::InvalidBurnRefundCurve);
+        }
+        let destination_share = destination
+            .treasury
+            .deconstruct()
+            .saturating_add(destination.founder.deconstruct());
+        ensure!(destination_share <= 100, Error::<T>::InvalidBurnDestination);
 
         ModuleBurnConfig::<T>::set(netuid, self);
 
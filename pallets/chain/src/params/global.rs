@@ -1,6 +1,9 @@
 use crate::*;
-use frame_support::pallet_prelude::{DispatchResult, MaxEncodedLen};
-use pallet_governance_api::GovernanceConfiguration;
+use frame_support::{
+    pallet_prelude::{ConstU32, DispatchResult, MaxEncodedLen},
+    BoundedBTreeSet,
+};
+use pallet_governance_api::{GovernanceConfiguration, MAX_CURATOR_COUNCIL_MEMBERS};
 use scale_info::TypeInfo;
 use sp_arithmetic::per_things::Percent;
 
@@ -24,7 +27,8 @@ pub struct GlobalParams<T: Config> {
     pub min_weight_stake: u64,               // min weight stake required
 
     // S0 governance
-    pub curator: T::AccountId,
+    pub curator_council: BoundedBTreeSet<T::AccountId, ConstU32<MAX_CURATOR_COUNCIL_MEMBERS>>,
+    pub curator_threshold: u32,
     pub general_subnet_application_cost: u64,
 
     // Other
@@ -37,13 +41,16 @@ pub struct GlobalParams<T: Config> {
 
 impl<T: Config> Pallet<T> {
     pub fn global_params() -> GlobalParams<T> {
+        let (curator_council, curator_threshold) = T::get_curator_council();
+
         GlobalParams {
             // network
             max_name_length: MaxNameLength::<T>::get(),
             min_name_length: MinNameLength::<T>::get(),
             max_allowed_subnets: MaxAllowedSubnets::<T>::get(),
             max_allowed_modules: MaxAllowedModules::<T>::get(),
-            curator: T::get_curator(),
+            curator_council: BoundedBTreeSet::try_from(curator_council).unwrap_or_default(),
+            curator_threshold,
             floor_founder_share: FloorFounderShare::<T>::get(),
             floor_stake_delegation_fee: MinFees::<T>::get().stake_delegation_fee,
             floor_validator_weight_fee: MinFees::<T>::get().validator_weight_fee,
@@ -78,7 +85,8 @@ impl<T: Config> Pallet<T> {
             floor_validator_weight_fee,
             floor_founder_share,
             min_weight_stake,
-            curator,
+            curator_council,
+            curator_threshold,
             general_subnet_application_cost,
             subnet_immunity_period,
             governance_config,
@@ -104,7 +112,7 @@ impl<T: Config> Pallet<T> {
         MinWeightStake::<T>::put(min_weight_stake);
 
         // Governance and administrative parameters
-        T::set_curator(&curator);
+        T::set_curator_council(curator_council.into_inner(), curator_threshold)?;
         FloorFounderShare::<T>::put(floor_founder_share);
         SubnetImmunityPeriod::<T>::put(subnet_immunity_period);
         T::update_global_governance_configuration(governance_config)
@@ -132,7 +140,8 @@ impl<T: Config> Pallet<T> {
             floor_validator_weight_fee,
             floor_founder_share,
             min_weight_stake: _,
-            curator: _,
+            curator_council,
+            curator_threshold,
             general_subnet_application_cost,
             subnet_immunity_period,
             governance_config,
@@ -182,6 +191,12 @@ impl<T: Config> Pallet<T> {
             Error::<T>::InvalidMaxAllowedWeights
         );
 
+        // Curator council validations
+        ensure!(
+            *curator_threshold > 0 && *curator_threshold as usize <= curator_council.len(),
+            Error::<T>::InvalidCuratorCouncil
+        );
+
         // Cost and stake validations
         ensure!(
             *general_subnet_application_cost > 0,
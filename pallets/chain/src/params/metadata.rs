@@ -0,0 +1,161 @@
+use crate::*;
+use frame_support::{
+    pallet_prelude::{ConstU32, MaxEncodedLen},
+    BoundedBTreeMap, BoundedVec,
+};
+use scale_info::TypeInfo;
+use sp_runtime::DispatchError;
+
+/// Maximum amount of entries a module or subnet metadata map can hold.
+pub const MAX_METADATA_ENTRIES: u32 = 16;
+/// Maximum length of a custom metadata key.
+pub const MAX_METADATA_KEY_LENGTH: u32 = 32;
+/// Maximum length of a metadata value. Some well-known keys have tighter limits.
+pub const MAX_METADATA_VALUE_LENGTH: u32 = 256;
+
+/// Custom key the metadata written before it was structured is migrated under.
+pub const LEGACY_METADATA_KEY: &[u8] = b"legacy";
+
+/// A metadata key. Well-known keys have a fixed meaning every client can rely on.
+#[derive(Decode, Encode, PartialEq, Eq, PartialOrd, Ord, Clone, Debug, TypeInfo, MaxEncodedLen)]
+pub enum MetadataKey {
+    /// The IPFS CID of a document describing the module or subnet.
+    IpfsCid,
+    /// The website URL.
+    Website,
+    /// A URL or IPFS CID pointing to the API schema.
+    ApiSchema,
+    /// The software version.
+    Version,
+    /// A custom key, made of lowercase ASCII letters, digits and underscores.
+    Custom(BoundedVec<u8, ConstU32<MAX_METADATA_KEY_LENGTH>>),
+}
+
+impl MetadataKey {
+    const WELL_KNOWN: [Self; 4] = [Self::IpfsCid, Self::Website, Self::ApiSchema, Self::Version];
+
+    /// The key name, e.g. `ipfs_cid`.
+    pub fn name(&self) -> &[u8] {
+        match self {
+            Self::IpfsCid => b"ipfs_cid",
+            Self::Website => b"website",
+            Self::ApiSchema => b"api_schema",
+            Self::Version => b"version",
+            Self::Custom(key) => key,
+        }
+    }
+
+    /// The longest value accepted for this key.
+    pub fn max_value_length(&self) -> usize {
+        match self {
+            Self::IpfsCid => 64,
+            Self::Website => 128,
+            Self::Version => 32,
+            Self::ApiSchema | Self::Custom(_) => MAX_METADATA_VALUE_LENGTH as usize,
+        }
+    }
+
+    /// Custom keys can't be empty or shadow a well-known key.
+    fn is_valid(&self) -> bool {
+        let Self::Custom(key) = self else {
+            return true;
+        };
+
+        !key.is_empty()
+            && key.iter().all(|b| b.is_ascii_lowercase() || b.is_ascii_digit() || *b == b'_')
+            && Self::WELL_KNOWN.iter().all(|well_known| well_known.name() != key.as_slice())
+    }
+}
+
+pub type MetadataValue = BoundedVec<u8, ConstU32<MAX_METADATA_VALUE_LENGTH>>;
+
+/// The metadata of a module or subnet.
+pub type MetadataMap = BoundedBTreeMap<MetadataKey, MetadataValue, ConstU32<MAX_METADATA_ENTRIES>>;
+
+/// Changes to a metadata map: `Some` sets the key to the value, `None` clears the key.
+pub type MetadataChanges = Vec<(MetadataKey, Option<Vec<u8>>)>;
+
+/// Whose metadata is handled, picking the errors invalid metadata fails with.
+#[derive(Clone, Copy)]
+pub enum MetadataOwner {
+    Module,
+    Subnet,
+}
+
+impl MetadataOwner {
+    fn invalid<T: Config>(self) -> Error<T> {
+        match self {
+            Self::Module => Error::<T>::InvalidModuleMetadata,
+            Self::Subnet => Error::<T>::InvalidSubnetMetadata,
+        }
+    }
+
+    fn too_long<T: Config>(self) -> Error<T> {
+        match self {
+            Self::Module => Error::<T>::ModuleMetadataTooLong,
+            Self::Subnet => Error::<T>::SubnetMetadataTooLong,
+        }
+    }
+}
+
+/// Maps the free-form metadata written before it was structured onto a metadata map. IPFS CIDs
+/// become [`MetadataKey::IpfsCid`], anything else is kept under [`LEGACY_METADATA_KEY`].
+pub fn legacy_metadata(metadata: &[u8]) -> MetadataMap {
+    let is_cid =
+        (metadata.len() == 46 && metadata.starts_with(b"Qm")) || metadata.starts_with(b"bafy");
+    let key = if is_cid {
+        MetadataKey::IpfsCid
+    } else {
+        MetadataKey::Custom(BoundedVec::truncate_from(LEGACY_METADATA_KEY.to_vec()))
+    };
+
+    let mut map = MetadataMap::new();
+    if !metadata.is_empty() && metadata.len() <= key.max_value_length() {
+        let _ = map.try_insert(key, BoundedVec::truncate_from(metadata.to_vec()));
+    }
+    map
+}
+
+impl<T: Config> Pallet<T> {
+    /// Checks a single metadata entry, returning the value bounded.
+    fn validate_metadata_entry(
+        key: &MetadataKey,
+        value: &[u8],
+        owner: MetadataOwner,
+    ) -> Result<MetadataValue, DispatchError> {
+        ensure!(key.is_valid(), owner.invalid::<T>());
+        ensure!(!value.is_empty(), owner.invalid::<T>());
+        ensure!(value.len() <= key.max_value_length(), owner.too_long::<T>());
+        core::str::from_utf8(value).map_err(|_| owner.invalid::<T>())?;
+        Ok(BoundedVec::truncate_from(value.to_vec()))
+    }
+
+    /// Checks every entry of a metadata map.
+    pub fn validate_metadata(metadata: &MetadataMap, owner: MetadataOwner) -> DispatchResult {
+        for (key, value) in metadata.iter() {
+            Self::validate_metadata_entry(key, value, owner)?;
+        }
+        Ok(())
+    }
+
+    /// Applies the changes on top of `metadata`, in order.
+    pub fn apply_metadata_changes(
+        metadata: MetadataMap,
+        changes: MetadataChanges,
+        owner: MetadataOwner,
+    ) -> Result<MetadataMap, DispatchError> {
+        let mut metadata = metadata;
+        for (key, value) in changes {
+            match value {
+                Some(value) => {
+                    let value = Self::validate_metadata_entry(&key, &value, owner)?;
+                    metadata.try_insert(key, value).map_err(|_| owner.too_long::<T>())?;
+                }
+                None => {
+                    metadata.remove(&key);
+                }
+            }
+        }
+        Ok(metadata)
+    }
+}
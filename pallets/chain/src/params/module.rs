@@ -1,22 +1,122 @@
 use crate::*;
+use core::{
+    net::{Ipv4Addr, Ipv6Addr},
+    str::FromStr,
+};
 use scale_info::TypeInfo;
 
+/// Longest DNS host name a module may advertise.
+const MAX_HOST_LENGTH: usize = 253;
+/// Longest textual multiaddr a module may advertise.
+const MAX_MULTIADDR_LENGTH: usize = 256;
+
+/// The network address a module advertises for peer discovery.
+#[derive(Decode, Encode, PartialEq, Eq, Clone, Debug, TypeInfo)]
+pub enum ModuleAddress {
+    /// An IPv4 address and port.
+    Ipv4 { ip: [u8; 4], port: u16 },
+    /// An IPv6 address and port.
+    Ipv6 { ip: [u8; 16], port: u16 },
+    /// A DNS host name and port.
+    Dns { host: Vec<u8>, port: u16 },
+    /// A libp2p multiaddr in its textual form, e.g. `/ip4/1.2.3.4/tcp/30333`.
+    Multiaddr(Vec<u8>),
+}
+
+impl ModuleAddress {
+    /// Parses a legacy free-form address, like `1.2.3.4:30333`, `[::1]:30333`,
+    /// `https://example.com/path` or `/dns4/example.com/tcp/30333`.
+    ///
+    /// Returns `None` if the bytes cannot be mapped onto a well-formed address.
+    pub fn from_legacy(address: &[u8]) -> Option<Self> {
+        let address = core::str::from_utf8(address).ok()?.trim();
+
+        if address.starts_with('/') {
+            return Some(Self::Multiaddr(address.as_bytes().to_vec())).filter(Self::is_well_formed);
+        }
+
+        let (default_port, address) = match address.split_once("://") {
+            Some(("http" | "ws", rest)) => (Some(80), rest),
+            Some(("https" | "wss", rest)) => (Some(443), rest),
+            Some((_, rest)) => (None, rest),
+            None => (None, address),
+        };
+        let authority = address.split('/').next().unwrap_or_default();
+
+        let parsed = if let Some(rest) = authority.strip_prefix('[') {
+            let (ip, port) = rest.split_once(']')?;
+            let port = match port.strip_prefix(':') {
+                Some(port) => port.parse().ok()?,
+                None if port.is_empty() => default_port?,
+                None => return None,
+            };
+            let ip = Ipv6Addr::from_str(ip).ok()?;
+            Self::Ipv6 {
+                ip: ip.octets(),
+                port,
+            }
+        } else {
+            let (host, port) = match authority.rsplit_once(':') {
+                Some((host, port)) => (host, port.parse().ok()?),
+                None => (authority, default_port?),
+            };
+            match Ipv4Addr::from_str(host) {
+                Ok(ip) => Self::Ipv4 {
+                    ip: ip.octets(),
+                    port,
+                },
+                Err(_) => Self::Dns {
+                    host: host.as_bytes().to_vec(),
+                    port,
+                },
+            }
+        };
+
+        Some(parsed).filter(Self::is_well_formed)
+    }
+
+    /// Whether the address is structurally valid, ignoring length limits.
+    pub fn is_well_formed(&self) -> bool {
+        match self {
+            Self::Ipv4 { port, .. } | Self::Ipv6 { port, .. } => *port != 0,
+            Self::Dns { host, port } => {
+                *port != 0
+                    && !host.is_empty()
+                    && host.split(|b| *b == b'.').all(|label| {
+                        !label.is_empty()
+                            && label.len() <= 63
+                            && label.first() != Some(&b'-')
+                            && label.last() != Some(&b'-')
+                            && label.iter().all(|b| b.is_ascii_alphanumeric() || *b == b'-')
+                    })
+            }
+            Self::Multiaddr(addr) => match addr.split_first() {
+                Some((b'/', rest)) => {
+                    addr.iter().all(u8::is_ascii_graphic)
+                        && rest.split(|b| *b == b'/').all(|segment| !segment.is_empty())
+                }
+                _ => false,
+            },
+        }
+    }
+}
+
 #[derive(Decode, Encode, PartialEq, Eq, Clone, Debug, TypeInfo)]
 #[scale_info(skip_type_params(T))]
 pub struct ModuleParams<T: Config> {
     pub name: Vec<u8>,
-    pub address: Vec<u8>,
+    pub address: Option<ModuleAddress>,
     pub fees: ValidatorFees,
-    pub metadata: Option<Vec<u8>>,
+    pub metadata: MetadataMap,
     pub _pd: PhantomData<T>,
 }
 
 #[derive(Debug)]
 pub struct ModuleChangeset<T: Config> {
     pub name: Option<Vec<u8>>,
-    pub address: Option<Vec<u8>>,
+    pub address: Option<ModuleAddress>,
     pub fees: Option<ValidatorFees>,
-    pub metadata: Option<Vec<u8>>,
+    pub metadata: MetadataChanges,
     pub _pd: PhantomData<T>,
 }
 
@@ -24,9 +124,9 @@ impl<T: Config> ModuleChangeset<T> {
     #[must_use]
     pub fn new(
         name: Vec<u8>,
-        address: Vec<u8>,
+        address: ModuleAddress,
         fees: ValidatorFees,
-        metadata: Option<Vec<u8>>,
+        metadata: MetadataChanges,
     ) -> Self {
         Self {
             name: Some(name),
@@ -42,9 +142,9 @@ impl<T: Config> ModuleChangeset<T> {
     pub fn update(
         params: &ModuleParams<T>,
         name: Vec<u8>,
-        address: Vec<u8>,
+        address: ModuleAddress,
         fees: Option<ValidatorFees>,
-        metadata: Option<Vec<u8>>,
+        metadata: MetadataChanges,
     ) -> Self {
         let ModuleParams {
             name: old_name,
@@ -56,7 +156,7 @@ impl<T: Config> ModuleChangeset<T> {
 
         Self {
             name: (name != *old_name).then_some(name),
-            address: (address != *old_address).then_some(address),
+            address: (Some(&address) != old_address.as_ref()).then_some(address),
             fees,
             metadata,
             _pd: PhantomData,
@@ -69,7 +169,7 @@ impl<T: Config> ModuleChangeset<T> {
             name,
             address,
             fees,
-            metadata,
+            metadata: _, // validated against the current metadata on apply
             _pd: _,
         } = self;
 
@@ -81,17 +181,13 @@ impl<T: Config> ModuleChangeset<T> {
         }
 
         if let Some(address) = address {
-            ModuleValidator::validate_address::<T>(address, max_length)?;
+            ModuleValidator::validate_address::<T>(address)?;
         }
 
         if let Some(fees) = fees {
             ModuleValidator::validate_fees::<T>(fees)?;
         }
 
-        if let Some(metadata) = metadata {
-            ModuleValidator::validate_metadata::<T>(metadata)?;
-        }
-
         Ok(())
     }
 
@@ -112,7 +208,18 @@ impl<T: Config> ModuleChangeset<T> {
             _pd: _,
         } = self;
 
+        let metadata = if metadata.is_empty() {
+            None
+        } else {
+            let current = Metadata::<T>::get(netuid, &key).unwrap_or_default();
+            let updated =
+                Pallet::<T>::apply_metadata_changes(current, metadata, MetadataOwner::Module)?;
+            Some(updated)
+        };
+
         if let Some(new_name) = name {
+            Pallet::<T>::unindex_module_name(netuid, uid);
+            ModuleNameIndex::<T>::insert(netuid, canonical_module_name(&new_name), uid);
             Name::<T>::insert(netuid, uid, new_name);
         }
 
@@ -125,7 +232,11 @@ impl<T: Config> ModuleChangeset<T> {
         }
 
         if let Some(new_metadata) = metadata {
-            Metadata::<T>::insert(netuid, &key, new_metadata);
+            if new_metadata.is_empty() {
+                Metadata::<T>::remove(netuid, &key);
+            } else {
+                Metadata::<T>::insert(netuid, &key, new_metadata);
+            }
         }
 
         Pallet::<T>::deposit_event(Event::ModuleUpdated(netuid, key));
@@ -133,6 +244,17 @@ impl<T: Config> ModuleChangeset<T> {
     }
 }
 
+/// The form module names are compared in, so look-alike names like `Foo` and `foo`, or
+/// `my-module` and `my_module`, clash.
+pub fn canonical_module_name(name: &[u8]) -> Vec<u8> {
+    name.iter()
+        .map(|byte| match byte.to_ascii_lowercase() {
+            b'-' => b'_',
+            byte => byte,
+        })
+        .collect()
+}
+
 pub struct ModuleValidator;
 
 impl ModuleValidator {
@@ -145,31 +267,34 @@ impl ModuleValidator {
         ensure!(!name.is_empty(), Error::<T>::InvalidModuleName);
         ensure!(name.len() <= max_length, Error::<T>::ModuleNameTooLong);
         ensure!(name.len() >= min_length, Error::<T>::ModuleNameTooShort);
-        core::str::from_utf8(name).map_err(|_| Error::<T>::InvalidModuleName)?;
         ensure!(
-            !Name::<T>::iter_prefix_values(netuid).any(|existing| existing == name),
+            name.iter().all(|byte| byte.is_ascii_alphanumeric() || b"_-.:".contains(byte)),
+            Error::<T>::InvalidModuleName
+        );
+        ensure!(
+            !ModuleNameIndex::<T>::contains_key(netuid, canonical_module_name(name)),
             Error::<T>::ModuleNameAlreadyExists
         );
         Ok(())
     }
 
     pub fn validate_address<T: Config>(
-        address: &[u8],
-        max_length: usize,
+        address: &ModuleAddress,
     ) -> Result<(), sp_runtime::DispatchError> {
-        ensure!(!address.is_empty(), Error::<T>::InvalidModuleAddress);
-        ensure!(
-            address.len() <= max_length,
-            Error::<T>::ModuleAddressTooLong
-        );
-        core::str::from_utf8(address).map_err(|_| Error::<T>::InvalidModuleAddress)?;
-        Ok(())
-    }
-
-    pub fn validate_metadata<T: Config>(metadata: &[u8]) -> Result<(), sp_runtime::DispatchError> {
-        ensure!(!metadata.is_empty(), Error::<T>::InvalidModuleMetadata);
-        ensure!(metadata.len() <= 120, Error::<T>::ModuleMetadataTooLong);
-        core::str::from_utf8(metadata).map_err(|_| Error::<T>::InvalidModuleMetadata)?;
+        match address {
+            ModuleAddress::Dns { host, .. } => {
+                ensure!(
+                    host.len() <= MAX_HOST_LENGTH,
+                    Error::<T>::ModuleAddressTooLong
+                )
+            }
+            ModuleAddress::Multiaddr(addr) => ensure!(
+                addr.len() <= MAX_MULTIADDR_LENGTH,
+                Error::<T>::ModuleAddressTooLong
+            ),
+            ModuleAddress::Ipv4 { .. } | ModuleAddress::Ipv6 { .. } => {}
+        }
+        ensure!(address.is_well_formed(), Error::<T>::InvalidModuleAddress);
         Ok(())
     }
 
@@ -180,12 +305,20 @@ impl ModuleValidator {
 }
 
 impl<T: Config> Pallet<T> {
+    /// Drops the name of the module under `uid` from the name index.
+    pub(crate) fn unindex_module_name(netuid: u16, uid: u16) {
+        let name = canonical_module_name(&Name::<T>::get(netuid, uid));
+        if ModuleNameIndex::<T>::get(netuid, &name) == Some(uid) {
+            ModuleNameIndex::<T>::remove(netuid, name);
+        }
+    }
+
     pub fn module_params(netuid: u16, key: &T::AccountId, uid: u16) -> ModuleParams<T> {
         ModuleParams {
             name: Name::<T>::get(netuid, uid),
             address: Address::<T>::get(netuid, uid),
             fees: ValidatorFeeConfig::<T>::get(key),
-            metadata: Metadata::<T>::get(netuid, key),
+            metadata: Metadata::<T>::get(netuid, key).unwrap_or_default(),
             _pd: PhantomData,
         }
     }
@@ -10,6 +10,46 @@ use frame_support::pallet_prelude::*;
 use pallet_governance_api::GovernanceConfiguration;
 use substrate_fixed::types::I64F64;
 
+/// Decides who can register modules on a subnet.
+#[derive(Decode, Encode, PartialEq, Eq, Clone, Copy, Debug, Default, TypeInfo, MaxEncodedLen)]
+pub enum RegistrationMode {
+    /// Anyone paying the burn can register a module.
+    #[default]
+    Open,
+    /// Only module keys on the founder-managed allowlist can be registered.
+    Allowlist,
+    /// Only module keys the founder approved can be registered, each approval being used up by
+    /// the registration. The founder can also register modules directly.
+    FounderApproval,
+}
+
+/// Decides which module is evicted when a registration arrives on a full subnet. Modules in their
+/// immunity period are never evicted.
+#[derive(Decode, Encode, PartialEq, Eq, Clone, Copy, Debug, Default, TypeInfo, MaxEncodedLen)]
+pub enum PruningStrategy {
+    /// Evicts the module with the lowest emission score, where dividends count for 30%. Validator
+    /// permit holders are kept and the oldest module goes first on ties.
+    #[default]
+    EmissionScore,
+    /// Evicts the module with the least stake, the oldest one going first on ties.
+    LowestStake,
+    /// Evicts the oldest module.
+    OldestFirst,
+    /// Never evicts, registrations are rejected while the subnet is full.
+    NoEviction,
+}
+
+/// Decides what keeps a module active on a subnet, for the activity cutoff and pruning.
+#[derive(Decode, Encode, PartialEq, Eq, Clone, Copy, Debug, Default, TypeInfo, MaxEncodedLen)]
+pub enum ActivitySource {
+    /// Only setting weights counts as activity.
+    #[default]
+    Weights,
+    /// Heartbeats count as activity too. Modules past the activity cutoff are evicted first when
+    /// the subnet is full.
+    Heartbeats,
+}
+
 #[derive(
     Decode, Encode, PartialEq, Eq, Clone, frame_support::DebugNoBound, TypeInfo, MaxEncodedLen,
 )]
@@ -24,7 +64,7 @@ pub struct SubnetParams<T: Config> {
     pub min_allowed_weights: u16,
     pub max_weight_age: u64,
     pub name: BoundedVec<u8, ConstU32<256>>,
-    pub metadata: Option<BoundedVec<u8, ConstU32<120>>>,
+    pub metadata: MetadataMap,
     pub tempo: u16,
     pub maximum_set_weight_calls_per_epoch: Option<u16>,
     // --- Consensus ---
@@ -32,10 +72,27 @@ pub struct SubnetParams<T: Config> {
     pub module_burn_config: GeneralBurnConfiguration<T>,
     pub min_validator_stake: u64,
     pub max_allowed_validators: Option<u16>,
+    pub registration_mode: RegistrationMode,
+    pub pruning_strategy: PruningStrategy,
+    pub activity_source: ActivitySource,
     pub governance_config: GovernanceConfiguration,
     // ---  Weight Encryption ---
 }
 
+impl<T: Config> SubnetParams<T> {
+    /// Takes the fields that don't affect the subnet economics (founder, name, metadata and
+    /// governance configuration) from `other`.
+    pub fn with_non_economic_fields_of(self, other: &SubnetParams<T>) -> Self {
+        Self {
+            founder: other.founder.clone(),
+            name: other.name.clone(),
+            metadata: other.metadata.clone(),
+            governance_config: other.governance_config.clone(),
+            ..self
+        }
+    }
+}
+
 pub struct DefaultSubnetParams<T: Config>(sp_std::marker::PhantomData<((), T)>);
 
 impl<T: Config> DefaultSubnetParams<T> {
@@ -60,11 +117,14 @@ impl<T: Config> DefaultSubnetParams<T> {
             module_burn_config: GeneralBurnConfiguration::<T>::default_for(BurnType::Module),
             min_validator_stake: T::DefaultMinValidatorStake::get(),
             max_allowed_validators: None,
+            registration_mode: RegistrationMode::Open,
+            pruning_strategy: PruningStrategy::EmissionScore,
+            activity_source: ActivitySource::Weights,
             governance_config: GovernanceConfiguration {
                 vote_mode: VoteMode::Authority,
                 ..Default::default()
             },
-            metadata: None,
+            metadata: MetadataMap::default(),
 
         }
     }
@@ -81,6 +141,7 @@ pub struct ValidatedSubnetParams<T: Config> {
 #[derive(Debug)]
 pub struct SubnetChangeset<T: Config> {
     params: ValidatedSubnetParams<T>,
+    storage_deposit: bool,
     _validated: PhantomData<()>,
 }
 
@@ -127,7 +188,7 @@ impl<T: Config> ValidatedSubnetParams<T> {
 
         // ! Keep It Written Like This To Enhance Safety Of Unused
         let SubnetParams {
-            founder: _, // complete freedom
+            founder,
             founder_share,
             immunity_period: _, // complete freedom
             incentive_ratio,
@@ -143,6 +204,9 @@ impl<T: Config> ValidatedSubnetParams<T> {
             module_burn_config: _, // not validated
             min_validator_stake,
             max_allowed_validators,
+            registration_mode: _, // complete freedom
+            pruning_strategy: _,  // complete freedom
+            activity_source: _,   // complete freedom
             governance_config: _,      // TODO: validate
         } = params;
 
@@ -162,10 +226,7 @@ impl<T: Config> ValidatedSubnetParams<T> {
             Error::<T>::InvalidMinAllowedWeights
         );
 
-        // Validate metadata if present
-        if let Some(meta) = metadata {
-            ensure!(!meta.is_empty(), Error::<T>::InvalidSubnetMetadata);
-        }
+        Pallet::<T>::validate_metadata(metadata, MetadataOwner::Subnet)?;
 
         // Validate tempo and weight age
         ensure!(tempo >= &MIN_TEMPO, Error::<T>::InvalidTempo);
@@ -177,6 +238,12 @@ impl<T: Config> ValidatedSubnetParams<T> {
         // Validate UIDs
         ensure!(*max_allowed_uids > 0, Error::<T>::InvalidMaxAllowedUids);
 
+        // Existing subnets change hands through `propose_founder_transfer`
+        ensure!(
+            netuid.map_or(true, |netuid| *founder == Founder::<T>::get(netuid)),
+            Error::<T>::FounderChangeRequiresTransfer
+        );
+
         // Validate shares and ratios
         ensure!(
             *founder_share <= MAX_FOUNDER_SHARE,
@@ -249,6 +316,7 @@ impl<T: Config> SubnetChangeset<T> {
     pub fn new(params: SubnetParams<T>) -> Result<Self, DispatchError> {
         Ok(Self {
             params: ValidatedSubnetParams::new(params, None)?,
+            storage_deposit: true,
             _validated: PhantomData,
         })
     }
@@ -256,12 +324,43 @@ impl<T: Config> SubnetChangeset<T> {
     pub fn update(netuid: u16, params: SubnetParams<T>) -> Result<Self, DispatchError> {
         Ok(Self {
             params: ValidatedSubnetParams::new(params, Some(netuid))?,
+            storage_deposit: true,
             _validated: PhantomData,
         })
     }
 
+    /// Applies the changeset without reserving a storage deposit from the founder, for subnets
+    /// created at genesis.
+    pub fn without_storage_deposit(mut self) -> Self {
+        self.storage_deposit = false;
+        self
+    }
+
+    /// Whether the stored name or metadata of the subnet would change.
+    fn changes_stored_data(&self, netuid: u16) -> bool {
+        SubnetNames::<T>::get(netuid) != self.params.name.as_slice()
+            || SubnetMetadata::<T>::get(netuid).unwrap_or_default() != self.params.metadata
+    }
+
+    /// Checks the founder can afford the storage deposit applying the changeset reserves, so
+    /// proposals don't pass validation only to fail when enacted.
+    pub fn ensure_storage_deposit_affordable(&self, netuid: u16) -> DispatchResult {
+        if !self.storage_deposit || !self.changes_stored_data(netuid) {
+            return Ok(());
+        }
+
+        let metadata = if self.params.metadata.is_empty() {
+            0
+        } else {
+            self.params.metadata.encoded_size()
+        };
+        let bytes = self.params.name.encoded_size().saturating_add(metadata);
+        Pallet::<T>::ensure_subnet_deposit_affordable(netuid, &self.params.founder, bytes)
+    }
+
     #[deny(unused_variables)]
     pub fn apply(self, netuid: u16) -> DispatchResult {
+        let stored_changed = self.storage_deposit && self.changes_stored_data(netuid);
         let SubnetParams {
             founder,
             founder_share,
@@ -279,6 +378,9 @@ impl<T: Config> SubnetChangeset<T> {
             module_burn_config,
             min_validator_stake,
             max_allowed_validators,
+            registration_mode,
+            pruning_strategy,
+            activity_source,
             governance_config,
         } = self.params.into_inner();
 
@@ -303,10 +405,20 @@ impl<T: Config> SubnetChangeset<T> {
             }
         }
         T::update_subnet_governance_configuration(netuid, governance_config)?;
-        if let Some(meta) = &metadata {
-            SubnetMetadata::<T>::insert(netuid, meta);
+        if metadata.is_empty() {
+            SubnetMetadata::<T>::remove(netuid);
+        } else {
+            SubnetMetadata::<T>::insert(netuid, metadata);
+        }
+        // Only charge for what the subnet stores when it changes, so parameter updates don't fail
+        // on founders without free balance
+        if stored_changed {
+            Pallet::<T>::settle_subnet_deposit(netuid, &founder)?;
         }
         MaxAllowedValidators::<T>::insert(netuid, max_allowed_validators);
+        SubnetRegistrationMode::<T>::insert(netuid, registration_mode);
+        SubnetPruningStrategy::<T>::insert(netuid, pruning_strategy);
+        SubnetActivitySource::<T>::insert(netuid, activity_source);
         Pallet::<T>::deposit_event(Event::SubnetParamsUpdated(netuid));
 
         Ok(())
@@ -333,8 +445,11 @@ impl<T: Config> Pallet<T> {
             module_burn_config: ModuleBurnConfig::<T>::get(netuid),
             min_validator_stake: MinValidatorStake::<T>::get(netuid),
             max_allowed_validators: MaxAllowedValidators::<T>::get(netuid),
+            registration_mode: SubnetRegistrationMode::<T>::get(netuid),
+            pruning_strategy: SubnetPruningStrategy::<T>::get(netuid),
+            activity_source: SubnetActivitySource::<T>::get(netuid),
             governance_config: T::get_subnet_governance_configuration(netuid),
-            metadata: SubnetMetadata::<T>::get(netuid),
+            metadata: SubnetMetadata::<T>::get(netuid).unwrap_or_default(),
 
         }
     }
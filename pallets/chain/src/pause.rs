@@ -0,0 +1,54 @@
+use crate::*;
+use frame_support::pallet_prelude::{DispatchResult, MaxEncodedLen};
+use scale_info::TypeInfo;
+use sp_std::vec::Vec;
+
+/// Chain operations that can be halted in an emergency, e.g. while a consensus bug or an exploit
+/// is being fixed.
+#[derive(
+    Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Encode, Decode, TypeInfo, MaxEncodedLen,
+)]
+pub enum PausableOperation {
+    Registration,
+    Staking,
+    Weights,
+    Emission,
+    EvmPrecompiles,
+}
+
+impl<T: Config> Pallet<T> {
+    /// Whether the operation is currently paused. Pauses lapse on their own once their expiry
+    /// block is reached.
+    pub fn is_paused(operation: PausableOperation) -> bool {
+        PausedOperations::<T>::get(operation)
+            .is_some_and(|until| Self::get_current_block_number() < until)
+    }
+
+    pub fn ensure_not_paused(operation: PausableOperation) -> DispatchResult {
+        ensure!(!Self::is_paused(operation), Error::<T>::OperationPaused);
+        Ok(())
+    }
+
+    /// Pauses the operation until the given block. Overrides any pause already in place.
+    pub fn pause_operation(operation: PausableOperation, until: u64) {
+        PausedOperations::<T>::insert(operation, until);
+        Self::deposit_event(Event::OperationPaused(operation, until));
+    }
+
+    pub fn resume_operation(operation: PausableOperation) {
+        if PausedOperations::<T>::take(operation).is_some() {
+            Self::deposit_event(Event::OperationResumed(operation));
+        }
+    }
+
+    pub fn clear_expired_pauses(block_number: u64) {
+        let expired: Vec<_> = PausedOperations::<T>::iter()
+            .filter(|(_, until)| *until <= block_number)
+            .map(|(operation, _)| operation)
+            .collect();
+
+        for operation in expired {
+            Self::resume_operation(operation);
+        }
+    }
+}
@@ -71,9 +71,9 @@ pub mod dispatches {
             origin: OriginFor<T>,
             network_name: Vec<u8>,
             name: Vec<u8>,
-            address: Vec<u8>,
+            address: ModuleAddress,
             module_key: T::AccountId,
-            metadata: Option<Vec<u8>>,
+            metadata: MetadataChanges,
         ) -> DispatchResult {
             Self::do_register(origin, network_name, name, address, module_key, metadata)
         }
@@ -90,10 +90,10 @@ pub mod dispatches {
             origin: OriginFor<T>,
             netuid: u16,
             name: Vec<u8>,
-            address: Vec<u8>,
+            address: ModuleAddress,
             stake_delegation_fee: Option<Percent>,
             validator_weight_fee: Option<Percent>,
-            metadata: Option<Vec<u8>>,
+            metadata: MetadataChanges,
         ) -> DispatchResult {
             let key = ensure_signed(origin.clone())?;
             ensure!(
@@ -129,7 +129,7 @@ pub mod dispatches {
             founder: T::AccountId,
             founder_share: u16,
             name: BoundedVec<u8, ConstU32<256>>,
-            metadata: Option<BoundedVec<u8, ConstU32<120>>>,
+            metadata: MetadataChanges,
             immunity_period: u16,
             incentive_ratio: u16,
             max_allowed_uids: u16,
@@ -143,7 +143,16 @@ pub mod dispatches {
             module_burn_config: GeneralBurnConfiguration<T>,
             min_validator_stake: u64,
             max_allowed_validators: Option<u16>,
+            registration_mode: RegistrationMode,
+            pruning_strategy: PruningStrategy,
+            activity_source: ActivitySource,
         ) -> DispatchResult {
+            let metadata = Self::apply_metadata_changes(
+                SubnetMetadata::<T>::get(netuid).unwrap_or_default(),
+                metadata,
+                MetadataOwner::Subnet,
+            )?;
+
             let params = SubnetParams {
                 founder,
                 founder_share,
@@ -160,6 +169,9 @@ pub mod dispatches {
                 module_burn_config,
                 min_validator_stake,
                 max_allowed_validators,
+                registration_mode,
+                pruning_strategy,
+                activity_source,
                 governance_config: GovernanceConfiguration {
                     vote_mode,
                     ..T::get_subnet_governance_configuration(netuid)
@@ -176,9 +188,111 @@ pub mod dispatches {
         pub fn register_subnet(
             origin: OriginFor<T>,
             name: Vec<u8>,
-            metadata: Option<Vec<u8>>,
+            metadata: MetadataChanges,
         ) -> DispatchResult {
             Self::do_register_subnet(origin, name, metadata)
         }
+
+        #[pallet::call_index(13)]
+        #[pallet::weight((T::WeightInfo::commit_registration(), DispatchClass::Normal, Pays::No))]
+        pub fn commit_registration(
+            origin: OriginFor<T>,
+            netuid: u16,
+            commitment: H256,
+        ) -> DispatchResult {
+            Self::do_commit_registration(origin, netuid, commitment)
+        }
+
+        #[pallet::call_index(14)]
+        #[pallet::weight((T::WeightInfo::reveal_registration(), DispatchClass::Normal, Pays::No))]
+        pub fn reveal_registration(
+            origin: OriginFor<T>,
+            netuid: u16,
+            name: Vec<u8>,
+            address: ModuleAddress,
+            module_key: T::AccountId,
+            metadata: MetadataChanges,
+            salt: [u8; 32],
+        ) -> DispatchResult {
+            Self::do_reveal_registration(origin, netuid, name, address, module_key, metadata, salt)
+        }
+
+        #[pallet::call_index(15)]
+        #[pallet::weight((T::WeightInfo::add_to_allowlist(), DispatchClass::Normal, Pays::No))]
+        pub fn add_to_allowlist(
+            origin: OriginFor<T>,
+            netuid: u16,
+            module_key: T::AccountId,
+        ) -> DispatchResult {
+            Self::do_add_to_allowlist(origin, netuid, module_key)
+        }
+
+        #[pallet::call_index(16)]
+        #[pallet::weight((T::WeightInfo::remove_from_allowlist(), DispatchClass::Normal, Pays::No))]
+        pub fn remove_from_allowlist(
+            origin: OriginFor<T>,
+            netuid: u16,
+            module_key: T::AccountId,
+        ) -> DispatchResult {
+            Self::do_remove_from_allowlist(origin, netuid, module_key)
+        }
+
+        #[pallet::call_index(17)]
+        #[pallet::weight((T::WeightInfo::register_multiple(registrations.len() as u32), DispatchClass::Normal, Pays::No))]
+        pub fn register_multiple(
+            origin: OriginFor<T>,
+            module_key: T::AccountId,
+            registrations: MultipleRegistrations,
+        ) -> DispatchResult {
+            Self::do_register_multiple(origin, module_key, registrations)
+        }
+
+        #[pallet::call_index(18)]
+        #[pallet::weight((T::WeightInfo::propose_founder_transfer(), DispatchClass::Normal, Pays::No))]
+        pub fn propose_founder_transfer(
+            origin: OriginFor<T>,
+            netuid: u16,
+            new_founder: T::AccountId,
+        ) -> DispatchResult {
+            Self::do_propose_founder_transfer(origin, netuid, new_founder)
+        }
+
+        #[pallet::call_index(19)]
+        #[pallet::weight((T::WeightInfo::accept_founder_transfer(), DispatchClass::Normal, Pays::No))]
+        pub fn accept_founder_transfer(origin: OriginFor<T>, netuid: u16) -> DispatchResult {
+            Self::do_accept_founder_transfer(origin, netuid)
+        }
+
+        #[pallet::call_index(20)]
+        #[pallet::weight((T::WeightInfo::dissolve_subnet(), DispatchClass::Normal, Pays::No))]
+        pub fn dissolve_subnet(origin: OriginFor<T>, netuid: u16) -> DispatchResult {
+            Self::do_dissolve_subnet(origin, netuid)
+        }
+
+        #[pallet::call_index(21)]
+        #[pallet::weight((T::WeightInfo::heartbeat(), DispatchClass::Normal, Pays::No))]
+        pub fn heartbeat(origin: OriginFor<T>, netuid: u16) -> DispatchResult {
+            Self::do_heartbeat(origin, netuid)
+        }
+
+        #[pallet::call_index(22)]
+        #[pallet::weight((T::WeightInfo::approve_module_registration(), DispatchClass::Normal, Pays::No))]
+        pub fn approve_module_registration(
+            origin: OriginFor<T>,
+            netuid: u16,
+            module_key: T::AccountId,
+        ) -> DispatchResult {
+            Self::do_approve_module_registration(origin, netuid, module_key)
+        }
+
+        #[pallet::call_index(23)]
+        #[pallet::weight((T::WeightInfo::revoke_module_registration_approval(), DispatchClass::Normal, Pays::No))]
+        pub fn revoke_module_registration_approval(
+            origin: OriginFor<T>,
+            netuid: u16,
+            module_key: T::AccountId,
+        ) -> DispatchResult {
+            Self::do_revoke_module_registration_approval(origin, netuid, module_key)
+        }
     }
 }
@@ -131,13 +131,14 @@ pub mod errors {
         ModuleNameTooLong,
         /// The module name is too short.
         ModuleNameTooShort,
-        /// The module name is invalid. It must be a UTF-8 encoded string.
+        /// The module name is invalid. It may only contain ASCII letters, digits, `_`, `-`, `.`
+        /// and `:`.
         InvalidModuleName,
         /// The module address is too long.
         ModuleAddressTooLong,
         /// The module address is invalid.
         InvalidModuleAddress,
-        /// A module with this name already exists in the subnet.
+        /// A module with this name, or a look-alike of it, already exists in the subnet.
         ModuleNameAlreadyExists,
         /// The founder share is invalid.
         InvalidFounderShare,
@@ -215,5 +216,51 @@ pub mod errors {
         NotEnoughBridgedTokens,
         /// User is trying to bridge tokens in closed period
         OutsideValidBlockRange,
+        /// The curator council threshold must be between 1 and the amount of council members.
+        InvalidCuratorCouncil,
+        /// The operation is currently paused by an emergency pause.
+        OperationPaused,
+        /// The subnet only accepts modules approved through its application process.
+        ModuleNotApprovedForSubnet,
+        /// A registration with the same commitment is already pending.
+        RegistrationCommitAlreadyExists,
+        /// No pending registration commitment matches the revealed details.
+        RegistrationCommitNotFound,
+        /// The registration commitment can only be revealed in a later block.
+        RegistrationRevealTooEarly,
+        /// The module key is not on the subnet allowlist.
+        ModuleNotAllowlisted,
+        /// The module key is already on the subnet allowlist.
+        ModuleAlreadyAllowlisted,
+        /// The subnet founder didn't approve the module key to register on this subnet.
+        ModuleRegistrationNotApproved,
+        /// The subnet is full and its pruning strategy doesn't evict modules.
+        SubnetIsFull,
+        /// No registrations were provided.
+        EmptyRegistrations,
+        /// The burn refund curve must decay over at least one block.
+        InvalidBurnRefundCurve,
+        /// The treasury and founder shares of the burn destination add up to more than 100%.
+        InvalidBurnDestination,
+        /// The founder of an existing subnet can only change through a founder transfer.
+        FounderChangeRequiresTransfer,
+        /// The subnet has no pending founder transfer.
+        NoPendingFounderTransfer,
+        /// The caller is not the pending founder of the subnet.
+        NotPendingFounder,
+        /// The founder transfer was not accepted in time.
+        FounderTransferExpired,
+        /// The subnet is being dissolved.
+        SubnetDissolving,
+        /// This subnet can't be dissolved.
+        CannotDissolveSubnet,
+        /// The subnet on this netuid is not the expected generation.
+        SubnetGenerationMismatch,
+        /// The module already sent a heartbeat this epoch.
+        HeartbeatTooSoon,
+        /// Not enough free balance to reserve the storage deposit.
+        NotEnoughBalanceForStorageDeposit,
+        /// The module key is already approved to register on the subnet.
+        ModuleRegistrationAlreadyApproved,
     }
 }
@@ -28,5 +28,50 @@ pub mod events {
         GlobalParamsUpdated(GlobalParams<T>),
         /// Event created when subnet parameters are updated
         SubnetParamsUpdated(u16),
+        /// Event created when an operation is paused until the given block
+        OperationPaused(PausableOperation, u64),
+        /// Event created when a paused operation is resumed, either manually or by expiry
+        OperationResumed(PausableOperation),
+        /// Event created when an account commits to a registration on the subnet
+        RegistrationCommitted(T::AccountId, u16, H256),
+        /// Event created when a registration commitment expires unrevealed, with the refunded
+        /// amount
+        RegistrationCommitExpired(T::AccountId, H256, u64),
+        /// Event created when the subnet founder adds a module key to the allowlist
+        ModuleAllowlisted(u16, T::AccountId),
+        /// Event created when the subnet founder removes a module key from the allowlist
+        ModuleRemovedFromAllowlist(u16, T::AccountId),
+        /// Event created when part of the registration burn is refunded from the subnet escrow
+        /// to the account that paid it
+        RegistrationBurnRefunded(u16, T::AccountId, u64),
+        /// Event created when a registration burn is paid on a subnet, with the amounts
+        /// destroyed, sent to the DAO treasury and sent to the subnet founder
+        RegistrationBurnDistributed(u16, u64, u64, u64),
+        /// Event created when a subnet transfer is proposed, with the pending founder and the
+        /// last block it can accept the transfer at
+        FounderTransferProposed(u16, T::AccountId, u64),
+        /// Event created when the pending founder accepts the subnet transfer, with the previous
+        /// and the new founder
+        FounderTransferred(u16, T::AccountId, T::AccountId),
+        /// Event created when the founder starts dissolving the subnet, with the block the
+        /// subnet is removed at
+        SubnetDissolving(u16, u64),
+        /// Event created when the founder announces changes to the subnet economics, with the
+        /// block they apply at
+        SubnetParamsChangeScheduled(u16, u64),
+        /// Event created when announced subnet parameters became invalid before applying
+        SubnetParamsChangeDropped(u16),
+        /// Event created when the storage of a removed network has been fully cleaned up
+        NetworkCleanedUp(u16),
+        /// Event created when a revealed registration finds its name already taken, with the
+        /// refunded burn
+        RegistrationCommitRefunded(T::AccountId, H256, u64),
+        /// Event created when the founder cancels the announced subnet parameters by updating
+        /// the subnet with its current economics
+        SubnetParamsChangeCancelled(u16),
+        /// Event created when the subnet founder approves a module key to register once
+        ModuleRegistrationApproved(u16, T::AccountId),
+        /// Event created when the subnet founder withdraws an unused registration approval
+        ModuleRegistrationApprovalRevoked(u16, T::AccountId),
     }
 }
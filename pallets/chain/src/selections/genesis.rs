@@ -47,8 +47,9 @@ pub mod genesis {
                 log::info!("registering subnet {netuid} with params: {params:?}");
 
                 let fee = Pallet::<T>::get_stake_delegation_fee(&params.founder);
-                let changeset: SubnetChangeset<T> =
-                    SubnetChangeset::new(params).expect("genesis subnets are valid");
+                let changeset: SubnetChangeset<T> = SubnetChangeset::new(params)
+                    .expect("genesis subnets are valid")
+                    .without_storage_deposit();
                 let _ = self::Pallet::<T>::add_subnet(changeset, Some(netuid))
                     .expect("Failed to register genesis subnet");
 
@@ -60,12 +61,13 @@ pub mod genesis {
                         validator_weight_fee: DefaultValidatorFees::<T>::get().validator_weight_fee,
                     };
 
-                    let changeset = ModuleChangeset::new(
-                        module.name.clone(),
-                        module.address.clone(),
-                        fees,
-                        None,
-                    );
+                    let changeset = ModuleChangeset {
+                        name: Some(module.name.clone()),
+                        address: ModuleAddress::from_legacy(&module.address),
+                        fees: Some(fees),
+                        metadata: Vec::new(),
+                        _pd: PhantomData,
+                    };
                     self::Pallet::<T>::append_module(netuid, &module.key, changeset)
                         .expect("genesis modules are valid");
                     T::set_weights(netuid, module_uid, module.weights.clone());
@@ -14,13 +14,25 @@ pub mod hooks {
             // Clears the root net weights daily quota
             Self::clear_rootnet_daily_weight_calls(block_number);
 
+            // Lifts emergency pauses that reached their expiry
+            Self::clear_expired_pauses(block_number);
+
+            // Refunds registration commitments that were never revealed
+            Self::clear_expired_registration_commits(block_number);
+
+            // Removes dissolving subnets whose grace period is over
+            Self::remove_dissolved_subnets(block_number);
+
+            // Applies announced subnet parameters whose notice period is over
+            Self::apply_scheduled_subnet_params(block_number);
+
             // TODO: fix later
             Weight::default()
         }
 
-        fn on_idle(_n: BlockNumberFor<T>, _remaining: Weight) -> Weight {
-            log::info!("running on_idle");
-            Weight::zero()
+        fn on_idle(_n: BlockNumberFor<T>, remaining_weight: Weight) -> Weight {
+            // Deletes the storage of removed subnets in batches
+            Self::cleanup_tombstoned_subnets(remaining_weight)
         }
     }
 }
@@ -46,6 +46,17 @@ pub trait WeightInfo {
 	fn update_module() -> Weight;
 	fn update_subnet() -> Weight;
 	fn register_subnet() -> Weight;
+	fn commit_registration() -> Weight;
+	fn reveal_registration() -> Weight;
+	fn add_to_allowlist() -> Weight;
+	fn remove_from_allowlist() -> Weight;
+	fn register_multiple(r: u32, ) -> Weight;
+	fn propose_founder_transfer() -> Weight;
+	fn accept_founder_transfer() -> Weight;
+	fn dissolve_subnet() -> Weight;
+	fn heartbeat() -> Weight;
+	fn approve_module_registration() -> Weight;
+	fn revoke_module_registration_approval() -> Weight;
 }
 
 /// Weights for `pallet_chain` using the Substrate node and recommended hardware.
@@ -574,6 +585,75 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(T::DbWeight::get().reads(30_u64))
 			.saturating_add(T::DbWeight::get().writes(27_u64))
 	}
+	/// Hand-written estimate, not benchmarked yet. Regenerate with `make run-benchmarking`.
+	fn commit_registration() -> Weight {
+		Weight::from_parts(95_000_000, 6210)
+			.saturating_add(T::DbWeight::get().reads(6_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	/// Hand-written estimate, not benchmarked yet. Regenerate with `make run-benchmarking`.
+	fn reveal_registration() -> Weight {
+		Weight::from_parts(372_000_000, 18729)
+			.saturating_add(T::DbWeight::get().reads(49_u64))
+			.saturating_add(T::DbWeight::get().writes(27_u64))
+	}
+	/// Hand-written estimate, not benchmarked yet. Regenerate with `make run-benchmarking`.
+	fn add_to_allowlist() -> Weight {
+		Weight::from_parts(31_000_000, 3520)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Hand-written estimate, not benchmarked yet. Regenerate with `make run-benchmarking`.
+	fn remove_from_allowlist() -> Weight {
+		Weight::from_parts(31_000_000, 3520)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Hand-written estimate, not benchmarked yet. Regenerate with `make run-benchmarking`.
+	fn register_multiple(r: u32, ) -> Weight {
+		Weight::from_parts(24_000_000, 3593)
+			.saturating_add(Weight::from_parts(359_000_000, 16408).saturating_mul(r.into()))
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().reads((45_u64).saturating_mul(r.into())))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+			.saturating_add(T::DbWeight::get().writes((26_u64).saturating_mul(r.into())))
+	}
+	/// Hand-written estimate, not benchmarked yet. Regenerate with `make run-benchmarking`.
+	fn propose_founder_transfer() -> Weight {
+		Weight::from_parts(24_000_000, 3650)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Hand-written estimate, not benchmarked yet. Regenerate with `make run-benchmarking`.
+	fn accept_founder_transfer() -> Weight {
+		Weight::from_parts(26_000_000, 3720)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Hand-written estimate, not benchmarked yet. Regenerate with `make run-benchmarking`.
+	fn dissolve_subnet() -> Weight {
+		Weight::from_parts(27_000_000, 3720)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Hand-written estimate, not benchmarked yet. Regenerate with `make run-benchmarking`.
+	fn heartbeat() -> Weight {
+		Weight::from_parts(18_000_000, 4810)
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Hand-written estimate, not benchmarked yet. Regenerate with `make run-benchmarking`.
+	fn approve_module_registration() -> Weight {
+		Weight::from_parts(31_000_000, 3520)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Hand-written estimate, not benchmarked yet. Regenerate with `make run-benchmarking`.
+	fn revoke_module_registration_approval() -> Weight {
+		Weight::from_parts(31_000_000, 3520)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
 }
 
 // For backwards compatibility and tests.
@@ -1101,4 +1181,73 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().reads(30_u64))
 			.saturating_add(RocksDbWeight::get().writes(27_u64))
 	}
+	/// Hand-written estimate, not benchmarked yet. Regenerate with `make run-benchmarking`.
+	fn commit_registration() -> Weight {
+		Weight::from_parts(95_000_000, 6210)
+			.saturating_add(RocksDbWeight::get().reads(6_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	/// Hand-written estimate, not benchmarked yet. Regenerate with `make run-benchmarking`.
+	fn reveal_registration() -> Weight {
+		Weight::from_parts(372_000_000, 18729)
+			.saturating_add(RocksDbWeight::get().reads(49_u64))
+			.saturating_add(RocksDbWeight::get().writes(27_u64))
+	}
+	/// Hand-written estimate, not benchmarked yet. Regenerate with `make run-benchmarking`.
+	fn add_to_allowlist() -> Weight {
+		Weight::from_parts(31_000_000, 3520)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Hand-written estimate, not benchmarked yet. Regenerate with `make run-benchmarking`.
+	fn remove_from_allowlist() -> Weight {
+		Weight::from_parts(31_000_000, 3520)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Hand-written estimate, not benchmarked yet. Regenerate with `make run-benchmarking`.
+	fn register_multiple(r: u32, ) -> Weight {
+		Weight::from_parts(24_000_000, 3593)
+			.saturating_add(Weight::from_parts(359_000_000, 16408).saturating_mul(r.into()))
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().reads((45_u64).saturating_mul(r.into())))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+			.saturating_add(RocksDbWeight::get().writes((26_u64).saturating_mul(r.into())))
+	}
+	/// Hand-written estimate, not benchmarked yet. Regenerate with `make run-benchmarking`.
+	fn propose_founder_transfer() -> Weight {
+		Weight::from_parts(24_000_000, 3650)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Hand-written estimate, not benchmarked yet. Regenerate with `make run-benchmarking`.
+	fn accept_founder_transfer() -> Weight {
+		Weight::from_parts(26_000_000, 3720)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Hand-written estimate, not benchmarked yet. Regenerate with `make run-benchmarking`.
+	fn dissolve_subnet() -> Weight {
+		Weight::from_parts(27_000_000, 3720)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Hand-written estimate, not benchmarked yet. Regenerate with `make run-benchmarking`.
+	fn heartbeat() -> Weight {
+		Weight::from_parts(18_000_000, 4810)
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Hand-written estimate, not benchmarked yet. Regenerate with `make run-benchmarking`.
+	fn approve_module_registration() -> Weight {
+		Weight::from_parts(31_000_000, 3520)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Hand-written estimate, not benchmarked yet. Regenerate with `make run-benchmarking`.
+	fn revoke_module_registration_approval() -> Weight {
+		Weight::from_parts(31_000_000, 3520)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
 }
\ No newline at end of file
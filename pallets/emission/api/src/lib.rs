@@ -22,6 +22,9 @@ pub type SubnetWeights = Vec<(u16, Vec<(u16, u16)>)>;
 pub trait SubnetEmissionApi<AccountId> {
     fn get_lowest_emission_netuid(ignore_subnet_immunity: bool) -> Option<u16>;
 
+    /// The subnet with the lowest emission that modules can be evicted from.
+    fn get_lowest_evictable_netuid() -> Option<u16>;
+
     fn set_emission_storage(netuid: u16, emission: u64);
 
     fn create_yuma_subnet(netuid: u16);
@@ -36,15 +39,23 @@ pub trait SubnetEmissionApi<AccountId> {
 
     fn set_subnet_consensus_type(netuid: u16, subnet_consensus: Option<SubnetConsensus>);
 
+    /// Moves an existing subnet to another consensus, migrating its consensus state.
+    fn change_subnet_consensus_type(netuid: u16, subnet_consensus: SubnetConsensus);
+
     fn get_weights(netuid: u16, uid: u16) -> Option<Vec<(u16, u16)>>;
 
+    /// Pays out the pending emission of the subnet right away, on the weights it currently has.
+    fn distribute_pending_emission(netuid: u16);
+
     fn set_weights(
         netuid: u16,
         uid: u16,
         weights: Option<Vec<(u16, u16)>>,
     ) -> Option<Vec<(u16, u16)>>;
 
-    fn clear_subnet_includes(netuid: u16);
+    /// Removes up to `limit` storage entries of the subnet. Returns the amount of removed
+    /// entries and whether the subnet storage is fully cleared.
+    fn clear_subnet_includes(netuid: u16, limit: u32) -> (u32, bool);
 
     fn clear_module_includes(
         netuid: u16,
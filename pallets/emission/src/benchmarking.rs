@@ -4,7 +4,9 @@ use crate::*;
 use frame_benchmarking::{account, benchmarks};
 use frame_system::RawOrigin;
 pub use pallet::*;
-use pallet_chain::{vec, MinValidatorStake, Pallet as ChainMod, SubnetBurn};
+use pallet_chain::{
+    vec, MetadataKey, MinValidatorStake, ModuleAddress, Pallet as ChainMod, SubnetBurn,
+};
 use sp_std::vec::Vec;
 
 fn register_mock<T: Config>(
@@ -12,7 +14,10 @@ fn register_mock<T: Config>(
     module_key: T::AccountId,
     name: Vec<u8>,
 ) -> Result<(), &'static str> {
-    let address = "test".as_bytes().to_vec();
+    let address = ModuleAddress::Dns {
+        host: "test".as_bytes().to_vec(),
+        port: 30333,
+    };
     let network = "testnet".as_bytes().to_vec();
 
     let enough_stake = 10000000000000u64;
@@ -20,8 +25,8 @@ fn register_mock<T: Config>(
         &key,
         ChainMod::<T>::u64_to_balance(SubnetBurn::<T>::get() + enough_stake).unwrap(),
     );
-    let network_metadata = Some("networkmetadata".as_bytes().to_vec());
-    let metadata = Some("metadata".as_bytes().to_vec());
+    let network_metadata = vec![(MetadataKey::Website, Some(b"networkmetadata".to_vec()))];
+    let metadata = vec![(MetadataKey::Website, Some("metadata".as_bytes().to_vec()))];
     let _ = ChainMod::<T>::register_subnet(
         RawOrigin::Signed(key.clone()).into(),
         network.clone(),
@@ -89,4 +94,15 @@ benchmarks! {
         let _ = Pallet::<T>::delegate_weight_control(RawOrigin::Signed(module_key.clone()).into(), netuid, module_key2.clone());
 
     }: remove_weight_control(RawOrigin::Signed(module_key), netuid)
+
+    delegate_weight_control_at_generation {
+        let module_key: T::AccountId = account("ModuleKey", 0, 2);
+        let module_key2: T::AccountId = account("ModuleKey2", 0, 3);
+
+        register_mock::<T>(module_key.clone(), module_key.clone(), "test".as_bytes().to_vec())?;
+        register_mock::<T>(module_key2.clone(), module_key2.clone(), "test1".as_bytes().to_vec())?;
+        let netuid = ChainMod::<T>::get_netuid_for_name("testnet".as_bytes()).unwrap();
+        let generation = pallet_chain::SubnetGeneration::<T>::get(netuid);
+
+    }: delegate_weight_control_at_generation(RawOrigin::Signed(module_key), netuid, generation, module_key2.clone())
 }
@@ -3,7 +3,7 @@ use core::fmt::Debug;
 use crate::{Config, WeightEncryptionData};
 use frame_support::DebugNoBound;
 use pallet_chain::{
-    math::*, AlphaValues, BalanceOf, Bonds, BondsMovingAverage, Founder, Kappa, Keys, LastUpdate,
+    math::*, AlphaValues, BalanceOf, Bonds, BondsMovingAverage, Founder, Kappa, Keys,
     MaxAllowedValidators, MaxWeightAge, MinValidatorStake, Pallet as PalletChain,
     UseWeightsEncryption, ValidatorPermits, Vec, WeightSettingDelegation,
 };
@@ -129,7 +129,8 @@ impl<T: Config> ConsensusParams<T> {
         let uids: BTreeMap<_, _> = Keys::<T>::iter_prefix(subnet_id).collect();
         let (stake_original, stake_normalized) = Self::compute_stake(&uids);
         let bonds = Self::compute_bonds(subnet_id, &uids);
-        let last_update = LastUpdate::<T>::get(subnet_id);
+        // Heartbeats count as updates on subnets that opted into them
+        let last_update = PalletChain::<T>::get_last_activity_for(subnet_id);
         let block_at_registration = PalletChain::<T>::get_block_at_registration(subnet_id);
         let validator_permits = ValidatorPermits::<T>::get(subnet_id);
         let alpha_values = Self::get_alpha_values_32(subnet_id);
@@ -5,7 +5,7 @@ use crate::consensus::util::params::ConsensusParams;
 use consensus::yuma::YumaEpoch;
 use frame_support::storage::with_storage_layer;
 use pallet_emission_api::SubnetConsensus;
-use pallet_chain::{Pallet as PalletChain, N};
+use pallet_chain::{Pallet as PalletChain, PausableOperation, N};
 
 const NO_WEIGHTS: &str = "no weights";
 
@@ -262,10 +262,15 @@ impl<T: Config> Pallet<T> {
     /// * `emission_per_block` - The total emission to be distributed per block.
     ///
     /// This function calculates the emission distribution across subnets and
-    /// processes each subnet accordingly.
+    /// processes each subnet accordingly. Nothing is distributed while emission is paused.
     pub fn process_emission_distribution(block_number: u64, emission_per_block: u64) {
         log::debug!("stepping block {block_number:?}");
 
+        if PalletChain::<T>::is_paused(PausableOperation::Emission) {
+            log::warn!("emission is paused, skipping distribution for block {block_number}");
+            return;
+        }
+
         let subnets_emission_distribution = Self::get_pricing(emission_per_block);
         process_subnets::<T>(block_number, subnets_emission_distribution);
     }
@@ -298,6 +303,20 @@ impl<T: Config> Pallet<T> {
             .map(|(netuid, _)| netuid)
     }
 
+    /// Gets the subnet with the lowest emission that modules can be evicted from, ignoring the
+    /// subnet immunity. Subnets whose pruning strategy doesn't evict modules are left out.
+    pub fn get_lowest_evictable_netuid() -> Option<u16> {
+        SubnetEmission::<T>::iter()
+            .filter(|(netuid, _)| Self::can_remove_subnet(*netuid))
+            .filter(|(netuid, _)| pallet_chain::N::<T>::get(netuid) > 0)
+            .filter(|(netuid, _)| {
+                pallet_chain::SubnetPruningStrategy::<T>::get(netuid)
+                    != pallet_chain::PruningStrategy::NoEviction
+            })
+            .min_by_key(|(_, emission)| *emission)
+            .map(|(netuid, _)| netuid)
+    }
+
     /// Sets the emission storage for a given subnet.
     ///
     /// # Arguments
@@ -312,6 +331,43 @@ impl<T: Config> Pallet<T> {
         SubnetConsensusType::<T>::set(netuid, Some(SubnetConsensus::Yuma));
     }
 
+    /// Moves the subnet to a new consensus. State kept by the previous consensus that the new one
+    /// can't make sense of is reset.
+    pub fn change_subnet_consensus_type(netuid: u16, subnet_consensus: SubnetConsensus) {
+        let previous = SubnetConsensusType::<T>::get(netuid);
+
+        // Bonds only exist under Yuma, and are rebuilt from scratch when entering it.
+        if previous == Some(SubnetConsensus::Yuma) || subnet_consensus == SubnetConsensus::Yuma {
+            let _ = pallet_chain::Bonds::<T>::clear_prefix(netuid, u32::MAX, None);
+            let _ = ConsensusParameters::<T>::clear_prefix(netuid, u32::MAX, None);
+        }
+
+        // Non-mineable subnets don't hand out validator permits nor take weights.
+        if !matches!(
+            subnet_consensus,
+            SubnetConsensus::Linear | SubnetConsensus::Yuma
+        ) {
+            let n = N::<T>::get(netuid) as usize;
+            pallet_chain::ValidatorPermits::<T>::insert(netuid, sp_std::vec![false; n]);
+            pallet_chain::ValidatorTrust::<T>::insert(netuid, sp_std::vec![0; n]);
+            let _ = Weights::<T>::clear_prefix(netuid, u32::MAX, None);
+            let _ = WeightEncryptionData::<T>::clear_prefix(netuid, u32::MAX, None);
+        }
+
+        SubnetConsensusType::<T>::set(netuid, Some(subnet_consensus));
+        Self::deposit_event(Event::<T>::SubnetConsensusChanged(
+            netuid,
+            previous,
+            subnet_consensus,
+        ));
+    }
+
+    /// Runs an epoch on the subnet right away, so the emission it accumulated is paid out before
+    /// it is dissolved and removed.
+    pub fn distribute_pending_emission(netuid: u16) {
+        run_epoch::<T>(netuid);
+    }
+
     pub fn can_remove_subnet(netuid: u16) -> bool {
         matches!(
             SubnetConsensusType::<T>::get(netuid),
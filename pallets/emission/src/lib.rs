@@ -225,6 +225,8 @@ pub mod pallet {
     pub enum Event<T: Config> {
         /// Subnets tempo has finished or Snapshot has been taken
         EpochFinalized(u16),
+        /// The subnet consensus type changed from the first to the second consensus.
+        SubnetConsensusChanged(u16, Option<SubnetConsensus>, SubnetConsensus),
 
     }
 
@@ -381,5 +383,34 @@ pub mod pallet {
         pub fn remove_weight_control(origin: OriginFor<T>, netuid: u16) -> DispatchResult {
             Self::do_remove_weight_control(origin, netuid)
         }
+
+        /// Sets weights only if the netuid still belongs to the subnet generation the caller
+        /// expects, so weights don't end up on a subnet that reused the netuid.
+        #[pallet::call_index(4)]
+        #[pallet::weight((<T as pallet::Config>::WeightInfo::set_weights(), DispatchClass::Normal, Pays::No))]
+        pub fn set_weights_at_generation(
+            origin: OriginFor<T>,
+            netuid: u16,
+            expected_generation: u64,
+            uids: Vec<u16>,
+            weights: Vec<u16>,
+        ) -> DispatchResult {
+            pallet_chain::Pallet::<T>::ensure_subnet_generation(netuid, expected_generation)?;
+            Self::do_set_weights(origin, netuid, uids, weights)
+        }
+
+        /// Delegates weight control only if the netuid still belongs to the subnet generation the
+        /// caller expects, so the delegation doesn't end up on a subnet that reused the netuid.
+        #[pallet::call_index(5)]
+        #[pallet::weight((<T as pallet::Config>::WeightInfo::delegate_weight_control_at_generation(), DispatchClass::Normal, Pays::No))]
+        pub fn delegate_weight_control_at_generation(
+            origin: OriginFor<T>,
+            netuid: u16,
+            expected_generation: u64,
+            target: T::AccountId,
+        ) -> DispatchResult {
+            pallet_chain::Pallet::<T>::ensure_subnet_generation(netuid, expected_generation)?;
+            Self::do_delegate_weight_control(origin, netuid, target)
+        }
     }
 }
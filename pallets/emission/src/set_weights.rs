@@ -3,7 +3,7 @@ use crate::consensus::util::params::ConsensusParams;
 use frame_support::{ensure, pallet_prelude::DispatchResult};
 use frame_system::ensure_signed;
 use pallet_emission_api::SubnetConsensus;
-use pallet_chain::{Error, Pallet as PalletChain, WeightSettingDelegation};
+use pallet_chain::{Error, Pallet as PalletChain, PausableOperation, WeightSettingDelegation};
 use sp_core::Get;
 
 impl<T: Config> Pallet<T> {
@@ -56,6 +56,9 @@ impl<T: Config> Pallet<T> {
     ) -> DispatchResult {
         let key = ensure_signed(origin)?;
 
+        PalletChain::<T>::ensure_not_paused(PausableOperation::Weights)?;
+        PalletChain::<T>::ensure_subnet_active(netuid)?;
+
         if pallet_chain::UseWeightsEncryption::<T>::get(netuid) {
             return Err(Error::<T>::SubnetEncrypted.into());
         }
@@ -390,6 +393,9 @@ impl<T: Config> Pallet<T> {
     ) -> DispatchResult {
         let key = ensure_signed(origin.clone())?;
 
+        PalletChain::<T>::ensure_not_paused(PausableOperation::Weights)?;
+        PalletChain::<T>::ensure_subnet_active(netuid)?;
+
         if !pallet_chain::UseWeightsEncryption::<T>::get(netuid) {
             return Err(Error::<T>::SubnetNotEncrypted.into());
         }
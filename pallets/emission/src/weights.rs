@@ -39,6 +39,7 @@ pub trait WeightInfo {
 	fn set_weights_encrypted() -> Weight;
 	fn delegate_weight_control() -> Weight;
 	fn remove_weight_control() -> Weight;
+	fn delegate_weight_control_at_generation() -> Weight;
 }
 
 /// Weights for `pallet_emission` using the Substrate node and recommended hardware.
@@ -138,6 +139,12 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(T::DbWeight::get().reads(2_u64))
 			.saturating_add(T::DbWeight::get().writes(1_u64))
 	}
+	/// Hand-written estimate, not benchmarked yet. Regenerate with `make run-benchmarking`.
+	fn delegate_weight_control_at_generation() -> Weight {
+		Weight::from_parts(51_000_000, 7140)
+			.saturating_add(T::DbWeight::get().reads(6_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
 }
 
 // For backwards compatibility and tests.
@@ -236,4 +243,10 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().reads(2_u64))
 			.saturating_add(RocksDbWeight::get().writes(1_u64))
 	}
+	/// Hand-written estimate, not benchmarked yet. Regenerate with `make run-benchmarking`.
+	fn delegate_weight_control_at_generation() -> Weight {
+		Weight::from_parts(51_000_000, 7140)
+			.saturating_add(RocksDbWeight::get().reads(6_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
 }
\ No newline at end of file
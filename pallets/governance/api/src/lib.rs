@@ -5,6 +5,9 @@ use parity_scale_codec::{Decode, Encode, MaxEncodedLen};
 use scale_info::{prelude::collections::BTreeSet, TypeInfo};
 use sp_runtime::{DispatchResult, Percent};
 
+/// The maximum amount of members the curator council can have.
+pub const MAX_CURATOR_COUNCIL_MEMBERS: u32 = 32;
+
 #[derive(Clone, Copy, Debug, PartialEq, Eq, TypeInfo, Decode, Encode, MaxEncodedLen)]
 pub enum VoteMode {
     Authority = 0,
@@ -19,6 +22,7 @@ pub struct GovernanceConfiguration {
     pub proposal_reward_treasury_allocation: Percent,
     pub max_proposal_reward_treasury_allocation: u64,
     pub proposal_reward_interval: u64,
+    pub enactment_delay: u64,
 }
 
 impl Default for GovernanceConfiguration {
@@ -32,6 +36,8 @@ impl Default for GovernanceConfiguration {
             // Maximum reward allocation 10K.
             max_proposal_reward_treasury_allocation: 10_000_000_000_000,
             proposal_reward_interval: 75_600,
+            // Accepted proposals are enacted roughly one day later.
+            enactment_delay: 10_800,
         }
     }
 }
@@ -76,11 +82,19 @@ pub trait GovernanceApi<AccountId> {
 
     fn whitelisted_keys() -> BTreeSet<AccountId>;
 
-    fn get_curator() -> AccountId;
+    /// Returns whether the key can register on the subnet, which is always the case unless the
+    /// subnet runs its own application process.
+    fn is_approved_for_subnet(subnet_id: u16, module_key: &AccountId) -> bool;
+
+    /// Returns the curator council members and the amount of approvals curator actions need.
+    fn get_curator_council() -> (BTreeSet<AccountId>, u32);
 
-    fn set_curator(key: &AccountId);
+    /// Replaces the curator council members and their approval threshold.
+    fn set_curator_council(members: BTreeSet<AccountId>, threshold: u32) -> DispatchResult;
 
     fn set_general_subnet_application_cost(amount: u64);
 
-    fn clear_subnet_includes(netuid: u16);
+    /// Removes up to `limit` storage entries of the subnet. Returns the amount of removed
+    /// entries and whether the subnet storage is fully cleared.
+    fn clear_subnet_includes(netuid: u16, limit: u32) -> (u32, bool);
 }
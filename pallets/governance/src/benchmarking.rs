@@ -4,8 +4,8 @@ use crate::{Pallet as GovernanceMod, *};
 use frame_benchmarking::{account, benchmarks};
 use frame_system::RawOrigin;
 pub use pallet::*;
-use pallet_chain::{Pallet as ChainMod, SubnetBurn};
-use sp_std::vec::Vec;
+use pallet_chain::{MetadataKey, ModuleAddress, Pallet as ChainMod, SubnetBurn};
+use sp_std::{collections::btree_set::BTreeSet, vec::Vec};
 
 fn submit_dao_application<T: Config>() -> Result<(), &'static str> {
     // First add the application
@@ -29,7 +29,10 @@ fn register_mock<T: Config>(
     module_key: T::AccountId,
     name: Vec<u8>,
 ) -> Result<(), &'static str> {
-    let address = "test".as_bytes().to_vec();
+    let address = ModuleAddress::Dns {
+        host: "test".as_bytes().to_vec(),
+        port: 30333,
+    };
     let network = "testnet".as_bytes().to_vec();
 
     let enough_stake = 10000000000000u64;
@@ -37,11 +40,11 @@ fn register_mock<T: Config>(
         &key,
         ChainMod::<T>::u64_to_balance(SubnetBurn::<T>::get() + enough_stake).unwrap(),
     );
-    let metadata = Some("metadata".as_bytes().to_vec());
+    let metadata = sp_std::vec![(MetadataKey::Website, Some("metadata".as_bytes().to_vec()))];
     let _ = ChainMod::<T>::register_subnet(
         RawOrigin::Signed(key.clone()).into(),
         network.clone(),
-        None,
+        Vec::new(),
     );
     ChainMod::<T>::register(
         RawOrigin::Signed(key.clone()).into(),
@@ -86,7 +89,8 @@ benchmarks! {
             params.floor_validator_weight_fee,           // floor_validator_weight_fee: min validator weight fee
             params.floor_founder_share,            // floor_founder_share: min founder share
             params.min_weight_stake,               // min_weight_stake: min weight stake required
-            params.curator,                            // curator: subnet 0 dao multisig
+            params.curator_council,                    // curator_council: members approving curator actions
+            params.curator_threshold,                  // curator_threshold: approvals needed by curator actions
             params.governance_config.proposal_cost,                      // proposal_cost: amount of $COMAI to create a proposal, returned if proposal gets accepted
             params.governance_config.proposal_expiration,                // proposal_expiration: the block number, proposal expires at
             params.general_subnet_application_cost,     // general_subnet_application_cost
@@ -115,7 +119,7 @@ benchmarks! {
             params.founder.clone(),
             params.founder_share,
             params.name.clone(),
-            params.metadata.clone(),
+            Vec::new(),
             params.immunity_period,
             params.incentive_ratio,
             params.max_allowed_uids,
@@ -129,6 +133,9 @@ benchmarks! {
             params.module_burn_config.clone(),
             params.min_validator_stake,
             params.max_allowed_validators,
+            params.registration_mode,
+            params.pruning_strategy,
+            params.activity_source,
         )?;
 
         // add balance to submit the proposal
@@ -145,7 +152,7 @@ benchmarks! {
         params.founder.clone(),
         params.founder_share,
         params.name.clone(),
-        params.metadata.clone(),
+        sp_std::vec![(MetadataKey::Website, Some("metadata".as_bytes().to_vec()))],
         params.immunity_period,
         params.incentive_ratio,
         params.max_allowed_uids,
@@ -159,6 +166,9 @@ benchmarks! {
         params.module_burn_config,
         params.min_validator_stake,
         params.max_allowed_validators,
+        params.registration_mode,
+        params.pruning_strategy,
+        params.activity_source,
     )
 
     // 2
@@ -265,7 +275,7 @@ benchmarks! {
         // First add the application
         submit_dao_application::<T>()?;
         let caller: T::AccountId = account("Alice", 0, 1);
-        Curator::<T>::set(caller.clone());
+        CuratorCouncil::<T>::set(BoundedBTreeSet::try_from(BTreeSet::from([caller.clone()])).unwrap());
     }: refuse_dao_application(RawOrigin::Signed(caller), 0)
 
     // 11
@@ -274,7 +284,7 @@ benchmarks! {
         submit_dao_application::<T>()?;
         let caller: T::AccountId = account("Alice", 0, 1);
         let application_key: T::AccountId = account("Bob", 0, 2);
-        Curator::<T>::set(caller.clone());
+        CuratorCouncil::<T>::set(BoundedBTreeSet::try_from(BTreeSet::from([caller.clone()])).unwrap());
     }: add_to_whitelist(RawOrigin::Signed(caller), application_key)
 
     // 12
@@ -283,10 +293,227 @@ benchmarks! {
         submit_dao_application::<T>()?;
         let caller: T::AccountId = account("Alice", 0, 1);
         let application_key: T::AccountId = account("Bob", 0, 2);
-        Curator::<T>::set(caller.clone());
+        CuratorCouncil::<T>::set(BoundedBTreeSet::try_from(BTreeSet::from([caller.clone()])).unwrap());
         // Now add it to whitelist
         GovernanceMod::<T>::add_to_whitelist(RawOrigin::Signed(caller.clone()).into(),
     application_key.clone())?; }: remove_from_whitelist(RawOrigin::Signed(caller),
     application_key)
 
+    // 13
+    add_batch_proposal {
+        // Global parameters are the most expensive action to validate
+        let a in 1 .. MAX_BATCH_ACTIONS;
+
+        let caller: T::AccountId = account("Alice", 0, 1);
+        ChainMod::<T>::add_balance_to_account(
+            &caller,
+            ChainMod::<T>::u64_to_balance(1_000_000_000_000_000).unwrap()
+        );
+
+        let data = "ipfshash".as_bytes().to_vec();
+        let params = ChainMod::<T>::global_params();
+        let actions: Vec<_> = (0..a).map(|_| ProposalData::GlobalParams(params.clone())).collect();
+    }: add_batch_proposal(RawOrigin::Signed(caller), data, actions)
+
+    // 14
+    add_treasury_grant_proposal {
+        let caller: T::AccountId = account("Alice", 0, 1);
+        ChainMod::<T>::add_balance_to_account(
+            &caller,
+            ChainMod::<T>::u64_to_balance(1_000_000_000_000_000).unwrap()
+        );
+        ChainMod::<T>::add_balance_to_account(
+            &DaoTreasuryAddress::<T>::get(),
+            ChainMod::<T>::u64_to_balance(1_000_000_000_000_000).unwrap()
+        );
+
+        let data = "ipfshash".as_bytes().to_vec();
+    }: add_treasury_grant_proposal(RawOrigin::Signed(caller.clone()), data, caller.clone(), 100, 4, 100)
+
+    // 15
+    add_cancel_treasury_grant_proposal {
+        let caller: T::AccountId = account("Alice", 0, 1);
+        ChainMod::<T>::add_balance_to_account(
+            &caller,
+            ChainMod::<T>::u64_to_balance(1_000_000_000_000_000).unwrap()
+        );
+
+        let grant_id = GovernanceMod::<T>::create_treasury_grant(caller.clone(), 100, 4, 100);
+        let data = "ipfshash".as_bytes().to_vec();
+    }: add_cancel_treasury_grant_proposal(RawOrigin::Signed(caller), data, grant_id)
+
+    // 16
+    add_subnet_consensus_change_proposal {
+        let caller: T::AccountId = account("Alice", 0, 1);
+        register_mock::<T>(caller.clone(), caller.clone(), "test".as_bytes().to_vec())?;
+        let netuid = ChainMod::<T>::get_netuid_for_name("testnet".as_bytes()).unwrap();
+
+        let data = "ipfshash".as_bytes().to_vec();
+    }: add_subnet_consensus_change_proposal(RawOrigin::Signed(caller), data, netuid, SubnetConsensus::Linear)
+
+    // 17
+    pause_operation {
+        let caller: T::AccountId = account("Alice", 0, 1);
+        PauseGuardian::<T>::set(Some(caller.clone()));
+    }: pause_operation(RawOrigin::Signed(caller), PausableOperation::Registration, 100)
+
+    // 18
+    resume_operation {
+        let caller: T::AccountId = account("Alice", 0, 1);
+        PauseGuardian::<T>::set(Some(caller.clone()));
+        GovernanceMod::<T>::emergency_pause(PausableOperation::Registration, 100);
+    }: resume_operation(RawOrigin::Signed(caller), PausableOperation::Registration)
+
+    // 19
+    add_emergency_pause_proposal {
+        let caller: T::AccountId = account("Alice", 0, 1);
+        ChainMod::<T>::add_balance_to_account(
+            &caller,
+            ChainMod::<T>::u64_to_balance(1_000_000_000_000_000).unwrap()
+        );
+
+        let data = "ipfshash".as_bytes().to_vec();
+    }: add_emergency_pause_proposal(RawOrigin::Signed(caller), data, PausableOperation::Staking, 100)
+
+    // 20
+    add_emergency_resume_proposal {
+        let caller: T::AccountId = account("Alice", 0, 1);
+        ChainMod::<T>::add_balance_to_account(
+            &caller,
+            ChainMod::<T>::u64_to_balance(1_000_000_000_000_000).unwrap()
+        );
+
+        let data = "ipfshash".as_bytes().to_vec();
+    }: add_emergency_resume_proposal(RawOrigin::Signed(caller), data, PausableOperation::Staking)
+
+    // 21
+    add_pause_guardian_proposal {
+        let caller: T::AccountId = account("Alice", 0, 1);
+        ChainMod::<T>::add_balance_to_account(
+            &caller,
+            ChainMod::<T>::u64_to_balance(1_000_000_000_000_000).unwrap()
+        );
+
+        let data = "ipfshash".as_bytes().to_vec();
+    }: add_pause_guardian_proposal(RawOrigin::Signed(caller.clone()), data, Some(caller.clone()))
+
+    // 22
+    renew_whitelist {
+        submit_dao_application::<T>()?;
+        let caller: T::AccountId = account("Alice", 0, 1);
+        let application_key: T::AccountId = account("Bob", 0, 2);
+        CuratorCouncil::<T>::set(BoundedBTreeSet::try_from(BTreeSet::from([caller.clone()])).unwrap());
+        GovernanceMod::<T>::add_to_whitelist(RawOrigin::Signed(caller.clone()).into(), application_key.clone())?;
+    }: renew_whitelist(RawOrigin::Signed(caller), application_key)
+
+    // 23
+    set_subnet_application_cost {
+        let caller: T::AccountId = account("Alice", 0, 1);
+        register_mock::<T>(caller.clone(), caller.clone(), "test".as_bytes().to_vec())?;
+        let netuid = ChainMod::<T>::get_netuid_for_name("testnet".as_bytes()).unwrap();
+    }: set_subnet_application_cost(RawOrigin::Signed(caller), netuid, Some(1_000_000_000))
+
+    // 24
+    add_subnet_application {
+        let caller: T::AccountId = account("Alice", 0, 1);
+        let module_key: T::AccountId = account("Bob", 0, 2);
+        register_mock::<T>(caller.clone(), caller.clone(), "test".as_bytes().to_vec())?;
+        let netuid = ChainMod::<T>::get_netuid_for_name("testnet".as_bytes()).unwrap();
+        SubnetApplicationCost::<T>::insert(netuid, 1_000_000_000);
+        let data = "test".as_bytes().to_vec();
+    }: add_subnet_application(RawOrigin::Signed(caller), netuid, module_key, data)
+
+    // 25
+    set_subnet_whitelisted {
+        let caller: T::AccountId = account("Alice", 0, 1);
+        let module_key: T::AccountId = account("Bob", 0, 2);
+        register_mock::<T>(caller.clone(), caller.clone(), "test".as_bytes().to_vec())?;
+        let netuid = ChainMod::<T>::get_netuid_for_name("testnet".as_bytes()).unwrap();
+        SubnetApplicationCost::<T>::insert(netuid, 1_000_000_000);
+        GovernanceMod::<T>::add_subnet_application(
+            RawOrigin::Signed(caller.clone()).into(),
+            netuid,
+            module_key.clone(),
+            "test".as_bytes().to_vec(),
+        )?;
+    }: set_subnet_whitelisted(RawOrigin::Signed(caller), netuid, module_key, true)
+
+    // 26
+    add_subnet_application_cost_proposal {
+        let caller: T::AccountId = account("Alice", 0, 1);
+        register_mock::<T>(caller.clone(), caller.clone(), "test".as_bytes().to_vec())?;
+        let netuid = ChainMod::<T>::get_netuid_for_name("testnet".as_bytes()).unwrap();
+        SubnetGovernanceConfig::<T>::mutate(netuid, |config| config.vote_mode = VoteMode::Vote);
+        ChainMod::<T>::add_balance_to_account(
+            &caller,
+            ChainMod::<T>::u64_to_balance(1_000_000_000_000_000).unwrap()
+        );
+
+        let data = "ipfshash".as_bytes().to_vec();
+    }: add_subnet_application_cost_proposal(RawOrigin::Signed(caller), data, netuid, Some(1_000_000_000))
+
+    // 27
+    add_subnet_whitelist_proposal {
+        let caller: T::AccountId = account("Alice", 0, 1);
+        let module_key: T::AccountId = account("Bob", 0, 2);
+        register_mock::<T>(caller.clone(), caller.clone(), "test".as_bytes().to_vec())?;
+        let netuid = ChainMod::<T>::get_netuid_for_name("testnet".as_bytes()).unwrap();
+        SubnetApplicationCost::<T>::insert(netuid, 1_000_000_000);
+        GovernanceMod::<T>::add_subnet_application(
+            RawOrigin::Signed(caller.clone()).into(),
+            netuid,
+            module_key.clone(),
+            "test".as_bytes().to_vec(),
+        )?;
+        SubnetGovernanceConfig::<T>::mutate(netuid, |config| config.vote_mode = VoteMode::Vote);
+
+        let data = "ipfshash".as_bytes().to_vec();
+    }: add_subnet_whitelist_proposal(RawOrigin::Signed(caller), data, netuid, module_key, true)
+
+    // 28
+    veto_enactment {
+        let caller: T::AccountId = account("Alice", 0, 1);
+        PauseGuardian::<T>::set(Some(caller.clone()));
+        ScheduledEnactments::<T>::insert(100, 0, ProposalData::<T>::GlobalCustom);
+    }: veto_enactment(RawOrigin::Signed(caller), 0)
+
+    // 29
+    add_veto_enactment_proposal {
+        let caller: T::AccountId = account("Alice", 0, 1);
+        ChainMod::<T>::add_balance_to_account(
+            &caller,
+            ChainMod::<T>::u64_to_balance(1_000_000_000_000_000).unwrap()
+        );
+        ScheduledEnactments::<T>::insert(100, 0, ProposalData::<T>::GlobalCustom);
+
+        let data = "ipfshash".as_bytes().to_vec();
+    }: add_veto_enactment_proposal(RawOrigin::Signed(caller), data, 0)
+
+    // 30
+    add_subnet_founder_transfer_proposal {
+        let caller: T::AccountId = account("Alice", 0, 1);
+        let new_founder: T::AccountId = account("Bob", 0, 2);
+        register_mock::<T>(caller.clone(), caller.clone(), "test".as_bytes().to_vec())?;
+        let netuid = ChainMod::<T>::get_netuid_for_name("testnet".as_bytes()).unwrap();
+        SubnetGovernanceConfig::<T>::mutate(netuid, |config| config.vote_mode = VoteMode::Vote);
+        ChainMod::<T>::add_balance_to_account(
+            &caller,
+            ChainMod::<T>::u64_to_balance(1_000_000_000_000_000).unwrap()
+        );
+
+        let data = "ipfshash".as_bytes().to_vec();
+    }: add_subnet_founder_transfer_proposal(RawOrigin::Signed(caller), data, netuid, new_founder)
+
+    // 31
+    add_override_veto_proposal {
+        let caller: T::AccountId = account("Alice", 0, 1);
+        ChainMod::<T>::add_balance_to_account(
+            &caller,
+            ChainMod::<T>::u64_to_balance(1_000_000_000_000_000).unwrap()
+        );
+        GuardianVetoedEnactments::<T>::insert(0, ProposalData::<T>::GlobalCustom);
+
+        let data = "ipfshash".as_bytes().to_vec();
+    }: add_override_veto_proposal(RawOrigin::Signed(caller), data, 0)
+
 }
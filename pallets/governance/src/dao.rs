@@ -1,9 +1,10 @@
 use crate::*;
-use frame_support::pallet_prelude::{BoundedVec, ConstU32, DispatchResult};
+use frame_support::pallet_prelude::{BoundedVec, ConstU32, DispatchError, DispatchResult, Get, Weight};
 use frame_system::ensure_signed;
 use pallet_chain::Pallet as PalletChain;
 use parity_scale_codec::{Decode, Encode, MaxEncodedLen};
 use scale_info::TypeInfo;
+use sp_std::collections::btree_set::BTreeSet;
 
 #[derive(Clone, Default, TypeInfo, Decode, Encode, MaxEncodedLen, frame_support::DebugNoBound)]
 #[scale_info(skip_type_params(T))]
@@ -26,6 +27,15 @@ pub enum ApplicationStatus {
     Removed,
 }
 
+/// An action that needs the approval of the curator council threshold to be executed.
+#[derive(Clone, Debug, PartialEq, Eq, MaxEncodedLen, TypeInfo, Decode, Encode)]
+pub enum CuratorAction<AccountId> {
+    AddToWhitelist(AccountId),
+    RemoveFromWhitelist(AccountId),
+    RefuseApplication(u64),
+    RenewWhitelist(AccountId),
+}
+
 impl<T: Config> Pallet<T> {
     fn get_next_application_id() -> u64 {
         match CuratorApplications::<T>::iter_keys().max() {
@@ -34,9 +44,27 @@ impl<T: Config> Pallet<T> {
         }
     }
 
+    /// Keys can only apply again once their previous applications were accepted, to renew an
+    /// expired whitelist membership.
     #[must_use]
     fn can_add_application_status_based(key: &T::AccountId) -> bool {
-        !CuratorApplications::<T>::iter().any(|(_, app)| app.user_id == *key)
+        CuratorApplications::<T>::iter_values()
+            .filter(|app| app.user_id == *key)
+            .all(|app| app.status == ApplicationStatus::Accepted)
+    }
+
+    /// Finds the pending application of the key. Accepted applications are kept, so renewed
+    /// memberships leave several applications behind for the same key.
+    fn get_pending_application(key: &T::AccountId) -> Result<CuratorApplication<T>, Error<T>> {
+        let applications: Vec<_> = CuratorApplications::<T>::iter_values()
+            .filter(|app| app.user_id == *key)
+            .collect();
+        ensure!(!applications.is_empty(), Error::<T>::ApplicationNotFound);
+
+        applications
+            .into_iter()
+            .find(|app| app.status == ApplicationStatus::Pending)
+            .ok_or(Error::<T>::ApplicationNotPending)
     }
 
     pub fn add_application(
@@ -95,8 +123,19 @@ impl<T: Config> Pallet<T> {
     ) -> DispatchResult {
         let key = ensure_signed(origin)?;
 
-        // Ensure that the key is the curator multisig.
-        ensure!(Curator::<T>::get() == key, Error::<T>::NotCurator);
+        // Ensure that the key is part of the curator council.
+        ensure!(Self::is_curator(&key), Error::<T>::NotCurator);
+
+        let application =
+            CuratorApplications::<T>::get(application_id).ok_or(Error::<T>::ApplicationNotFound)?;
+        ensure!(
+            application.status == ApplicationStatus::Pending,
+            Error::<T>::ApplicationNotPending
+        );
+
+        if !Self::approve_curator_action(key, CuratorAction::RefuseApplication(application_id))? {
+            return Ok(());
+        }
 
         CuratorApplications::<T>::try_mutate(application_id, |application| match application {
             Some(app) if app.status == ApplicationStatus::Pending => {
@@ -132,9 +171,7 @@ impl<T: Config> Pallet<T> {
         // Perform actions based on the application data type
         // The owners will handle the off-chain logic
 
-        let mut application = CuratorApplications::<T>::iter_values()
-            .find(|app| app.user_id == *user_id)
-            .ok_or(Error::<T>::ApplicationNotFound)?;
+        let mut application = Self::get_pending_application(user_id)?;
 
         // Give the proposer back his tokens, if the application passed
         PalletChain::<T>::add_balance_to_account(
@@ -153,7 +190,7 @@ impl<T: Config> Pallet<T> {
         module_key: T::AccountId,
     ) -> DispatchResult {
         let key = ensure_signed(origin)?;
-        ensure!(Curator::<T>::get() == key, Error::<T>::NotCurator);
+        ensure!(Self::is_curator(&key), Error::<T>::NotCurator);
 
         // make sure application isnt already whitelisted
         ensure!(
@@ -161,16 +198,13 @@ impl<T: Config> Pallet<T> {
             Error::<T>::AlreadyWhitelisted
         );
 
-        let application = CuratorApplications::<T>::iter_values()
-            .find(|app| app.user_id == module_key)
-            .ok_or(Error::<T>::ApplicationNotFound)?;
+        Self::get_pending_application(&module_key)?;
 
-        ensure!(
-            application.status == ApplicationStatus::Pending,
-            Error::<T>::ApplicationNotPending
-        );
+        if !Self::approve_curator_action(key, CuratorAction::AddToWhitelist(module_key.clone()))? {
+            return Ok(());
+        }
 
-        LegitWhitelist::<T>::insert(&module_key, ());
+        Self::renew_whitelist_membership(&module_key);
 
         T::execute_application(&module_key)?;
 
@@ -184,12 +218,19 @@ impl<T: Config> Pallet<T> {
         module_key: T::AccountId,
     ) -> DispatchResult {
         let key = ensure_signed(origin)?;
-        ensure!(Curator::<T>::get() == key, Error::<T>::NotCurator);
+        ensure!(Self::is_curator(&key), Error::<T>::NotCurator);
         ensure!(
             Self::is_in_legit_whitelist(&module_key),
             Error::<T>::NotWhitelisted
         );
 
+        if !Self::approve_curator_action(
+            key,
+            CuratorAction::RemoveFromWhitelist(module_key.clone()),
+        )? {
+            return Ok(());
+        }
+
         LegitWhitelist::<T>::remove(&module_key);
 
         CuratorApplications::<T>::iter()
@@ -204,6 +245,131 @@ impl<T: Config> Pallet<T> {
         Ok(())
     }
 
+    /// Extends the whitelist membership of a module that is still whitelisted, even if its
+    /// membership already expired but wasn't swept yet.
+    pub fn do_renew_whitelist(
+        origin: T::RuntimeOrigin,
+        module_key: T::AccountId,
+    ) -> DispatchResult {
+        let key = ensure_signed(origin)?;
+        ensure!(Self::is_curator(&key), Error::<T>::NotCurator);
+        ensure!(
+            LegitWhitelist::<T>::contains_key(&module_key),
+            Error::<T>::NotWhitelisted
+        );
+
+        if !Self::approve_curator_action(key, CuratorAction::RenewWhitelist(module_key.clone()))? {
+            return Ok(());
+        }
+
+        Self::renew_whitelist_membership(&module_key);
+
+        Ok(())
+    }
+
+    /// Whitelists the module until `WhitelistDuration` blocks from now.
+    fn renew_whitelist_membership(module_key: &T::AccountId) {
+        let expiry = PalletChain::<T>::get_current_block_number()
+            .saturating_add(WhitelistDuration::<T>::get());
+        LegitWhitelist::<T>::insert(module_key, expiry);
+
+        Self::deposit_event(Event::WhitelistModuleRenewed(module_key.clone(), expiry));
+    }
+
+    /// Removes expired whitelist memberships, as long as the weight budget allows.
+    pub fn sweep_expired_whitelist(remaining_weight: Weight) -> Weight {
+        let db_weight = T::DbWeight::get();
+        let current_block = PalletChain::<T>::get_current_block_number();
+
+        let mut used_weight = Weight::zero();
+        let mut expired = Vec::new();
+        for (module_key, expiry) in LegitWhitelist::<T>::iter() {
+            if used_weight
+                .saturating_add(db_weight.reads_writes(1, 1))
+                .any_gt(remaining_weight)
+            {
+                break;
+            }
+
+            used_weight = used_weight.saturating_add(db_weight.reads(1));
+            if expiry <= current_block {
+                used_weight = used_weight.saturating_add(db_weight.writes(1));
+                expired.push(module_key);
+            }
+        }
+
+        for module_key in expired {
+            LegitWhitelist::<T>::remove(&module_key);
+            Self::deposit_event(Event::WhitelistModuleExpired(module_key));
+        }
+
+        used_weight
+    }
+
+    // Curator council
+    // ===============
+
+    /// Records the approval of a council member for the action. Returns whether the action
+    /// reached the council threshold and should be executed.
+    fn approve_curator_action(
+        key: T::AccountId,
+        action: CuratorAction<T::AccountId>,
+    ) -> Result<bool, DispatchError> {
+        let approvals = CuratorApprovals::<T>::try_mutate(&action, |approvals| {
+            ensure!(
+                !approvals.contains(&key),
+                Error::<T>::CuratorActionAlreadyApproved
+            );
+            approvals.try_insert(key.clone()).map_err(|_| Error::<T>::InternalError)?;
+            Ok::<_, Error<T>>(approvals.len())
+        })?;
+
+        Self::deposit_event(Event::CuratorActionApproved(key, action.clone()));
+
+        if approvals < CuratorThreshold::<T>::get() as usize {
+            return Ok(false);
+        }
+
+        CuratorApprovals::<T>::remove(&action);
+        Self::deposit_event(Event::CuratorActionExecuted(action));
+
+        Ok(true)
+    }
+
+    pub fn is_curator(key: &T::AccountId) -> bool {
+        CuratorCouncil::<T>::get().contains(key)
+    }
+
+    pub fn curator_council() -> (BTreeSet<T::AccountId>, u32) {
+        (
+            CuratorCouncil::<T>::get().into_inner(),
+            CuratorThreshold::<T>::get(),
+        )
+    }
+
+    /// Replaces the curator council. Pending approvals were given by the previous council, so
+    /// they are discarded.
+    pub fn set_curator_council(members: BTreeSet<T::AccountId>, threshold: u32) -> DispatchResult {
+        ensure!(
+            threshold > 0 && threshold as usize <= members.len(),
+            Error::<T>::InvalidCuratorCouncil
+        );
+        let members: CuratorCouncilMembers<T> =
+            BoundedBTreeSet::try_from(members).map_err(|_| Error::<T>::InvalidCuratorCouncil)?;
+
+        if CuratorCouncil::<T>::get() == members && CuratorThreshold::<T>::get() == threshold {
+            return Ok(());
+        }
+
+        CuratorCouncil::<T>::put(members);
+        CuratorThreshold::<T>::put(threshold);
+        let _ = CuratorApprovals::<T>::clear(u32::MAX, None);
+
+        Self::deposit_event(Event::CuratorCouncilUpdated);
+
+        Ok(())
+    }
+
     // Util
     // ====
 
@@ -213,6 +379,16 @@ impl<T: Config> Pallet<T> {
 
     // Whitelist management
     pub fn is_in_legit_whitelist(account_id: &T::AccountId) -> bool {
-        LegitWhitelist::<T>::contains_key(account_id)
+        let current_block = PalletChain::<T>::get_current_block_number();
+        LegitWhitelist::<T>::get(account_id).is_some_and(|expiry| current_block < expiry)
+    }
+
+    /// The modules with a whitelist membership that didn't expire yet.
+    pub fn whitelisted_keys() -> BTreeSet<T::AccountId> {
+        let current_block = PalletChain::<T>::get_current_block_number();
+        LegitWhitelist::<T>::iter()
+            .filter(|(_, expiry)| current_block < *expiry)
+            .map(|(module_key, _)| module_key)
+            .collect()
     }
 }
@@ -0,0 +1,222 @@
+use crate::*;
+use frame_support::pallet_prelude::{BoundedVec, DispatchResult};
+use frame_system::ensure_signed;
+use pallet_chain::{Pallet as PalletChain, PausableOperation, PausedOperations};
+
+/// The longest pause the guardian can impose on its own, roughly one day.
+pub const MAX_GUARDIAN_PAUSE_DURATION: u64 = 10_800;
+
+/// The longest pause an emergency proposal can impose, roughly a week.
+pub const MAX_EMERGENCY_PAUSE_DURATION: u64 = 75_600;
+
+impl<T: Config> Pallet<T> {
+    pub fn is_pause_guardian(key: &T::AccountId) -> bool {
+        PauseGuardian::<T>::get().is_some_and(|guardian| &guardian == key)
+    }
+
+    pub fn validate_pause_duration(duration: u64, max_duration: u64) -> DispatchResult {
+        ensure!(
+            duration > 0 && duration <= max_duration,
+            Error::<T>::InvalidPauseDuration
+        );
+        Ok(())
+    }
+
+    /// Pauses the operation for `duration` blocks from now on behalf of governance.
+    pub fn emergency_pause(operation: PausableOperation, duration: u64) {
+        let until = PalletChain::<T>::get_current_block_number().saturating_add(duration);
+        GovernancePauses::<T>::insert(operation, until);
+        PalletChain::<T>::pause_operation(operation, until);
+    }
+
+    /// Lifts the pause on behalf of governance, whoever imposed it.
+    pub fn emergency_resume(operation: PausableOperation) {
+        GovernancePauses::<T>::remove(operation);
+        PalletChain::<T>::resume_operation(operation);
+    }
+
+    /// Whether governance imposed a pause on the operation that is still running.
+    pub fn is_paused_by_governance(operation: PausableOperation) -> bool {
+        GovernancePauses::<T>::get(operation)
+            .is_some_and(|until| PalletChain::<T>::get_current_block_number() < until)
+    }
+
+    pub fn set_pause_guardian(guardian: Option<T::AccountId>) {
+        PauseGuardian::<T>::set(guardian.clone());
+        Self::deposit_event(Event::PauseGuardianChanged(guardian));
+    }
+
+    pub fn do_guardian_pause_operation(
+        origin: T::RuntimeOrigin,
+        operation: PausableOperation,
+        duration: u64,
+    ) -> DispatchResult {
+        let key = ensure_signed(origin)?;
+        ensure!(Self::is_pause_guardian(&key), Error::<T>::NotPauseGuardian);
+        Self::validate_pause_duration(duration, MAX_GUARDIAN_PAUSE_DURATION)?;
+
+        // The guardian can't shorten a longer pause imposed by governance.
+        let until = PalletChain::<T>::get_current_block_number().saturating_add(duration);
+        let until =
+            PausedOperations::<T>::get(operation).map_or(until, |current| current.max(until));
+        PalletChain::<T>::pause_operation(operation, until);
+
+        Ok(())
+    }
+
+    pub fn do_guardian_resume_operation(
+        origin: T::RuntimeOrigin,
+        operation: PausableOperation,
+    ) -> DispatchResult {
+        let key = ensure_signed(origin)?;
+        ensure!(Self::is_pause_guardian(&key), Error::<T>::NotPauseGuardian);
+        ensure!(
+            PalletChain::<T>::is_paused(operation),
+            Error::<T>::OperationNotPaused
+        );
+        ensure!(
+            !Self::is_paused_by_governance(operation),
+            Error::<T>::OperationPausedByGovernance
+        );
+
+        PalletChain::<T>::resume_operation(operation);
+
+        Ok(())
+    }
+
+    /// Finds the block an accepted proposal is going to be enacted at.
+    pub fn scheduled_enactment_block(proposal_id: ProposalId) -> Result<u64, Error<T>> {
+        ScheduledEnactments::<T>::iter_keys()
+            .find_map(|(block, id)| (id == proposal_id).then_some(block))
+            .ok_or(Error::<T>::EnactmentNotScheduled)
+    }
+
+    /// Drops an accepted proposal from the enactment queue, or one held by a guardian veto, so
+    /// it's never executed.
+    pub fn veto_enactment(proposal_id: ProposalId) -> DispatchResult {
+        if GuardianVetoedEnactments::<T>::take(proposal_id).is_none() {
+            let block = Self::scheduled_enactment_block(proposal_id)?;
+            ScheduledEnactments::<T>::remove(block, proposal_id);
+        }
+
+        Self::deposit_event(Event::ProposalEnactmentVetoed(proposal_id));
+        Ok(())
+    }
+
+    /// The guardian veto only holds the proposal back until governance overrides it, and it
+    /// can't be used against proposals changing the governance itself.
+    pub fn do_guardian_veto_enactment(
+        origin: T::RuntimeOrigin,
+        proposal_id: ProposalId,
+    ) -> DispatchResult {
+        let key = ensure_signed(origin)?;
+        ensure!(Self::is_pause_guardian(&key), Error::<T>::NotPauseGuardian);
+
+        let block = Self::scheduled_enactment_block(proposal_id)?;
+        let data = ScheduledEnactments::<T>::get(block, proposal_id)
+            .ok_or(Error::<T>::EnactmentNotScheduled)?;
+        ensure!(
+            data.is_guardian_vetoable(),
+            Error::<T>::EnactmentNotVetoable
+        );
+
+        ScheduledEnactments::<T>::remove(block, proposal_id);
+        GuardianVetoedEnactments::<T>::insert(proposal_id, data);
+        Self::deposit_event(Event::ProposalEnactmentVetoed(proposal_id));
+
+        Ok(())
+    }
+
+    /// Enacts a proposal held by a guardian veto.
+    pub fn override_guardian_veto(proposal_id: ProposalId) -> DispatchResult {
+        let data = GuardianVetoedEnactments::<T>::take(proposal_id)
+            .ok_or(Error::<T>::EnactmentNotVetoed)?;
+        data.execute()?;
+        Self::deposit_event(Event::ProposalVetoOverridden(proposal_id));
+        Ok(())
+    }
+
+    pub fn do_add_emergency_pause_proposal(
+        origin: T::RuntimeOrigin,
+        data: Vec<u8>,
+        operation: PausableOperation,
+        duration: u64,
+    ) -> DispatchResult {
+        let key = ensure_signed(origin)?;
+        ensure!(!data.is_empty(), Error::<T>::ProposalDataTooSmall);
+        ensure!(data.len() <= 256, Error::<T>::ProposalDataTooLarge);
+        sp_std::str::from_utf8(&data).map_err(|_| Error::<T>::InvalidProposalData)?;
+
+        let proposal_data = ProposalData::EmergencyPause {
+            operation,
+            duration,
+        };
+        proposal_data.validate()?;
+
+        Self::add_proposal(key, BoundedVec::truncate_from(data), proposal_data)
+    }
+
+    pub fn do_add_emergency_resume_proposal(
+        origin: T::RuntimeOrigin,
+        data: Vec<u8>,
+        operation: PausableOperation,
+    ) -> DispatchResult {
+        let key = ensure_signed(origin)?;
+        ensure!(!data.is_empty(), Error::<T>::ProposalDataTooSmall);
+        ensure!(data.len() <= 256, Error::<T>::ProposalDataTooLarge);
+        sp_std::str::from_utf8(&data).map_err(|_| Error::<T>::InvalidProposalData)?;
+
+        let proposal_data = ProposalData::EmergencyResume { operation };
+        proposal_data.validate()?;
+
+        Self::add_proposal(key, BoundedVec::truncate_from(data), proposal_data)
+    }
+
+    pub fn do_add_pause_guardian_proposal(
+        origin: T::RuntimeOrigin,
+        data: Vec<u8>,
+        guardian: Option<T::AccountId>,
+    ) -> DispatchResult {
+        let key = ensure_signed(origin)?;
+        ensure!(!data.is_empty(), Error::<T>::ProposalDataTooSmall);
+        ensure!(data.len() <= 256, Error::<T>::ProposalDataTooLarge);
+        sp_std::str::from_utf8(&data).map_err(|_| Error::<T>::InvalidProposalData)?;
+
+        let proposal_data = ProposalData::SetPauseGuardian { guardian };
+        proposal_data.validate()?;
+
+        Self::add_proposal(key, BoundedVec::truncate_from(data), proposal_data)
+    }
+
+    pub fn do_add_veto_enactment_proposal(
+        origin: T::RuntimeOrigin,
+        data: Vec<u8>,
+        proposal_id: ProposalId,
+    ) -> DispatchResult {
+        let key = ensure_signed(origin)?;
+        ensure!(!data.is_empty(), Error::<T>::ProposalDataTooSmall);
+        ensure!(data.len() <= 256, Error::<T>::ProposalDataTooLarge);
+        sp_std::str::from_utf8(&data).map_err(|_| Error::<T>::InvalidProposalData)?;
+
+        let proposal_data = ProposalData::VetoEnactment { proposal_id };
+        proposal_data.validate()?;
+
+        Self::add_proposal(key, BoundedVec::truncate_from(data), proposal_data)
+    }
+
+    pub fn do_add_override_veto_proposal(
+        origin: T::RuntimeOrigin,
+        data: Vec<u8>,
+        proposal_id: ProposalId,
+    ) -> DispatchResult {
+        let key = ensure_signed(origin)?;
+        ensure!(!data.is_empty(), Error::<T>::ProposalDataTooSmall);
+        ensure!(data.len() <= 256, Error::<T>::ProposalDataTooLarge);
+        sp_std::str::from_utf8(&data).map_err(|_| Error::<T>::InvalidProposalData)?;
+
+        let proposal_data = ProposalData::OverrideVeto { proposal_id };
+        proposal_data.validate()?;
+
+        Self::add_proposal(key, BoundedVec::truncate_from(data), proposal_data)
+    }
+}
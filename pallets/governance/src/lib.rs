@@ -6,8 +6,11 @@
 mod benchmarking;
 
 pub mod dao;
+pub mod emergency;
 pub mod migrations;
 pub mod proposal;
+pub mod subnet_dao;
+pub mod treasury;
 pub mod voting;
 pub mod weights; // Weight benchmarks
 
@@ -15,6 +18,8 @@ use frame_support::{
     dispatch::DispatchResult,
     ensure,
     sp_runtime::{DispatchError, Percent},
+    traits::ConstU32,
+    BoundedBTreeSet,
 };
 use frame_system::pallet_prelude::OriginFor;
 use sp_std::vec::Vec;
@@ -22,35 +27,50 @@ use substrate_fixed::types::I64F64;
 
 pub use pallet::*;
 pub use pallet_governance_api::*;
+use pallet_emission_api::SubnetConsensus;
 use pallet_chain::{
     self, define_subnet_includes,
-    params::{burn::GeneralBurnConfiguration, subnet::SubnetChangeset},
-    DefaultKey,
+    params::{
+        burn::GeneralBurnConfiguration,
+        subnet::{ActivitySource, PruningStrategy, RegistrationMode, SubnetChangeset},
+    },
+    DefaultKey, MetadataChanges, MetadataOwner, PausableOperation,
 };
 
-pub use proposal::{Proposal, ProposalData, ProposalId, ProposalStatus, UnrewardedProposal};
+pub use proposal::{
+    ArchivedProposal, Proposal, ProposalData, ProposalId, ProposalStatus, UnrewardedProposal,
+    MAX_BATCH_ACTIONS,
+};
+pub use subnet_dao::SubnetApplication;
+pub use treasury::{TreasuryGrant, TreasuryGrantId};
 
 type SubnetId = u16;
 
+pub type CuratorCouncilMembers<T> =
+    BoundedBTreeSet<<T as frame_system::Config>::AccountId, ConstU32<MAX_CURATOR_COUNCIL_MEMBERS>>;
+
 #[frame_support::pallet]
 pub mod pallet {
     #![allow(clippy::too_many_arguments)]
 
     pub use crate::weights::WeightInfo;
-    use crate::{dao::CuratorApplication, *};
+    use crate::{
+        dao::{CuratorAction, CuratorApplication},
+        *,
+    };
     use frame_support::{
         pallet_prelude::{ValueQuery, *},
-        traits::{Currency, StorageInstance},
+        traits::{ConstU64, Currency, StorageInstance},
         PalletId,
     };
     use frame_system::pallet_prelude::{ensure_signed, BlockNumberFor};
     use sp_runtime::traits::AccountIdConversion;
 
     #[cfg(feature = "testnet")]
-    const STORAGE_VERSION: StorageVersion = StorageVersion::new(4);
+    const STORAGE_VERSION: StorageVersion = StorageVersion::new(8);
 
     #[cfg(not(feature = "testnet"))]
-    const STORAGE_VERSION: StorageVersion = StorageVersion::new(2);
+    const STORAGE_VERSION: StorageVersion = StorageVersion::new(6);
 
     #[pallet::pallet]
     #[pallet::storage_version(STORAGE_VERSION)]
@@ -80,10 +100,16 @@ pub mod pallet {
                 block_number.try_into().ok().expect("blockchain won't pass 2 ^ 64 blocks");
 
             proposal::tick_proposals::<T>(block_number);
+            proposal::tick_enactments::<T>(block_number);
             proposal::tick_proposal_rewards::<T>(block_number);
+            treasury::tick_treasury_grants::<T>(block_number);
 
             Weight::zero()
         }
+
+        fn on_idle(_n: BlockNumberFor<T>, remaining_weight: Weight) -> Weight {
+            Self::sweep_expired_whitelist(remaining_weight)
+        }
     }
 
     impl<T: Config> StorageInstance for Pallet<T> {
@@ -97,8 +123,8 @@ pub mod pallet {
     // --- Subnet Related Storage ---
 
     define_subnet_includes!(
-        double_maps: {},
-        maps: { SubnetGovernanceConfig, }
+        double_maps: { SubnetApplications, SubnetWhitelist, },
+        maps: { SubnetGovernanceConfig, SubnetApplicationCost, }
     );
 
     #[pallet::storage]
@@ -119,15 +145,69 @@ pub mod pallet {
         }
     }
 
+    /// The subnets running their own application process, mapped to the cost of applying.
+    /// Only keys in [`SubnetWhitelist`] can register modules on them.
+    #[pallet::storage]
+    pub type SubnetApplicationCost<T: Config> = StorageMap<_, Identity, SubnetId, u64>;
+
+    /// The pending applications to each subnet, indexed by the applying module key.
+    #[pallet::storage]
+    pub type SubnetApplications<T: Config> =
+        StorageDoubleMap<_, Identity, SubnetId, Identity, T::AccountId, SubnetApplication<T>>;
+
+    /// The module keys approved to register on each subnet.
+    #[pallet::storage]
+    pub type SubnetWhitelist<T: Config> =
+        StorageDoubleMap<_, Identity, SubnetId, Identity, T::AccountId, ()>;
+
     // --- Proposal Related Storage ---
 
-    /// A map of all proposals, indexed by their IDs.
+    /// The proposals still open for voting, indexed by their IDs. Closed proposals are moved to
+    /// [`ProposalArchive`].
     #[pallet::storage]
     pub type Proposals<T: Config> = StorageMap<_, Identity, ProposalId, Proposal<T>>;
 
+    /// The ID given to the next proposal.
+    #[pallet::storage]
+    pub type NextProposalId<T: Config> = StorageValue<_, ProposalId, ValueQuery>;
+
+    /// The votes on open proposals, `true` meaning the voter agrees with the proposal.
+    #[pallet::storage]
+    pub type ProposalVotes<T: Config> =
+        StorageDoubleMap<_, Identity, ProposalId, Identity, T::AccountId, bool>;
+
+    /// Closed proposals, indexed by their IDs.
+    #[pallet::storage]
+    pub type ProposalArchive<T: Config> = StorageMap<_, Identity, ProposalId, ArchivedProposal<T>>;
+
     #[pallet::storage]
     pub type UnrewardedProposals<T: Config> =
-        StorageMap<_, Identity, ProposalId, UnrewardedProposal<T>>;
+        StorageMap<_, Identity, ProposalId, UnrewardedProposal>;
+
+    /// The stake each voter had when the proposal closed, used to reward them.
+    #[pallet::storage]
+    pub type UnrewardedProposalVotes<T: Config> =
+        StorageDoubleMap<_, Identity, ProposalId, Identity, T::AccountId, u64, ValueQuery>;
+
+    /// Accepted proposals waiting to be enacted, indexed by the block they are enacted at.
+    #[pallet::storage]
+    pub type ScheduledEnactments<T: Config> =
+        StorageDoubleMap<_, Identity, u64, Identity, ProposalId, ProposalData<T>>;
+
+    /// Accepted proposals vetoed by the pause guardian, held until governance overrides or
+    /// confirms the veto.
+    #[pallet::storage]
+    pub type GuardianVetoedEnactments<T: Config> =
+        StorageMap<_, Identity, ProposalId, ProposalData<T>>;
+
+    // --- Treasury Grant Related Storage ---
+
+    /// Treasury grants that still have tranches left to be paid, indexed by their IDs.
+    #[pallet::storage]
+    pub type TreasuryGrants<T: Config> = StorageMap<_, Identity, TreasuryGrantId, TreasuryGrant<T>>;
+
+    #[pallet::storage]
+    pub type NextTreasuryGrantId<T: Config> = StorageValue<_, TreasuryGrantId, ValueQuery>;
 
     // --- Storage Items ---
 
@@ -171,11 +251,52 @@ pub mod pallet {
     #[pallet::storage]
     pub type CuratorApplications<T: Config> = StorageMap<_, Identity, u64, CuratorApplication<T>>;
 
+    /// The whitelisted modules, mapped to the block their membership expires at.
+    #[pallet::storage]
+    pub type LegitWhitelist<T: Config> = StorageMap<_, Identity, T::AccountId, u64>;
+
+    /// How many blocks a whitelist membership lasts before it has to be renewed, roughly six
+    /// months.
+    #[pallet::storage]
+    pub type WhitelistDuration<T: Config> = StorageValue<_, u64, ValueQuery, ConstU64<1_944_000>>;
+
+    #[pallet::type_value]
+    pub fn DefaultCuratorCouncil<T: Config>() -> CuratorCouncilMembers<T> {
+        BoundedBTreeSet::try_from(sp_std::collections::btree_set::BTreeSet::from([
+            DefaultKey::<T>::get(),
+        ]))
+        .unwrap_or_default()
+    }
+
+    /// The members allowed to approve curator actions.
+    #[pallet::storage]
+    pub type CuratorCouncil<T: Config> =
+        StorageValue<_, CuratorCouncilMembers<T>, ValueQuery, DefaultCuratorCouncil<T>>;
+
+    /// The amount of council approvals a curator action needs to be executed.
+    #[pallet::storage]
+    pub type CuratorThreshold<T: Config> = StorageValue<_, u32, ValueQuery, ConstU32<1>>;
+
+    /// The council members that approved each pending curator action.
     #[pallet::storage]
-    pub type LegitWhitelist<T: Config> = StorageMap<_, Identity, T::AccountId, (), ValueQuery>;
+    pub type CuratorApprovals<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        CuratorAction<T::AccountId>,
+        CuratorCouncilMembers<T>,
+        ValueQuery,
+    >;
+
+    // --- Emergency Related Storage ---
 
+    /// The account allowed to pause and resume chain operations without a proposal.
     #[pallet::storage]
-    pub type Curator<T: Config> = StorageValue<_, T::AccountId, ValueQuery, DefaultKey<T>>;
+    pub type PauseGuardian<T: Config> = StorageValue<_, T::AccountId>;
+
+    /// The block until which governance paused each operation. The pause guardian can extend
+    /// these pauses, but can't resume them.
+    #[pallet::storage]
+    pub type GovernancePauses<T: Config> = StorageMap<_, Identity, PausableOperation, u64>;
 
     // --- Extrinsics ---
 
@@ -198,7 +319,8 @@ pub mod pallet {
             floor_validator_weight_fee: Percent,
             floor_founder_share: u8,
             min_weight_stake: u64,
-            curator: T::AccountId,
+            curator_council: CuratorCouncilMembers<T>,
+            curator_threshold: u32,
             proposal_cost: u64,
             proposal_expiration: u32,
             general_subnet_application_cost: u64,
@@ -217,7 +339,8 @@ pub mod pallet {
             params.floor_validator_weight_fee = floor_validator_weight_fee;
             params.floor_founder_share = floor_founder_share;
             params.min_weight_stake = min_weight_stake;
-            params.curator = curator;
+            params.curator_council = curator_council;
+            params.curator_threshold = curator_threshold;
             params.governance_config.proposal_cost = proposal_cost;
             params.governance_config.proposal_expiration = proposal_expiration;
             params.general_subnet_application_cost = general_subnet_application_cost;
@@ -236,7 +359,7 @@ pub mod pallet {
             founder: T::AccountId,
             founder_share: u16,
             name: BoundedVec<u8, ConstU32<256>>,
-            metadata: Option<BoundedVec<u8, ConstU32<120>>>,
+            metadata: MetadataChanges,
             immunity_period: u16,
             incentive_ratio: u16,
             max_allowed_uids: u16,
@@ -250,12 +373,19 @@ pub mod pallet {
             module_burn_config: GeneralBurnConfiguration<T>,
             min_validator_stake: u64,
             max_allowed_validators: Option<u16>,
+            registration_mode: RegistrationMode,
+            pruning_strategy: PruningStrategy,
+            activity_source: ActivitySource,
         ) -> DispatchResult {
             let mut params = pallet_chain::Pallet::subnet_params(netuid);
             params.founder = founder;
             params.founder_share = founder_share;
             params.name = name;
-            params.metadata = metadata;
+            params.metadata = pallet_chain::Pallet::<T>::apply_metadata_changes(
+                params.metadata,
+                metadata,
+                MetadataOwner::Subnet,
+            )?;
             params.immunity_period = immunity_period;
             params.incentive_ratio = incentive_ratio;
             params.max_allowed_uids = max_allowed_uids;
@@ -269,6 +399,9 @@ pub mod pallet {
             params.module_burn_config = module_burn_config;
             params.min_validator_stake = min_validator_stake;
             params.max_allowed_validators = max_allowed_validators;
+            params.registration_mode = registration_mode;
+            params.pruning_strategy = pruning_strategy;
+            params.activity_source = activity_source;
             Self::do_add_subnet_params_proposal(origin, netuid, data, params)
         }
 
@@ -364,6 +497,203 @@ pub mod pallet {
         ) -> DispatchResult {
             Self::do_remove_from_whitelist(origin, module_key)
         }
+
+        #[pallet::call_index(13)]
+        #[pallet::weight((<T as pallet::Config>::WeightInfo::add_batch_proposal((actions.len() as u32).min(MAX_BATCH_ACTIONS)), DispatchClass::Normal, Pays::No))]
+        pub fn add_batch_proposal(
+            origin: OriginFor<T>,
+            data: Vec<u8>,
+            actions: Vec<ProposalData<T>>,
+        ) -> DispatchResult {
+            Self::do_add_batch_proposal(origin, data, actions)
+        }
+
+        #[pallet::call_index(14)]
+        #[pallet::weight((<T as pallet::Config>::WeightInfo::add_treasury_grant_proposal(), DispatchClass::Normal, Pays::No))]
+        pub fn add_treasury_grant_proposal(
+            origin: OriginFor<T>,
+            data: Vec<u8>,
+            dest: T::AccountId,
+            amount: u64,
+            tranches: u32,
+            interval: u64,
+        ) -> DispatchResult {
+            Self::do_add_treasury_grant_proposal(origin, data, dest, amount, tranches, interval)
+        }
+
+        #[pallet::call_index(15)]
+        #[pallet::weight((<T as pallet::Config>::WeightInfo::add_cancel_treasury_grant_proposal(), DispatchClass::Normal, Pays::No))]
+        pub fn add_cancel_treasury_grant_proposal(
+            origin: OriginFor<T>,
+            data: Vec<u8>,
+            grant_id: TreasuryGrantId,
+        ) -> DispatchResult {
+            Self::do_add_cancel_treasury_grant_proposal(origin, data, grant_id)
+        }
+
+        #[pallet::call_index(16)]
+        #[pallet::weight((<T as pallet::Config>::WeightInfo::add_subnet_consensus_change_proposal(), DispatchClass::Normal, Pays::No))]
+        pub fn add_subnet_consensus_change_proposal(
+            origin: OriginFor<T>,
+            data: Vec<u8>,
+            subnet_id: u16,
+            consensus: SubnetConsensus,
+        ) -> DispatchResult {
+            Self::do_add_subnet_consensus_change_proposal(origin, data, subnet_id, consensus)
+        }
+
+        // --- Emergency Pauses ---
+        #[pallet::call_index(17)]
+        #[pallet::weight((<T as pallet::Config>::WeightInfo::pause_operation(), DispatchClass::Operational, Pays::No))]
+        pub fn pause_operation(
+            origin: OriginFor<T>,
+            operation: PausableOperation,
+            duration: u64,
+        ) -> DispatchResult {
+            Self::do_guardian_pause_operation(origin, operation, duration)
+        }
+
+        #[pallet::call_index(18)]
+        #[pallet::weight((<T as pallet::Config>::WeightInfo::resume_operation(), DispatchClass::Operational, Pays::No))]
+        pub fn resume_operation(
+            origin: OriginFor<T>,
+            operation: PausableOperation,
+        ) -> DispatchResult {
+            Self::do_guardian_resume_operation(origin, operation)
+        }
+
+        #[pallet::call_index(19)]
+        #[pallet::weight((<T as pallet::Config>::WeightInfo::add_emergency_pause_proposal(), DispatchClass::Normal, Pays::No))]
+        pub fn add_emergency_pause_proposal(
+            origin: OriginFor<T>,
+            data: Vec<u8>,
+            operation: PausableOperation,
+            duration: u64,
+        ) -> DispatchResult {
+            Self::do_add_emergency_pause_proposal(origin, data, operation, duration)
+        }
+
+        #[pallet::call_index(20)]
+        #[pallet::weight((<T as pallet::Config>::WeightInfo::add_emergency_resume_proposal(), DispatchClass::Normal, Pays::No))]
+        pub fn add_emergency_resume_proposal(
+            origin: OriginFor<T>,
+            data: Vec<u8>,
+            operation: PausableOperation,
+        ) -> DispatchResult {
+            Self::do_add_emergency_resume_proposal(origin, data, operation)
+        }
+
+        #[pallet::call_index(21)]
+        #[pallet::weight((<T as pallet::Config>::WeightInfo::add_pause_guardian_proposal(), DispatchClass::Normal, Pays::No))]
+        pub fn add_pause_guardian_proposal(
+            origin: OriginFor<T>,
+            data: Vec<u8>,
+            guardian: Option<T::AccountId>,
+        ) -> DispatchResult {
+            Self::do_add_pause_guardian_proposal(origin, data, guardian)
+        }
+
+        #[pallet::call_index(22)]
+        #[pallet::weight((<T as pallet::Config>::WeightInfo::renew_whitelist(), DispatchClass::Normal, Pays::No))]
+        pub fn renew_whitelist(origin: OriginFor<T>, module_key: T::AccountId) -> DispatchResult {
+            Self::do_renew_whitelist(origin, module_key)
+        }
+
+        // --- Subnet DAO ---
+
+        #[pallet::call_index(23)]
+        #[pallet::weight((<T as pallet::Config>::WeightInfo::set_subnet_application_cost(), DispatchClass::Normal, Pays::No))]
+        pub fn set_subnet_application_cost(
+            origin: OriginFor<T>,
+            subnet_id: u16,
+            cost: Option<u64>,
+        ) -> DispatchResult {
+            Self::do_set_subnet_application_cost(origin, subnet_id, cost)
+        }
+
+        #[pallet::call_index(24)]
+        #[pallet::weight((<T as pallet::Config>::WeightInfo::add_subnet_application(), DispatchClass::Normal, Pays::No))]
+        pub fn add_subnet_application(
+            origin: OriginFor<T>,
+            subnet_id: u16,
+            module_key: T::AccountId,
+            data: Vec<u8>,
+        ) -> DispatchResult {
+            Self::do_add_subnet_application(origin, subnet_id, module_key, data)
+        }
+
+        #[pallet::call_index(25)]
+        #[pallet::weight((<T as pallet::Config>::WeightInfo::set_subnet_whitelisted(), DispatchClass::Normal, Pays::No))]
+        pub fn set_subnet_whitelisted(
+            origin: OriginFor<T>,
+            subnet_id: u16,
+            module_key: T::AccountId,
+            whitelisted: bool,
+        ) -> DispatchResult {
+            Self::do_set_subnet_whitelisted(origin, subnet_id, module_key, whitelisted)
+        }
+
+        #[pallet::call_index(26)]
+        #[pallet::weight((<T as pallet::Config>::WeightInfo::add_subnet_application_cost_proposal(), DispatchClass::Normal, Pays::No))]
+        pub fn add_subnet_application_cost_proposal(
+            origin: OriginFor<T>,
+            data: Vec<u8>,
+            subnet_id: u16,
+            cost: Option<u64>,
+        ) -> DispatchResult {
+            Self::do_add_subnet_application_cost_proposal(origin, data, subnet_id, cost)
+        }
+
+        #[pallet::call_index(27)]
+        #[pallet::weight((<T as pallet::Config>::WeightInfo::add_subnet_whitelist_proposal(), DispatchClass::Normal, Pays::No))]
+        pub fn add_subnet_whitelist_proposal(
+            origin: OriginFor<T>,
+            data: Vec<u8>,
+            subnet_id: u16,
+            module_key: T::AccountId,
+            whitelisted: bool,
+        ) -> DispatchResult {
+            Self::do_add_subnet_whitelist_proposal(origin, data, subnet_id, module_key, whitelisted)
+        }
+
+        // --- Enactment Vetoes ---
+
+        #[pallet::call_index(28)]
+        #[pallet::weight((<T as pallet::Config>::WeightInfo::veto_enactment(), DispatchClass::Operational, Pays::No))]
+        pub fn veto_enactment(origin: OriginFor<T>, proposal_id: ProposalId) -> DispatchResult {
+            Self::do_guardian_veto_enactment(origin, proposal_id)
+        }
+
+        #[pallet::call_index(29)]
+        #[pallet::weight((<T as pallet::Config>::WeightInfo::add_veto_enactment_proposal(), DispatchClass::Normal, Pays::No))]
+        pub fn add_veto_enactment_proposal(
+            origin: OriginFor<T>,
+            data: Vec<u8>,
+            proposal_id: ProposalId,
+        ) -> DispatchResult {
+            Self::do_add_veto_enactment_proposal(origin, data, proposal_id)
+        }
+
+        #[pallet::call_index(30)]
+        #[pallet::weight((<T as pallet::Config>::WeightInfo::add_subnet_founder_transfer_proposal(), DispatchClass::Normal, Pays::No))]
+        pub fn add_subnet_founder_transfer_proposal(
+            origin: OriginFor<T>,
+            data: Vec<u8>,
+            subnet_id: u16,
+            new_founder: T::AccountId,
+        ) -> DispatchResult {
+            Self::do_add_subnet_founder_transfer_proposal(origin, data, subnet_id, new_founder)
+        }
+
+        #[pallet::call_index(31)]
+        #[pallet::weight((<T as pallet::Config>::WeightInfo::add_override_veto_proposal(), DispatchClass::Normal, Pays::No))]
+        pub fn add_override_veto_proposal(
+            origin: OriginFor<T>,
+            data: Vec<u8>,
+            proposal_id: ProposalId,
+        ) -> DispatchResult {
+            Self::do_add_override_veto_proposal(origin, data, proposal_id)
+        }
     }
 
     // --- Events ---
@@ -389,6 +719,46 @@ pub mod pallet {
         WhitelistModuleRemoved(T::AccountId),
         /// A new application has been created.
         ApplicationCreated(u64),
+        /// A curator council member approved a curator action.
+        CuratorActionApproved(T::AccountId, CuratorAction<T::AccountId>),
+        /// A curator action reached the council threshold and was executed.
+        CuratorActionExecuted(CuratorAction<T::AccountId>),
+        /// The curator council members or threshold have changed.
+        CuratorCouncilUpdated,
+        /// A treasury grant has been scheduled.
+        TreasuryGrantCreated(TreasuryGrantId),
+        /// A tranche of a treasury grant has been paid to the account.
+        TreasuryGrantTranchePaid(TreasuryGrantId, T::AccountId, u64),
+        /// All tranches of a treasury grant have been paid.
+        TreasuryGrantCompleted(TreasuryGrantId),
+        /// A treasury grant has been cancelled, its remaining tranches won't be paid.
+        TreasuryGrantCancelled(TreasuryGrantId),
+        /// The pause guardian has been changed or removed.
+        PauseGuardianChanged(Option<T::AccountId>),
+        /// The subnet application cost changed, `None` meaning the subnet accepts every key.
+        SubnetApplicationCostChanged(SubnetId, Option<u64>),
+        /// A module key applied to register on the subnet.
+        SubnetApplicationCreated(SubnetId, T::AccountId),
+        /// The application of a module key to the subnet was refused.
+        SubnetApplicationRefused(SubnetId, T::AccountId),
+        /// A module key has been added to the subnet whitelist.
+        SubnetWhitelistModuleAdded(SubnetId, T::AccountId),
+        /// A module key has been removed from the subnet whitelist.
+        SubnetWhitelistModuleRemoved(SubnetId, T::AccountId),
+        /// A module account whitelist membership has been renewed until the given block.
+        WhitelistModuleRenewed(T::AccountId, u64),
+        /// A module account whitelist membership expired and was removed.
+        WhitelistModuleExpired(T::AccountId),
+        /// An accepted proposal will be enacted at the given block.
+        ProposalEnactmentScheduled(ProposalId, u64),
+        /// An accepted proposal has been enacted.
+        ProposalEnacted(ProposalId),
+        /// An accepted proposal failed to be enacted, its changes were discarded.
+        ProposalEnactmentFailed(ProposalId, DispatchError),
+        /// An accepted proposal was vetoed before being enacted.
+        ProposalEnactmentVetoed(ProposalId),
+        /// Governance overrode the guardian veto of a proposal, which has been enacted.
+        ProposalVetoOverridden(ProposalId),
     }
 
     // ---  Errors ---
@@ -450,7 +820,7 @@ pub mod pallet {
         InvalidApplication,
         /// The account doesn't have enough balance to submit an application.
         NotEnoughBalanceToApply,
-        /// The operation can only be performed by the curator.
+        /// The operation can only be performed by a curator council member.
         NotCurator,
         /// The application with the given ID was not found.
         ApplicationNotFound,
@@ -460,6 +830,54 @@ pub mod pallet {
         NotWhitelisted,
         /// Failed to convert the given value to a balance.
         CouldNotConvertToBalance,
+        /// The batch proposal doesn't contain any actions.
+        EmptyBatchProposal,
+        /// The batch proposal contains more actions than allowed.
+        BatchProposalTooLarge,
+        /// Batch proposals can't contain other batch proposals.
+        NestedBatchProposal,
+        /// The treasury grant must have at least one tranche, a non-zero interval and a
+        /// non-zero amount per tranche.
+        InvalidTreasuryGrant,
+        /// The treasury grant with the given ID was not found.
+        TreasuryGrantNotFound,
+        /// The curator council must have between 1 and 32 members, with a threshold between 1 and
+        /// the amount of members.
+        InvalidCuratorCouncil,
+        /// The council member already approved this curator action.
+        CuratorActionAlreadyApproved,
+        /// The subnet doesn't exist or has no consensus type.
+        SubnetConsensusNotFound,
+        /// The subnet already runs the proposed consensus.
+        SubnetConsensusUnchanged,
+        /// The treasury subnet consensus can't be changed.
+        CannotRemoveTreasurySubnet,
+        /// The rootnet consensus can't be changed.
+        CannotRemoveRootSubnet,
+        /// There can only be a single rootnet.
+        RootSubnetAlreadyExists,
+        /// The operation can only be performed by the pause guardian.
+        NotPauseGuardian,
+        /// The pause duration must be more than 0 and can't exceed the maximum allowed duration.
+        InvalidPauseDuration,
+        /// The operation isn't paused.
+        OperationNotPaused,
+        /// The subnet doesn't exist.
+        SubnetNotFound,
+        /// The operation can only be performed by the subnet founder.
+        NotSubnetFounder,
+        /// The subnet doesn't run its own application process.
+        SubnetApplicationsDisabled,
+        /// The proposal isn't waiting to be enacted.
+        EnactmentNotScheduled,
+        /// The pause guardian can't veto proposals changing the governance itself.
+        EnactmentNotVetoable,
+        /// The proposal wasn't vetoed by the pause guardian.
+        EnactmentNotVetoed,
+        /// The operation was paused by governance, only governance can resume it.
+        OperationPausedByGovernance,
+        /// There can only be a single treasury subnet.
+        TreasurySubnetAlreadyExists,
     }
 }
 
@@ -3,6 +3,27 @@ use frame_support::{
     pallet_prelude::ValueQuery,
     traits::{ConstU32, Get, StorageVersion},
 };
+use parity_scale_codec::Decode;
+
+// The modules are named after the mainnet version they migrate to. Testnet was already two
+// versions ahead when the curator council landed, so it migrates between these versions instead:
+//
+// | module | mainnet | testnet |
+// |--------|---------|---------|
+// | v2     | 1 -> 2  | -       |
+// | v3     | 2 -> 3  | 4 -> 5  |
+// | v4     | 3 -> 4  | 5 -> 6  |
+// | v5     | 4 -> 5  | 6 -> 7  |
+// | v6     | 5 -> 6  | 7 -> 8  |
+
+/// Logs a stored proposal that doesn't decode in the layout its migration expects. The
+/// proposer is still decoded when possible, so the proposal cost can be refunded by hand.
+fn log_undecodable_proposal<T: Config>(id: ProposalId, raw: &[u8]) {
+    match <(ProposalId, T::AccountId)>::decode(&mut &raw[..]) {
+        Ok((_, proposer)) => log::error!("dropping undecodable proposal {id} of {proposer:?}"),
+        Err(_) => log::error!("dropping undecodable proposal {id}"),
+    }
+}
 
 pub mod v2 {
     use dao::CuratorApplication;
@@ -67,7 +88,7 @@ pub mod v2 {
             _ = old_storage::LegitWhitelist::<T>::clear(u32::MAX, None);
 
             for (account, _) in old_whitelist {
-                LegitWhitelist::<T>::insert(account, ());
+                v5::old_storage::LegitWhitelist::<T>::insert(account, ());
             }
 
             log::info!("Migrated to v2");
@@ -76,3 +97,911 @@ pub mod v2 {
         }
     }
 }
+
+pub mod v3 {
+    use frame_support::{traits::OnRuntimeUpgrade, weights::Weight};
+    use sp_std::collections::btree_set::BTreeSet;
+
+    use super::*;
+
+    #[cfg(feature = "testnet")]
+    const PREVIOUS_VERSION: u16 = 4;
+    #[cfg(not(feature = "testnet"))]
+    const PREVIOUS_VERSION: u16 = 2;
+
+    pub mod old_storage {
+        use super::*;
+        use frame_support::storage_alias;
+        use pallet_chain::AccountIdOf;
+
+        #[storage_alias]
+        pub type Curator<T: Config> = StorageValue<Pallet<T>, AccountIdOf<T>>;
+    }
+
+    /// Moves the single curator key into the curator council, with a threshold of one. Pending
+    /// global parameter proposals still carry the curator, v4 translates them.
+    pub struct MigrateToV3<T>(sp_std::marker::PhantomData<T>);
+
+    impl<T: Config> OnRuntimeUpgrade for MigrateToV3<T> {
+        fn on_runtime_upgrade() -> frame_support::weights::Weight {
+            let on_chain_version = StorageVersion::get::<Pallet<T>>();
+            if on_chain_version != PREVIOUS_VERSION {
+                log::info!("Storage v3 already updated");
+                return Weight::zero();
+            }
+
+            StorageVersion::new(PREVIOUS_VERSION.saturating_add(1)).put::<Pallet<T>>();
+
+            if let Some(curator) = old_storage::Curator::<T>::take() {
+                if let Err(err) = Pallet::<T>::set_curator_council(BTreeSet::from([curator]), 1) {
+                    log::error!("failed to migrate curator to the council: {err:?}");
+                }
+            }
+
+            log::info!("Migrated to v3");
+
+            T::DbWeight::get().reads_writes(2, 3)
+        }
+    }
+}
+
+pub mod v4 {
+    use frame_support::{storage::unhashed, traits::OnRuntimeUpgrade, weights::Weight};
+    use parity_scale_codec::DecodeAll;
+
+    use super::*;
+    use crate::proposal::{ArchivedProposal, ProposalStatus, UnrewardedProposal};
+
+    #[cfg(feature = "testnet")]
+    const PREVIOUS_VERSION: u16 = 5;
+    #[cfg(not(feature = "testnet"))]
+    const PREVIOUS_VERSION: u16 = 3;
+
+    pub mod old_storage {
+        use super::*;
+        use frame_support::{
+            pallet_prelude::TypeInfo, storage_alias, BoundedBTreeMap, BoundedBTreeSet, Identity,
+        };
+        use pallet_chain::{params::metadata::legacy_metadata, AccountIdOf};
+        use parity_scale_codec::{Decode, Encode};
+        use sp_runtime::{BoundedVec, Percent};
+
+        #[derive(Encode, Decode, TypeInfo)]
+        pub enum ProposalStatus<T: Config> {
+            Open {
+                votes_for: BoundedBTreeSet<AccountIdOf<T>, ConstU32<{ u32::MAX }>>,
+                votes_against: BoundedBTreeSet<AccountIdOf<T>, ConstU32<{ u32::MAX }>>,
+                stake_for: u64,
+                stake_against: u64,
+            },
+            Accepted {
+                block: u64,
+                stake_for: u64,
+                stake_against: u64,
+            },
+            Refused {
+                block: u64,
+                stake_for: u64,
+                stake_against: u64,
+            },
+            Expired,
+        }
+
+        /// The module burn configuration as it was stored before the refund curve and the burn
+        /// destination.
+        #[derive(Encode, Decode)]
+        pub struct GeneralBurnConfiguration {
+            pub min_burn: u64,
+            pub max_burn: u64,
+            pub adjustment_alpha: u64,
+            pub target_registrations_interval: u16,
+            pub target_registrations_per_interval: u16,
+            pub max_registrations_per_interval: u16,
+        }
+
+        #[derive(Encode, Decode)]
+        pub struct GlobalParams<T: Config> {
+            pub max_name_length: u16,
+            pub min_name_length: u16,
+            pub max_allowed_subnets: u16,
+            pub max_allowed_modules: u16,
+            pub max_registrations_per_block: u16,
+            pub max_allowed_weights: u16,
+            pub floor_stake_delegation_fee: Percent,
+            pub floor_validator_weight_fee: Percent,
+            pub floor_founder_share: u8,
+            pub min_weight_stake: u64,
+            pub curator: AccountIdOf<T>,
+            pub general_subnet_application_cost: u64,
+            pub subnet_immunity_period: u64,
+            pub governance_config: v6::old_storage::GovernanceConfiguration,
+            pub kappa: u16,
+            pub rho: u16,
+        }
+
+        #[derive(Encode, Decode)]
+        pub struct SubnetParams<T: Config> {
+            pub founder: AccountIdOf<T>,
+            pub founder_share: u16,
+            pub immunity_period: u16,
+            pub incentive_ratio: u16,
+            pub max_allowed_uids: u16,
+            pub max_allowed_weights: u16,
+            pub min_allowed_weights: u16,
+            pub max_weight_age: u64,
+            pub name: BoundedVec<u8, ConstU32<256>>,
+            pub metadata: Option<BoundedVec<u8, ConstU32<120>>>,
+            pub tempo: u16,
+            pub maximum_set_weight_calls_per_epoch: Option<u16>,
+            pub bonds_ma: u64,
+            pub module_burn_config: GeneralBurnConfiguration,
+            pub min_validator_stake: u64,
+            pub max_allowed_validators: Option<u16>,
+            pub governance_config: v6::old_storage::GovernanceConfiguration,
+        }
+
+        #[derive(Encode, Decode)]
+        pub enum ProposalData<T: Config> {
+            GlobalCustom,
+            GlobalParams(GlobalParams<T>),
+            SubnetCustom {
+                subnet_id: SubnetId,
+            },
+            SubnetParams {
+                subnet_id: SubnetId,
+                params: SubnetParams<T>,
+            },
+            TransferDaoTreasury {
+                account: AccountIdOf<T>,
+                amount: u64,
+            },
+        }
+
+        impl<T: Config> ProposalData<T> {
+            pub fn subnet_id(&self) -> Option<SubnetId> {
+                match self {
+                    Self::SubnetCustom { subnet_id } | Self::SubnetParams { subnet_id, .. } => {
+                        Some(*subnet_id)
+                    }
+                    _ => None,
+                }
+            }
+        }
+
+        impl From<GeneralBurnConfiguration> for v6::old_storage::GeneralBurnConfiguration {
+            fn from(old: GeneralBurnConfiguration) -> Self {
+                Self {
+                    min_burn: old.min_burn,
+                    max_burn: old.max_burn,
+                    adjustment_alpha: old.adjustment_alpha,
+                    target_registrations_interval: old.target_registrations_interval,
+                    target_registrations_per_interval: old.target_registrations_per_interval,
+                    max_registrations_per_interval: old.max_registrations_per_interval,
+                    refund_curve: None,
+                    destination: Default::default(),
+                }
+            }
+        }
+
+        impl<T: Config> From<GlobalParams<T>> for v6::old_storage::GlobalParams<T> {
+            fn from(old: GlobalParams<T>) -> Self {
+                let mut curator_council = BoundedBTreeSet::new();
+                let _ = curator_council.try_insert(old.curator);
+
+                Self {
+                    max_name_length: old.max_name_length,
+                    min_name_length: old.min_name_length,
+                    max_allowed_subnets: old.max_allowed_subnets,
+                    max_allowed_modules: old.max_allowed_modules,
+                    max_registrations_per_block: old.max_registrations_per_block,
+                    max_allowed_weights: old.max_allowed_weights,
+                    floor_stake_delegation_fee: old.floor_stake_delegation_fee,
+                    floor_validator_weight_fee: old.floor_validator_weight_fee,
+                    floor_founder_share: old.floor_founder_share,
+                    min_weight_stake: old.min_weight_stake,
+                    curator_council,
+                    curator_threshold: 1,
+                    general_subnet_application_cost: old.general_subnet_application_cost,
+                    subnet_immunity_period: old.subnet_immunity_period,
+                    governance_config: old.governance_config,
+                    kappa: old.kappa,
+                    rho: old.rho,
+                }
+            }
+        }
+
+        impl<T: Config> From<SubnetParams<T>> for v6::old_storage::SubnetParams<T> {
+            fn from(old: SubnetParams<T>) -> Self {
+                Self {
+                    founder: old.founder,
+                    founder_share: old.founder_share,
+                    immunity_period: old.immunity_period,
+                    incentive_ratio: old.incentive_ratio,
+                    max_allowed_uids: old.max_allowed_uids,
+                    max_allowed_weights: old.max_allowed_weights,
+                    min_allowed_weights: old.min_allowed_weights,
+                    max_weight_age: old.max_weight_age,
+                    name: old.name,
+                    metadata: old
+                        .metadata
+                        .map(|metadata| {
+                            v6::old_storage::convert_metadata(legacy_metadata(&metadata))
+                        })
+                        .unwrap_or_default(),
+                    tempo: old.tempo,
+                    maximum_set_weight_calls_per_epoch: old.maximum_set_weight_calls_per_epoch,
+                    bonds_ma: old.bonds_ma,
+                    module_burn_config: old.module_burn_config.into(),
+                    min_validator_stake: old.min_validator_stake,
+                    max_allowed_validators: old.max_allowed_validators,
+                    registration_mode: Default::default(),
+                    pruning_strategy: Default::default(),
+                    activity_source: Default::default(),
+                    governance_config: old.governance_config,
+                }
+            }
+        }
+
+        impl<T: Config> From<ProposalData<T>> for v6::old_storage::ProposalData<T> {
+            fn from(old: ProposalData<T>) -> Self {
+                match old {
+                    ProposalData::GlobalCustom => Self::GlobalCustom,
+                    ProposalData::GlobalParams(params) => Self::GlobalParams(params.into()),
+                    ProposalData::SubnetCustom { subnet_id } => Self::SubnetCustom { subnet_id },
+                    ProposalData::SubnetParams { subnet_id, params } => Self::SubnetParams {
+                        subnet_id,
+                        params: params.into(),
+                    },
+                    ProposalData::TransferDaoTreasury { account, amount } => {
+                        Self::TransferDaoTreasury { account, amount }
+                    }
+                }
+            }
+        }
+
+        #[derive(Encode, Decode)]
+        pub struct Proposal<T: Config> {
+            pub id: ProposalId,
+            pub proposer: AccountIdOf<T>,
+            pub expiration_block: u64,
+            pub data: ProposalData<T>,
+            pub status: ProposalStatus<T>,
+            pub metadata: BoundedVec<u8, ConstU32<256>>,
+            pub proposal_cost: u64,
+            pub creation_block: u64,
+        }
+
+        #[derive(Encode, Decode, TypeInfo)]
+        pub struct UnrewardedProposal<T: Config> {
+            pub subnet_id: Option<u16>,
+            pub block: u64,
+            pub votes_for: BoundedBTreeMap<AccountIdOf<T>, u64, ConstU32<{ u32::MAX }>>,
+            pub votes_against: BoundedBTreeMap<AccountIdOf<T>, u64, ConstU32<{ u32::MAX }>>,
+        }
+
+        #[storage_alias]
+        pub type Proposals<T: Config> = StorageMap<Pallet<T>, Identity, ProposalId, Proposal<T>>;
+
+        #[storage_alias]
+        pub type UnrewardedProposals<T: Config> =
+            StorageMap<Pallet<T>, Identity, ProposalId, UnrewardedProposal<T>>;
+    }
+
+    /// Moves votes out of the proposals into their own maps, archives closed proposals and
+    /// starts the proposal ID counter.
+    pub struct MigrateToV4<T>(sp_std::marker::PhantomData<T>);
+
+    impl<T: Config> OnRuntimeUpgrade for MigrateToV4<T> {
+        fn on_runtime_upgrade() -> frame_support::weights::Weight {
+            let on_chain_version = StorageVersion::get::<Pallet<T>>();
+            if on_chain_version != PREVIOUS_VERSION {
+                log::info!("Storage v4 already updated");
+                return Weight::zero();
+            }
+
+            StorageVersion::new(PREVIOUS_VERSION.saturating_add(1)).put::<Pallet<T>>();
+
+            let ids: Vec<_> = old_storage::Proposals::<T>::iter_keys().collect();
+            let mut writes = ids.len() as u64;
+            let mut next_id: ProposalId = 0;
+            let mut dropped = 0u64;
+
+            for id in ids {
+                next_id = next_id.max(id.saturating_add(1));
+
+                let key = old_storage::Proposals::<T>::hashed_key_for(id);
+                let raw = unhashed::get_raw(&key).unwrap_or_default();
+                unhashed::kill(&key);
+
+                let Ok(old) = old_storage::Proposal::<T>::decode_all(&mut &raw[..]) else {
+                    log_undecodable_proposal::<T>(id, &raw);
+                    dropped = dropped.saturating_add(1);
+                    continue;
+                };
+
+                let (status, votes) = match old.status {
+                    old_storage::ProposalStatus::Open {
+                        votes_for,
+                        votes_against,
+                        stake_for,
+                        stake_against,
+                    } => (
+                        ProposalStatus::Open {
+                            stake_for,
+                            stake_against,
+                        },
+                        Some((votes_for, votes_against)),
+                    ),
+                    old_storage::ProposalStatus::Accepted {
+                        block,
+                        stake_for,
+                        stake_against,
+                    } => (
+                        ProposalStatus::Accepted {
+                            block,
+                            stake_for,
+                            stake_against,
+                        },
+                        None,
+                    ),
+                    old_storage::ProposalStatus::Refused {
+                        block,
+                        stake_for,
+                        stake_against,
+                    } => (
+                        ProposalStatus::Refused {
+                            block,
+                            stake_for,
+                            stake_against,
+                        },
+                        None,
+                    ),
+                    old_storage::ProposalStatus::Expired => (ProposalStatus::Expired, None),
+                };
+
+                let Some((votes_for, votes_against)) = votes else {
+                    ProposalArchive::<T>::insert(
+                        id,
+                        ArchivedProposal {
+                            proposer: old.proposer,
+                            subnet_id: old.data.subnet_id(),
+                            metadata: old.metadata,
+                            creation_block: old.creation_block,
+                            status,
+                        },
+                    );
+                    continue;
+                };
+
+                for voter in votes_for {
+                    ProposalVotes::<T>::insert(id, voter, true);
+                    writes = writes.saturating_add(1);
+                }
+                for voter in votes_against {
+                    ProposalVotes::<T>::insert(id, voter, false);
+                    writes = writes.saturating_add(1);
+                }
+
+                // Written in the layout v6 expects, which then adds the enactment delay
+                v6::old_storage::Proposals::<T>::insert(
+                    id,
+                    v6::old_storage::Proposal {
+                        id,
+                        proposer: old.proposer,
+                        expiration_block: old.expiration_block,
+                        data: old.data.into(),
+                        status,
+                        metadata: old.metadata,
+                        proposal_cost: old.proposal_cost,
+                        creation_block: old.creation_block,
+                    },
+                );
+            }
+
+            log::info!("Dropped {dropped} undecodable proposals");
+
+            NextProposalId::<T>::set(next_id);
+
+            let old_unrewarded: Vec<_> = old_storage::UnrewardedProposals::<T>::drain().collect();
+            writes = writes.saturating_add(old_unrewarded.len() as u64);
+
+            for (id, old) in old_unrewarded {
+                for (voter, stake) in old.votes_for.into_iter().chain(old.votes_against) {
+                    UnrewardedProposalVotes::<T>::insert(id, voter, stake);
+                    writes = writes.saturating_add(1);
+                }
+
+                UnrewardedProposals::<T>::insert(
+                    id,
+                    UnrewardedProposal {
+                        subnet_id: old.subnet_id,
+                        block: old.block,
+                    },
+                );
+            }
+
+            log::info!("Migrated to v4");
+
+            T::DbWeight::get().reads_writes(writes, writes.saturating_add(2))
+        }
+    }
+}
+
+pub mod v5 {
+    use frame_support::{traits::OnRuntimeUpgrade, weights::Weight};
+    use pallet_chain::Pallet as PalletChain;
+
+    use super::*;
+
+    #[cfg(feature = "testnet")]
+    const PREVIOUS_VERSION: u16 = 6;
+    #[cfg(not(feature = "testnet"))]
+    const PREVIOUS_VERSION: u16 = 4;
+
+    pub mod old_storage {
+        use super::*;
+        use frame_support::{storage_alias, Identity};
+        use pallet_chain::AccountIdOf;
+
+        #[storage_alias]
+        pub type LegitWhitelist<T: Config> =
+            StorageMap<Pallet<T>, Identity, AccountIdOf<T>, (), ValueQuery>;
+    }
+
+    /// Gives every whitelisted module a full membership period, starting at the upgrade.
+    pub struct MigrateToV5<T>(sp_std::marker::PhantomData<T>);
+
+    impl<T: Config> OnRuntimeUpgrade for MigrateToV5<T> {
+        fn on_runtime_upgrade() -> frame_support::weights::Weight {
+            let on_chain_version = StorageVersion::get::<Pallet<T>>();
+            if on_chain_version != PREVIOUS_VERSION {
+                log::info!("Storage v5 already updated");
+                return Weight::zero();
+            }
+
+            StorageVersion::new(PREVIOUS_VERSION.saturating_add(1)).put::<Pallet<T>>();
+
+            let expiry = PalletChain::<T>::get_current_block_number()
+                .saturating_add(WhitelistDuration::<T>::get());
+
+            let mut migrated = 0u64;
+            LegitWhitelist::<T>::translate(|_key, _old_value: ()| {
+                migrated = migrated.saturating_add(1);
+                Some(expiry)
+            });
+
+            log::info!("Migrated {migrated} whitelisted modules to v5");
+
+            T::DbWeight::get().reads_writes(migrated.saturating_add(2), migrated.saturating_add(1))
+        }
+    }
+}
+
+pub mod v6 {
+    use frame_support::{storage::unhashed, traits::OnRuntimeUpgrade, weights::Weight};
+    use parity_scale_codec::DecodeAll;
+    use sp_runtime::Percent;
+
+    use super::*;
+
+    #[cfg(feature = "testnet")]
+    const PREVIOUS_VERSION: u16 = 7;
+    #[cfg(not(feature = "testnet"))]
+    const PREVIOUS_VERSION: u16 = 5;
+
+    pub mod old_storage {
+        use super::*;
+        use crate::proposal::ProposalStatus;
+        use frame_support::{storage_alias, BoundedBTreeMap, BoundedBTreeSet, Identity};
+        use pallet_chain::AccountIdOf;
+        use pallet_governance_api::MAX_CURATOR_COUNCIL_MEMBERS;
+        use parity_scale_codec::{Decode, Encode};
+        use sp_runtime::BoundedVec;
+        use sp_std::collections::btree_map::BTreeMap;
+
+        // The chain types below are frozen copies of the layouts stored proposals were written
+        // in, so later changes to the chain pallet don't break the decoding.
+
+        #[derive(Decode, Encode, PartialEq, Eq, PartialOrd, Ord)]
+        pub enum MetadataKey {
+            IpfsCid,
+            Website,
+            ApiSchema,
+            Version,
+            Custom(BoundedVec<u8, ConstU32<32>>),
+        }
+
+        impl From<MetadataKey> for pallet_chain::MetadataKey {
+            fn from(old: MetadataKey) -> Self {
+                match old {
+                    MetadataKey::IpfsCid => Self::IpfsCid,
+                    MetadataKey::Website => Self::Website,
+                    MetadataKey::ApiSchema => Self::ApiSchema,
+                    MetadataKey::Version => Self::Version,
+                    MetadataKey::Custom(key) => Self::Custom(key),
+                }
+            }
+        }
+
+        impl From<pallet_chain::MetadataKey> for MetadataKey {
+            fn from(key: pallet_chain::MetadataKey) -> Self {
+                match key {
+                    pallet_chain::MetadataKey::IpfsCid => Self::IpfsCid,
+                    pallet_chain::MetadataKey::Website => Self::Website,
+                    pallet_chain::MetadataKey::ApiSchema => Self::ApiSchema,
+                    pallet_chain::MetadataKey::Version => Self::Version,
+                    pallet_chain::MetadataKey::Custom(key) => Self::Custom(key),
+                }
+            }
+        }
+
+        pub type MetadataMap =
+            BoundedBTreeMap<MetadataKey, BoundedVec<u8, ConstU32<256>>, ConstU32<16>>;
+
+        /// Converts the keys of a metadata map between the frozen and the live layouts. Both
+        /// hold the same amount of entries, so nothing is lost.
+        pub fn convert_metadata<K: Ord + Into<V>, V: Ord>(
+            metadata: BoundedBTreeMap<K, BoundedVec<u8, ConstU32<256>>, ConstU32<16>>,
+        ) -> BoundedBTreeMap<V, BoundedVec<u8, ConstU32<256>>, ConstU32<16>> {
+            let entries: BTreeMap<_, _> =
+                metadata.into_iter().map(|(key, value)| (key.into(), value)).collect();
+            BoundedBTreeMap::try_from(entries).unwrap_or_default()
+        }
+
+        #[derive(Decode, Encode)]
+        pub struct BurnRefundCurve {
+            pub initial_refund: Percent,
+            pub decay_period: u64,
+        }
+
+        #[derive(Decode, Encode, Default)]
+        pub struct BurnDestination {
+            pub treasury: Percent,
+            pub founder: Percent,
+        }
+
+        #[derive(Decode, Encode)]
+        pub struct GeneralBurnConfiguration {
+            pub min_burn: u64,
+            pub max_burn: u64,
+            pub adjustment_alpha: u64,
+            pub target_registrations_interval: u16,
+            pub target_registrations_per_interval: u16,
+            pub max_registrations_per_interval: u16,
+            pub refund_curve: Option<BurnRefundCurve>,
+            pub destination: BurnDestination,
+        }
+
+        impl<T: Config> From<GeneralBurnConfiguration> for pallet_chain::GeneralBurnConfiguration<T> {
+            fn from(old: GeneralBurnConfiguration) -> Self {
+                Self {
+                    min_burn: old.min_burn,
+                    max_burn: old.max_burn,
+                    adjustment_alpha: old.adjustment_alpha,
+                    target_registrations_interval: old.target_registrations_interval,
+                    target_registrations_per_interval: old.target_registrations_per_interval,
+                    max_registrations_per_interval: old.max_registrations_per_interval,
+                    refund_curve: old.refund_curve.map(|curve| pallet_chain::BurnRefundCurve {
+                        initial_refund: curve.initial_refund,
+                        decay_period: curve.decay_period,
+                    }),
+                    destination: pallet_chain::BurnDestination {
+                        treasury: old.destination.treasury,
+                        founder: old.destination.founder,
+                    },
+                    _pd: sp_std::marker::PhantomData,
+                }
+            }
+        }
+
+        #[derive(Decode, Encode, Default)]
+        pub enum RegistrationMode {
+            #[default]
+            Open,
+            Allowlist,
+            FounderApproval,
+        }
+
+        impl From<RegistrationMode> for pallet_chain::RegistrationMode {
+            fn from(old: RegistrationMode) -> Self {
+                match old {
+                    RegistrationMode::Open => Self::Open,
+                    RegistrationMode::Allowlist => Self::Allowlist,
+                    RegistrationMode::FounderApproval => Self::FounderApproval,
+                }
+            }
+        }
+
+        #[derive(Decode, Encode, Default)]
+        pub enum PruningStrategy {
+            #[default]
+            EmissionScore,
+            LowestStake,
+            OldestFirst,
+            NoEviction,
+        }
+
+        impl From<PruningStrategy> for pallet_chain::PruningStrategy {
+            fn from(old: PruningStrategy) -> Self {
+                match old {
+                    PruningStrategy::EmissionScore => Self::EmissionScore,
+                    PruningStrategy::LowestStake => Self::LowestStake,
+                    PruningStrategy::OldestFirst => Self::OldestFirst,
+                    PruningStrategy::NoEviction => Self::NoEviction,
+                }
+            }
+        }
+
+        #[derive(Decode, Encode, Default)]
+        pub enum ActivitySource {
+            #[default]
+            Weights,
+            Heartbeats,
+        }
+
+        impl From<ActivitySource> for pallet_chain::ActivitySource {
+            fn from(old: ActivitySource) -> Self {
+                match old {
+                    ActivitySource::Weights => Self::Weights,
+                    ActivitySource::Heartbeats => Self::Heartbeats,
+                }
+            }
+        }
+
+        #[derive(Decode, Encode)]
+        pub struct GovernanceConfiguration {
+            pub proposal_cost: u64,
+            pub proposal_expiration: u32,
+            pub vote_mode: VoteMode,
+            pub proposal_reward_treasury_allocation: Percent,
+            pub max_proposal_reward_treasury_allocation: u64,
+            pub proposal_reward_interval: u64,
+        }
+
+        impl From<GovernanceConfiguration> for crate::GovernanceConfiguration {
+            fn from(old: GovernanceConfiguration) -> Self {
+                Self {
+                    proposal_cost: old.proposal_cost,
+                    proposal_expiration: old.proposal_expiration,
+                    vote_mode: old.vote_mode,
+                    proposal_reward_treasury_allocation: old.proposal_reward_treasury_allocation,
+                    max_proposal_reward_treasury_allocation: old
+                        .max_proposal_reward_treasury_allocation,
+                    proposal_reward_interval: old.proposal_reward_interval,
+                    ..Default::default()
+                }
+            }
+        }
+
+        #[derive(Decode, Encode)]
+        pub struct GlobalParams<T: Config> {
+            pub max_name_length: u16,
+            pub min_name_length: u16,
+            pub max_allowed_subnets: u16,
+            pub max_allowed_modules: u16,
+            pub max_registrations_per_block: u16,
+            pub max_allowed_weights: u16,
+            pub floor_stake_delegation_fee: Percent,
+            pub floor_validator_weight_fee: Percent,
+            pub floor_founder_share: u8,
+            pub min_weight_stake: u64,
+            pub curator_council:
+                BoundedBTreeSet<AccountIdOf<T>, ConstU32<MAX_CURATOR_COUNCIL_MEMBERS>>,
+            pub curator_threshold: u32,
+            pub general_subnet_application_cost: u64,
+            pub subnet_immunity_period: u64,
+            pub governance_config: GovernanceConfiguration,
+            pub kappa: u16,
+            pub rho: u16,
+        }
+
+        impl<T: Config> From<GlobalParams<T>> for pallet_chain::GlobalParams<T> {
+            fn from(old: GlobalParams<T>) -> Self {
+                Self {
+                    max_name_length: old.max_name_length,
+                    min_name_length: old.min_name_length,
+                    max_allowed_subnets: old.max_allowed_subnets,
+                    max_allowed_modules: old.max_allowed_modules,
+                    max_registrations_per_block: old.max_registrations_per_block,
+                    max_allowed_weights: old.max_allowed_weights,
+                    floor_stake_delegation_fee: old.floor_stake_delegation_fee,
+                    floor_validator_weight_fee: old.floor_validator_weight_fee,
+                    floor_founder_share: old.floor_founder_share,
+                    min_weight_stake: old.min_weight_stake,
+                    curator_council: old.curator_council,
+                    curator_threshold: old.curator_threshold,
+                    general_subnet_application_cost: old.general_subnet_application_cost,
+                    subnet_immunity_period: old.subnet_immunity_period,
+                    governance_config: old.governance_config.into(),
+                    kappa: old.kappa,
+                    rho: old.rho,
+                }
+            }
+        }
+
+        #[derive(Decode, Encode)]
+        pub struct SubnetParams<T: Config> {
+            pub founder: AccountIdOf<T>,
+            pub founder_share: u16,
+            pub immunity_period: u16,
+            pub incentive_ratio: u16,
+            pub max_allowed_uids: u16,
+            pub max_allowed_weights: u16,
+            pub min_allowed_weights: u16,
+            pub max_weight_age: u64,
+            pub name: BoundedVec<u8, ConstU32<256>>,
+            pub metadata: MetadataMap,
+            pub tempo: u16,
+            pub maximum_set_weight_calls_per_epoch: Option<u16>,
+            pub bonds_ma: u64,
+            pub module_burn_config: GeneralBurnConfiguration,
+            pub min_validator_stake: u64,
+            pub max_allowed_validators: Option<u16>,
+            pub registration_mode: RegistrationMode,
+            pub pruning_strategy: PruningStrategy,
+            pub activity_source: ActivitySource,
+            pub governance_config: GovernanceConfiguration,
+        }
+
+        impl<T: Config> From<SubnetParams<T>> for pallet_chain::SubnetParams<T> {
+            fn from(old: SubnetParams<T>) -> Self {
+                Self {
+                    founder: old.founder,
+                    founder_share: old.founder_share,
+                    immunity_period: old.immunity_period,
+                    incentive_ratio: old.incentive_ratio,
+                    max_allowed_uids: old.max_allowed_uids,
+                    max_allowed_weights: old.max_allowed_weights,
+                    min_allowed_weights: old.min_allowed_weights,
+                    max_weight_age: old.max_weight_age,
+                    name: old.name,
+                    metadata: convert_metadata(old.metadata),
+                    tempo: old.tempo,
+                    maximum_set_weight_calls_per_epoch: old.maximum_set_weight_calls_per_epoch,
+                    bonds_ma: old.bonds_ma,
+                    module_burn_config: old.module_burn_config.into(),
+                    min_validator_stake: old.min_validator_stake,
+                    max_allowed_validators: old.max_allowed_validators,
+                    registration_mode: old.registration_mode.into(),
+                    pruning_strategy: old.pruning_strategy.into(),
+                    activity_source: old.activity_source.into(),
+                    governance_config: old.governance_config.into(),
+                }
+            }
+        }
+
+        /// Only the proposal kinds that existed before the upgrade can be stored in this layout.
+        #[derive(Decode, Encode)]
+        pub enum ProposalData<T: Config> {
+            GlobalCustom,
+            GlobalParams(GlobalParams<T>),
+            SubnetCustom {
+                subnet_id: SubnetId,
+            },
+            SubnetParams {
+                subnet_id: SubnetId,
+                params: SubnetParams<T>,
+            },
+            TransferDaoTreasury {
+                account: AccountIdOf<T>,
+                amount: u64,
+            },
+        }
+
+        impl<T: Config> From<ProposalData<T>> for crate::ProposalData<T> {
+            fn from(old: ProposalData<T>) -> Self {
+                match old {
+                    ProposalData::GlobalCustom => Self::GlobalCustom,
+                    ProposalData::GlobalParams(params) => Self::GlobalParams(params.into()),
+                    ProposalData::SubnetCustom { subnet_id } => Self::SubnetCustom { subnet_id },
+                    ProposalData::SubnetParams { subnet_id, params } => Self::SubnetParams {
+                        subnet_id,
+                        params: params.into(),
+                    },
+                    ProposalData::TransferDaoTreasury { account, amount } => {
+                        Self::TransferDaoTreasury { account, amount }
+                    }
+                }
+            }
+        }
+
+        #[derive(Decode, Encode)]
+        pub struct Proposal<T: Config> {
+            pub id: ProposalId,
+            pub proposer: AccountIdOf<T>,
+            pub expiration_block: u64,
+            pub data: ProposalData<T>,
+            pub status: ProposalStatus,
+            pub metadata: BoundedVec<u8, ConstU32<256>>,
+            pub proposal_cost: u64,
+            pub creation_block: u64,
+        }
+
+        #[storage_alias]
+        pub type Proposals<T: Config> = StorageMap<Pallet<T>, Identity, ProposalId, Proposal<T>>;
+
+        #[storage_alias]
+        pub type ScheduledEnactments<T: Config> =
+            StorageDoubleMap<Pallet<T>, Identity, u64, Identity, ProposalId, ProposalData<T>>;
+    }
+
+    /// Adds the default enactment delay to the global and subnet governance configurations,
+    /// including the ones carried by stored proposals.
+    pub struct MigrateToV6<T>(sp_std::marker::PhantomData<T>);
+
+    impl<T: Config> OnRuntimeUpgrade for MigrateToV6<T> {
+        fn on_runtime_upgrade() -> frame_support::weights::Weight {
+            let on_chain_version = StorageVersion::get::<Pallet<T>>();
+            if on_chain_version != PREVIOUS_VERSION {
+                log::info!("Storage v6 already updated");
+                return Weight::zero();
+            }
+
+            StorageVersion::new(PREVIOUS_VERSION.saturating_add(1)).put::<Pallet<T>>();
+
+            if let Err(err) = GlobalGovernanceConfig::<T>::translate(
+                |old: Option<old_storage::GovernanceConfiguration>| old.map(Into::into),
+            ) {
+                log::error!("failed to migrate the global governance configuration: {err:?}");
+            }
+
+            let mut migrated = 0u64;
+            SubnetGovernanceConfig::<T>::translate(
+                |_subnet_id, old: old_storage::GovernanceConfiguration| {
+                    migrated = migrated.saturating_add(1);
+                    Some(old.into())
+                },
+            );
+
+            log::info!("Migrated {migrated} subnet governance configurations to v6");
+
+            let (mut proposals, mut dropped) = (0u64, 0u64);
+            let ids: Vec<_> = old_storage::Proposals::<T>::iter_keys().collect();
+            for id in ids {
+                let key = old_storage::Proposals::<T>::hashed_key_for(id);
+                let raw = unhashed::get_raw(&key).unwrap_or_default();
+
+                let Ok(old) = old_storage::Proposal::<T>::decode_all(&mut &raw[..]) else {
+                    log_undecodable_proposal::<T>(id, &raw);
+                    unhashed::kill(&key);
+                    dropped = dropped.saturating_add(1);
+                    continue;
+                };
+
+                Proposals::<T>::insert(
+                    id,
+                    Proposal {
+                        id: old.id,
+                        proposer: old.proposer,
+                        expiration_block: old.expiration_block,
+                        data: old.data.into(),
+                        status: old.status,
+                        metadata: old.metadata,
+                        proposal_cost: old.proposal_cost,
+                        creation_block: old.creation_block,
+                    },
+                );
+                proposals = proposals.saturating_add(1);
+            }
+
+            let mut scheduled = 0u64;
+            ScheduledEnactments::<T>::translate(
+                |_block, _id, old: old_storage::ProposalData<T>| {
+                    scheduled = scheduled.saturating_add(1);
+                    Some(old.into())
+                },
+            );
+
+            log::info!(
+                "Migrated {proposals} proposals and {scheduled} scheduled enactments to v6, \
+                 dropped {dropped} undecodable proposals"
+            );
+
+            let migrated = migrated
+                .saturating_add(proposals)
+                .saturating_add(dropped)
+                .saturating_add(scheduled);
+            T::DbWeight::get().reads_writes(migrated.saturating_add(2), migrated.saturating_add(2))
+        }
+    }
+}
@@ -1,17 +1,19 @@
-use crate::*;
+use crate::{emergency::MAX_EMERGENCY_PAUSE_DURATION, *};
 use frame_support::{
     dispatch::DispatchResult,
     ensure,
     sp_runtime::{DispatchError, SaturatedConversion},
     storage::with_storage_layer,
     traits::ConstU32,
-    BoundedBTreeMap, BoundedBTreeSet, BoundedVec, DebugNoBound,
+    BoundedBTreeMap, BoundedVec, DebugNoBound,
 };
 use frame_system::ensure_signed;
 use pallet_chain::{
-    Event as ChainEvent, GlobalParams, Pallet as PalletChain, SubnetParams, TotalStake,
+    Event as ChainEvent, GlobalParams, Pallet as PalletChain, PausableOperation, SubnetParams,
+    TotalStake,
 };
-use parity_scale_codec::{Decode, Encode, MaxEncodedLen};
+use pallet_emission_api::SubnetConsensus;
+use parity_scale_codec::{Compact, Decode, Encode, MaxEncodedLen};
 use scale_info::TypeInfo;
 use sp_std::{collections::btree_set::BTreeSet, vec::Vec};
 use substrate_fixed::types::I92F36;
@@ -25,7 +27,7 @@ pub struct Proposal<T: Config> {
     pub proposer: T::AccountId,
     pub expiration_block: u64,
     pub data: ProposalData<T>,
-    pub status: ProposalStatus<T>,
+    pub status: ProposalStatus,
     pub metadata: BoundedVec<u8, ConstU32<256>>,
     pub proposal_cost: u64,
     pub creation_block: u64,
@@ -41,14 +43,29 @@ impl<T: Config> Proposal<T> {
     /// Returns the subnet ID that this proposal impact.s
     #[must_use]
     pub fn subnet_id(&self) -> Option<u16> {
-        match &self.data {
-            ProposalData::SubnetParams { subnet_id, .. }
-            | ProposalData::SubnetCustom { subnet_id, .. } => Some(*subnet_id),
-            _ => None,
+        self.data.subnet_id()
+    }
+
+    /// The compact record kept once the proposal is closed.
+    #[must_use]
+    pub fn to_archived(&self) -> ArchivedProposal<T> {
+        ArchivedProposal {
+            proposer: self.proposer.clone(),
+            subnet_id: self.subnet_id(),
+            metadata: self.metadata.clone(),
+            creation_block: self.creation_block,
+            status: self.status.clone(),
         }
     }
 
-    /// Marks a proposal as accepted and overrides the storage value.
+    /// Moves a closed proposal out of the active proposals and drops its votes.
+    fn archive(&self) {
+        Proposals::<T>::remove(self.id);
+        let _ = ProposalVotes::<T>::clear_prefix(self.id, u32::MAX, None);
+        ProposalArchive::<T>::insert(self.id, self.to_archived());
+    }
+
+    /// Marks a proposal as accepted and archives it.
     pub fn accept(mut self, block: u64, stake_for: u64, stake_against: u64) -> DispatchResult {
         ensure!(self.is_active(), Error::<T>::ProposalIsFinished);
 
@@ -58,7 +75,7 @@ impl<T: Config> Proposal<T> {
             stake_against,
         };
 
-        Proposals::<T>::insert(self.id, &self);
+        self.archive();
         Pallet::<T>::deposit_event(Event::ProposalAccepted(self.id));
 
         self.execute_proposal()?;
@@ -66,39 +83,30 @@ impl<T: Config> Proposal<T> {
         Ok(())
     }
 
+    /// Emergency proposals are enacted right away, the others wait for the enactment delay so
+    /// users are warned of the changes.
     fn execute_proposal(self) -> DispatchResult {
         PalletChain::<T>::add_balance_to_account(
             &self.proposer,
             PalletChain::<T>::u64_to_balance(self.proposal_cost).unwrap(),
         );
 
-        match self.data {
-            ProposalData::GlobalCustom | ProposalData::SubnetCustom { .. } => {
-                // No specific action needed for custom proposals
-                // The owners will handle the off-chain logic
-            }
-            ProposalData::GlobalParams(params) => {
-                PalletChain::<T>::set_global_params(params.clone())?;
-                PalletChain::<T>::deposit_event(ChainEvent::GlobalParamsUpdated(params));
-            }
-            ProposalData::SubnetParams { subnet_id, params } => {
-                let changeset = SubnetChangeset::<T>::update(subnet_id, params)?;
-                changeset.apply(subnet_id)?;
-                PalletChain::<T>::deposit_event(ChainEvent::SubnetParamsUpdated(subnet_id));
-            }
-            ProposalData::TransferDaoTreasury { account, amount } => {
-                PalletChain::<T>::transfer_balance_to_account(
-                    &DaoTreasuryAddress::<T>::get(),
-                    &account,
-                    amount,
-                )?;
-            }
+        let enactment_delay = GlobalGovernanceConfig::<T>::get().enactment_delay;
+        if enactment_delay == 0 || self.data.is_fast_tracked() {
+            self.data.execute()?;
+            Pallet::<T>::deposit_event(Event::ProposalEnacted(self.id));
+            return Ok(());
         }
 
+        let enactment_block =
+            PalletChain::<T>::get_current_block_number().saturating_add(enactment_delay);
+        ScheduledEnactments::<T>::insert(enactment_block, self.id, self.data);
+        Pallet::<T>::deposit_event(Event::ProposalEnactmentScheduled(self.id, enactment_block));
+
         Ok(())
     }
 
-    /// Marks a proposal as refused and overrides the storage value.
+    /// Marks a proposal as refused and archives it.
     pub fn refuse(mut self, block: u64, stake_for: u64, stake_against: u64) -> DispatchResult {
         ensure!(self.is_active(), Error::<T>::ProposalIsFinished);
 
@@ -108,13 +116,13 @@ impl<T: Config> Proposal<T> {
             stake_against,
         };
 
-        Proposals::<T>::insert(self.id, &self);
+        self.archive();
         Pallet::<T>::deposit_event(Event::ProposalRefused(self.id));
 
         Ok(())
     }
 
-    /// Marks a proposal as expired and overrides the storage value.
+    /// Marks a proposal as expired and archives it.
     pub fn expire(mut self, block_number: u64) -> DispatchResult {
         ensure!(self.is_active(), Error::<T>::ProposalIsFinished);
         ensure!(
@@ -124,19 +132,29 @@ impl<T: Config> Proposal<T> {
 
         self.status = ProposalStatus::Expired;
 
-        Proposals::<T>::insert(self.id, &self);
+        self.archive();
         Pallet::<T>::deposit_event(Event::ProposalExpired(self.id));
 
         Ok(())
     }
 }
 
+/// The compact record of a closed proposal. The proposal data and individual votes are dropped
+/// once the proposal is closed.
 #[derive(Clone, DebugNoBound, TypeInfo, Decode, Encode, MaxEncodedLen, PartialEq, Eq)]
 #[scale_info(skip_type_params(T))]
-pub enum ProposalStatus<T: Config> {
+pub struct ArchivedProposal<T: Config> {
+    pub proposer: T::AccountId,
+    pub subnet_id: Option<SubnetId>,
+    pub metadata: BoundedVec<u8, ConstU32<256>>,
+    pub creation_block: u64,
+    pub status: ProposalStatus,
+}
+
+/// The votes themselves are kept in [`ProposalVotes`] while the proposal is open.
+#[derive(Clone, Debug, TypeInfo, Decode, Encode, MaxEncodedLen, PartialEq, Eq)]
+pub enum ProposalStatus {
     Open {
-        votes_for: BoundedBTreeSet<T::AccountId, ConstU32<{ u32::MAX }>>,
-        votes_against: BoundedBTreeSet<T::AccountId, ConstU32<{ u32::MAX }>>,
         stake_for: u64,
         stake_against: u64,
     },
@@ -153,7 +171,10 @@ pub enum ProposalStatus<T: Config> {
     Expired,
 }
 
-#[derive(DebugNoBound, TypeInfo, Decode, Encode, MaxEncodedLen, PartialEq, Eq)]
+/// The maximum amount of actions a single batch proposal can carry.
+pub const MAX_BATCH_ACTIONS: u32 = 16;
+
+#[derive(Clone, DebugNoBound, TypeInfo, Decode, Encode, PartialEq, Eq)]
 #[scale_info(skip_type_params(T))]
 pub enum ProposalData<T: Config> {
     GlobalCustom,
@@ -169,6 +190,81 @@ pub enum ProposalData<T: Config> {
         account: T::AccountId,
         amount: u64,
     },
+    /// Pays `amount` from the DAO treasury in `tranches` equal parts, one every `interval` blocks.
+    TreasuryGrant {
+        account: T::AccountId,
+        amount: u64,
+        tranches: u32,
+        interval: u64,
+    },
+    /// Cancels the remaining tranches of a treasury grant.
+    CancelTreasuryGrant {
+        grant_id: TreasuryGrantId,
+    },
+    /// Moves a subnet to another consensus type.
+    SubnetConsensusChange {
+        subnet_id: SubnetId,
+        consensus: SubnetConsensus,
+    },
+    /// Pauses a chain operation for `duration` blocks once accepted.
+    EmergencyPause {
+        operation: PausableOperation,
+        duration: u64,
+    },
+    /// Lifts a pause before it expires.
+    EmergencyResume {
+        operation: PausableOperation,
+    },
+    /// Sets or removes the account allowed to pause operations without a proposal.
+    SetPauseGuardian {
+        guardian: Option<T::AccountId>,
+    },
+    /// Cancels an accepted proposal that wasn't enacted yet.
+    VetoEnactment {
+        proposal_id: ProposalId,
+    },
+    /// Opts the subnet in or out of its own application process.
+    SubnetApplicationCost {
+        subnet_id: SubnetId,
+        cost: Option<u64>,
+    },
+    /// Accepts the pending application of the key, or refuses it and removes the key from the
+    /// subnet whitelist.
+    SubnetWhitelist {
+        subnet_id: SubnetId,
+        module_key: T::AccountId,
+        whitelisted: bool,
+    },
+    /// Proposes to hand the subnet over to another account, which still has to accept it.
+    SubnetFounderTransfer {
+        subnet_id: SubnetId,
+        new_founder: T::AccountId,
+    },
+    /// Executes all actions atomically once accepted. Batches can't be nested.
+    Batch(BoundedVec<ProposalData<T>, ConstU32<MAX_BATCH_ACTIONS>>),
+    /// Enacts an accepted proposal the pause guardian vetoed.
+    OverrideVeto {
+        proposal_id: ProposalId,
+    },
+}
+
+// `Batch` makes the type recursive, so the bound has to be written by hand. As batches can't be
+// nested, a batch is bounded by `MAX_BATCH_ACTIONS` times the largest plain action.
+impl<T: Config> MaxEncodedLen for ProposalData<T> {
+    fn max_encoded_len() -> usize {
+        let action = GlobalParams::<T>::max_encoded_len()
+            .max(SubnetId::max_encoded_len().saturating_add(SubnetParams::<T>::max_encoded_len()))
+            .max(
+                T::AccountId::max_encoded_len()
+                    .saturating_add(u64::max_encoded_len())
+                    .saturating_add(u32::max_encoded_len())
+                    .saturating_add(u64::max_encoded_len()),
+            );
+        let batch = Compact::<u32>::max_encoded_len()
+            .saturating_add((MAX_BATCH_ACTIONS as usize).saturating_mul(action.saturating_add(1)));
+
+        action.max(batch).saturating_add(1)
+    }
 }
 
 impl<T: Config> ProposalData<T> {
@@ -176,29 +272,285 @@ impl<T: Config> ProposalData<T> {
     #[must_use]
     pub fn required_stake(&self) -> Percent {
         match self {
-            Self::GlobalCustom | Self::SubnetCustom { .. } | Self::TransferDaoTreasury { .. } => {
-                Percent::from_parts(50)
+            Self::GlobalCustom
+            | Self::SubnetCustom { .. }
+            | Self::TransferDaoTreasury { .. }
+            | Self::TreasuryGrant { .. }
+            | Self::CancelTreasuryGrant { .. }
+            | Self::SubnetConsensusChange { .. }
+            | Self::SetPauseGuardian { .. }
+            | Self::SubnetApplicationCost { .. }
+            | Self::SubnetWhitelist { .. }
+            | Self::SubnetFounderTransfer { .. }
+            | Self::OverrideVeto { .. } => Percent::from_parts(50),
+            Self::GlobalParams(_)
+            | Self::SubnetParams { .. }
+            | Self::EmergencyPause { .. }
+            | Self::EmergencyResume { .. }
+            | Self::VetoEnactment { .. } => Percent::from_parts(40),
+            Self::Batch(actions) => actions
+                .iter()
+                .map(Self::required_stake)
+                .max()
+                .unwrap_or(Percent::from_parts(50)),
+        }
+    }
+
+    /// Emergency proposals are accepted as soon as enough stake votes for them, instead of
+    /// waiting for their expiration.
+    #[must_use]
+    pub fn is_fast_tracked(&self) -> bool {
+        match self {
+            Self::EmergencyPause { .. }
+            | Self::EmergencyResume { .. }
+            | Self::VetoEnactment { .. } => true,
+            Self::Batch(actions) => {
+                !actions.is_empty() && actions.iter().all(Self::is_fast_tracked)
+            }
+            _ => false,
+        }
+    }
+
+    /// Whether the pause guardian can veto the proposal. Proposals changing the governance
+    /// itself, like the guardian or the curator council, can only be vetoed by governance.
+    #[must_use]
+    pub fn is_guardian_vetoable(&self) -> bool {
+        match self {
+            Self::GlobalParams(_)
+            | Self::SetPauseGuardian { .. }
+            | Self::VetoEnactment { .. }
+            | Self::OverrideVeto { .. } => false,
+            Self::Batch(actions) => actions.iter().all(Self::is_guardian_vetoable),
+            _ => true,
+        }
+    }
+
+    /// Returns the subnet ID the proposal data impacts. Batches only count as subnet proposals
+    /// if every action targets the same subnet.
+    #[must_use]
+    pub fn subnet_id(&self) -> Option<u16> {
+        match self {
+            Self::SubnetParams { subnet_id, .. }
+            | Self::SubnetCustom { subnet_id, .. }
+            | Self::SubnetApplicationCost { subnet_id, .. }
+            | Self::SubnetWhitelist { subnet_id, .. }
+            | Self::SubnetFounderTransfer { subnet_id, .. } => Some(*subnet_id),
+            Self::Batch(actions) => {
+                let mut subnets = actions.iter().map(Self::subnet_id);
+                let first = subnets.next().flatten()?;
+                subnets.all(|subnet_id| subnet_id == Some(first)).then_some(first)
+            }
+            _ => None,
+        }
+    }
+
+    /// Checks the proposal data is valid before it gets voted on.
+    pub fn validate(&self) -> DispatchResult {
+        match self {
+            Self::GlobalCustom | Self::SubnetCustom { .. } => {}
+            Self::GlobalParams(params) => {
+                Pallet::<T>::validate(params.governance_config.clone())?;
+                PalletChain::<T>::check_global_params(params)?;
+            }
+            Self::SubnetParams { subnet_id, params } => {
+                Pallet::<T>::ensure_subnet_vote_mode(*subnet_id)?;
+
+                Pallet::<T>::validate(params.governance_config.clone())?;
+                SubnetChangeset::<T>::update(*subnet_id, params.clone())?
+                    .ensure_storage_deposit_affordable(*subnet_id)?;
+            }
+            Self::TransferDaoTreasury { amount, .. } => {
+                ensure!(
+                    PalletChain::<T>::has_enough_balance(&DaoTreasuryAddress::<T>::get(), *amount),
+                    Error::<T>::InsufficientDaoTreasuryFunds
+                );
+            }
+            Self::TreasuryGrant {
+                amount,
+                tranches,
+                interval,
+                ..
+            } => {
+                Pallet::<T>::validate_treasury_grant(*amount, *tranches, *interval)?;
+            }
+            Self::CancelTreasuryGrant { grant_id } => {
+                ensure!(
+                    TreasuryGrants::<T>::contains_key(grant_id),
+                    Error::<T>::TreasuryGrantNotFound
+                );
+            }
+            Self::SubnetConsensusChange {
+                subnet_id,
+                consensus,
+            } => {
+                Pallet::<T>::validate_subnet_consensus_change(*subnet_id, *consensus)?;
+            }
+            Self::EmergencyPause { duration, .. } => {
+                Pallet::<T>::validate_pause_duration(*duration, MAX_EMERGENCY_PAUSE_DURATION)?;
+            }
+            Self::EmergencyResume { .. } | Self::SetPauseGuardian { .. } => {}
+            Self::VetoEnactment { proposal_id } => {
+                if !GuardianVetoedEnactments::<T>::contains_key(proposal_id) {
+                    Pallet::<T>::scheduled_enactment_block(*proposal_id)?;
+                }
+            }
+            Self::OverrideVeto { proposal_id } => {
+                ensure!(
+                    GuardianVetoedEnactments::<T>::contains_key(proposal_id),
+                    Error::<T>::EnactmentNotVetoed
+                );
+            }
+            Self::SubnetApplicationCost { subnet_id, .. } => {
+                Pallet::<T>::ensure_subnet_vote_mode(*subnet_id)?;
+            }
+            Self::SubnetWhitelist {
+                subnet_id,
+                module_key,
+                whitelisted,
+            } => {
+                Pallet::<T>::ensure_subnet_vote_mode(*subnet_id)?;
+                Pallet::<T>::validate_subnet_whitelist_change(
+                    *subnet_id,
+                    module_key,
+                    *whitelisted,
+                )?;
+            }
+            Self::SubnetFounderTransfer { subnet_id, .. } => {
+                Pallet::<T>::validate_subnet_founder_transfer(*subnet_id)?;
+            }
+            Self::Batch(actions) => {
+                ensure!(!actions.is_empty(), Error::<T>::EmptyBatchProposal);
+                ensure!(
+                    !actions.iter().any(|action| matches!(action, Self::Batch(_))),
+                    Error::<T>::NestedBatchProposal
+                );
+
+                for action in actions.iter() {
+                    action.validate()?;
+                }
+
+                let transferred: u64 = actions
+                    .iter()
+                    .filter_map(|action| match action {
+                        Self::TransferDaoTreasury { amount, .. }
+                        | Self::TreasuryGrant { amount, .. } => Some(*amount),
+                        _ => None,
+                    })
+                    .fold(0, u64::saturating_add);
+                ensure!(
+                    transferred == 0
+                        || PalletChain::<T>::has_enough_balance(
+                            &DaoTreasuryAddress::<T>::get(),
+                            transferred
+                        ),
+                    Error::<T>::InsufficientDaoTreasuryFunds
+                );
+            }
+        }
+
+        Ok(())
+    }
+
+    pub(crate) fn execute(self) -> DispatchResult {
+        match self {
+            Self::GlobalCustom | Self::SubnetCustom { .. } => {
+                // No specific action needed for custom proposals
+                // The owners will handle the off-chain logic
+            }
+            Self::GlobalParams(params) => {
+                PalletChain::<T>::set_global_params(params.clone())?;
+                PalletChain::<T>::deposit_event(ChainEvent::GlobalParamsUpdated(params));
+            }
+            Self::SubnetParams { subnet_id, params } => {
+                let changeset = SubnetChangeset::<T>::update(subnet_id, params)?;
+                changeset.apply(subnet_id)?;
+                PalletChain::<T>::deposit_event(ChainEvent::SubnetParamsUpdated(subnet_id));
+            }
+            Self::TransferDaoTreasury { account, amount } => {
+                PalletChain::<T>::transfer_balance_to_account(
+                    &DaoTreasuryAddress::<T>::get(),
+                    &account,
+                    amount,
+                )?;
+            }
+            Self::TreasuryGrant {
+                account,
+                amount,
+                tranches,
+                interval,
+            } => {
+                Pallet::<T>::create_treasury_grant(account, amount, tranches, interval);
+            }
+            Self::CancelTreasuryGrant { grant_id } => {
+                Pallet::<T>::cancel_treasury_grant(grant_id)?;
+            }
+            Self::SubnetConsensusChange {
+                subnet_id,
+                consensus,
+            } => {
+                // The network might have changed since the proposal was created.
+                Pallet::<T>::validate_subnet_consensus_change(subnet_id, consensus)?;
+                T::change_subnet_consensus_type(subnet_id, consensus);
+            }
+            Self::EmergencyPause {
+                operation,
+                duration,
+            } => {
+                Pallet::<T>::emergency_pause(operation, duration);
+            }
+            Self::EmergencyResume { operation } => {
+                Pallet::<T>::emergency_resume(operation);
+            }
+            Self::SetPauseGuardian { guardian } => {
+                Pallet::<T>::set_pause_guardian(guardian);
+            }
+            Self::VetoEnactment { proposal_id } => {
+                Pallet::<T>::veto_enactment(proposal_id)?;
+            }
+            Self::SubnetApplicationCost { subnet_id, cost } => {
+                Pallet::<T>::set_subnet_application_cost(subnet_id, cost);
+            }
+            Self::SubnetWhitelist {
+                subnet_id,
+                module_key,
+                whitelisted,
+            } => {
+                Pallet::<T>::set_subnet_whitelisted(subnet_id, module_key, whitelisted)?;
+            }
+            Self::SubnetFounderTransfer {
+                subnet_id,
+                new_founder,
+            } => {
+                // The subnet might have been removed since the proposal was created.
+                Pallet::<T>::validate_subnet_founder_transfer(subnet_id)?;
+                PalletChain::<T>::propose_founder_transfer(subnet_id, new_founder);
+            }
+            Self::Batch(actions) => {
+                // Either every action goes through, or none of them do.
+                with_storage_layer(|| actions.into_iter().try_for_each(Self::execute))?;
+            }
+            Self::OverrideVeto { proposal_id } => {
+                Pallet::<T>::override_guardian_veto(proposal_id)?;
             }
-            Self::GlobalParams(_) | Self::SubnetParams { .. } => Percent::from_parts(40),
         }
+
+        Ok(())
     }
 }
 
-#[derive(DebugNoBound, TypeInfo, Decode, Encode, MaxEncodedLen, PartialEq, Eq)]
-#[scale_info(skip_type_params(T))]
-pub struct UnrewardedProposal<T: Config> {
+/// A closed proposal whose voters weren't rewarded yet. The stake of each voter is kept in
+/// [`UnrewardedProposalVotes`].
+#[derive(Debug, TypeInfo, Decode, Encode, MaxEncodedLen, PartialEq, Eq)]
+pub struct UnrewardedProposal {
     pub subnet_id: Option<SubnetId>,
     pub block: u64,
-    pub votes_for: BoundedBTreeMap<T::AccountId, u64, ConstU32<{ u32::MAX }>>,
-    pub votes_against: BoundedBTreeMap<T::AccountId, u64, ConstU32<{ u32::MAX }>>,
 }
 
 impl<T: Config> Pallet<T> {
     fn get_next_proposal_id() -> u64 {
-        match Proposals::<T>::iter_keys().max() {
-            Some(id) => id.saturating_add(1),
-            None => 0,
-        }
+        let id = NextProposalId::<T>::get();
+        NextProposalId::<T>::set(id.saturating_add(1));
+        id
     }
 
     pub fn add_proposal(
@@ -241,8 +593,6 @@ impl<T: Config> Pallet<T> {
             expiration_block,
             data,
             status: ProposalStatus::Open {
-                votes_for: BoundedBTreeSet::new(),
-                votes_against: BoundedBTreeSet::new(),
                 stake_for: 0,
                 stake_against: 0,
             },
@@ -296,36 +646,30 @@ impl<T: Config> Pallet<T> {
         let key = ensure_signed(origin)?;
         ensure!(!data.is_empty(), Error::<T>::ProposalDataTooSmall);
         ensure!(data.len() <= 256, Error::<T>::ProposalDataTooLarge);
-        ensure!(
-            pallet_chain::Pallet::<T>::has_enough_balance(
-                &DaoTreasuryAddress::<T>::get(),
-                value
-            ),
-            Error::<T>::InsufficientDaoTreasuryFunds
-        );
         sp_std::str::from_utf8(&data).map_err(|_| Error::<T>::InvalidProposalData)?;
 
         let proposal_data = ProposalData::TransferDaoTreasury {
             amount: value,
             account: dest,
         };
+        proposal_data.validate()?;
+
         Self::add_proposal(key, BoundedVec::truncate_from(data), proposal_data)
     }
 
     pub fn do_add_global_params_proposal(
         origin: T::RuntimeOrigin,
         data: Vec<u8>,
-        mut params: GlobalParams<T>,
+        params: GlobalParams<T>,
     ) -> DispatchResult {
         let key = ensure_signed(origin)?;
 
         ensure!(!data.is_empty(), Error::<T>::ProposalDataTooSmall);
         ensure!(data.len() <= 256, Error::<T>::ProposalDataTooLarge);
 
-        params.governance_config = Self::validate(params.governance_config)?;
-        pallet_chain::Pallet::check_global_params(&params)?;
-
         let proposal_data = ProposalData::GlobalParams(params);
+        proposal_data.validate()?;
+
         Self::add_proposal(key, BoundedVec::truncate_from(data), proposal_data)
     }
 
@@ -333,38 +677,141 @@ impl<T: Config> Pallet<T> {
         origin: T::RuntimeOrigin,
         subnet_id: u16,
         data: Vec<u8>,
-        mut params: SubnetParams<T>,
+        params: SubnetParams<T>,
     ) -> DispatchResult {
         let key = ensure_signed(origin)?;
 
+        ensure!(!data.is_empty(), Error::<T>::ProposalDataTooSmall);
+        ensure!(data.len() <= 256, Error::<T>::ProposalDataTooLarge);
+
+        let proposal_data = ProposalData::SubnetParams { subnet_id, params };
+        proposal_data.validate()?;
+
+        Self::add_proposal(key, BoundedVec::truncate_from(data), proposal_data)
+    }
+
+    pub fn do_add_treasury_grant_proposal(
+        origin: T::RuntimeOrigin,
+        data: Vec<u8>,
+        dest: T::AccountId,
+        amount: u64,
+        tranches: u32,
+        interval: u64,
+    ) -> DispatchResult {
+        let key = ensure_signed(origin)?;
+        ensure!(!data.is_empty(), Error::<T>::ProposalDataTooSmall);
+        ensure!(data.len() <= 256, Error::<T>::ProposalDataTooLarge);
+        sp_std::str::from_utf8(&data).map_err(|_| Error::<T>::InvalidProposalData)?;
+
+        let proposal_data = ProposalData::TreasuryGrant {
+            account: dest,
+            amount,
+            tranches,
+            interval,
+        };
+        proposal_data.validate()?;
+
+        Self::add_proposal(key, BoundedVec::truncate_from(data), proposal_data)
+    }
+
+    pub fn do_add_cancel_treasury_grant_proposal(
+        origin: T::RuntimeOrigin,
+        data: Vec<u8>,
+        grant_id: TreasuryGrantId,
+    ) -> DispatchResult {
+        let key = ensure_signed(origin)?;
+        ensure!(!data.is_empty(), Error::<T>::ProposalDataTooSmall);
+        ensure!(data.len() <= 256, Error::<T>::ProposalDataTooLarge);
+        sp_std::str::from_utf8(&data).map_err(|_| Error::<T>::InvalidProposalData)?;
+
+        let proposal_data = ProposalData::CancelTreasuryGrant { grant_id };
+        proposal_data.validate()?;
+
+        Self::add_proposal(key, BoundedVec::truncate_from(data), proposal_data)
+    }
+
+    pub fn do_add_subnet_consensus_change_proposal(
+        origin: T::RuntimeOrigin,
+        data: Vec<u8>,
+        subnet_id: SubnetId,
+        consensus: SubnetConsensus,
+    ) -> DispatchResult {
+        let key = ensure_signed(origin)?;
+        ensure!(!data.is_empty(), Error::<T>::ProposalDataTooSmall);
+        ensure!(data.len() <= 256, Error::<T>::ProposalDataTooLarge);
+        sp_std::str::from_utf8(&data).map_err(|_| Error::<T>::InvalidProposalData)?;
+
+        let proposal_data = ProposalData::SubnetConsensusChange {
+            subnet_id,
+            consensus,
+        };
+        proposal_data.validate()?;
+
+        Self::add_proposal(key, BoundedVec::truncate_from(data), proposal_data)
+    }
+
+    /// Checks the consensus change keeps the network invariants: there is only a single rootnet,
+    /// and the treasury subnet is never removed.
+    pub fn validate_subnet_consensus_change(
+        subnet_id: SubnetId,
+        consensus: SubnetConsensus,
+    ) -> DispatchResult {
+        let current =
+            T::get_subnet_consensus_type(subnet_id).ok_or(Error::<T>::SubnetConsensusNotFound)?;
+
+        ensure!(current != consensus, Error::<T>::SubnetConsensusUnchanged);
         ensure!(
-            matches!(
-                SubnetGovernanceConfig::<T>::get(subnet_id).vote_mode,
-                VoteMode::Vote
-            ),
-            Error::<T>::NotVoteMode
+            current != SubnetConsensus::Treasury,
+            Error::<T>::CannotRemoveTreasurySubnet
+        );
+        ensure!(
+            current != SubnetConsensus::Root,
+            Error::<T>::CannotRemoveRootSubnet
+        );
+        ensure!(
+            consensus != SubnetConsensus::Root
+                || T::get_consensus_netuid(SubnetConsensus::Root).is_none(),
+            Error::<T>::RootSubnetAlreadyExists
+        );
+        ensure!(
+            consensus != SubnetConsensus::Treasury
+                || T::get_consensus_netuid(SubnetConsensus::Treasury).is_none(),
+            Error::<T>::TreasurySubnetAlreadyExists
         );
 
+        Ok(())
+    }
+
+    pub fn do_add_batch_proposal(
+        origin: T::RuntimeOrigin,
+        data: Vec<u8>,
+        actions: Vec<ProposalData<T>>,
+    ) -> DispatchResult {
+        let key = ensure_signed(origin)?;
+
         ensure!(!data.is_empty(), Error::<T>::ProposalDataTooSmall);
         ensure!(data.len() <= 256, Error::<T>::ProposalDataTooLarge);
 
-        params.governance_config = Self::validate(params.governance_config)?;
-        SubnetChangeset::<T>::update(subnet_id, params.clone())?;
+        let actions: BoundedVec<_, _> =
+            actions.try_into().map_err(|_| Error::<T>::BatchProposalTooLarge)?;
+
+        let proposal_data = ProposalData::Batch(actions);
+        proposal_data.validate()?;
 
-        let proposal_data = ProposalData::SubnetParams { subnet_id, params };
         Self::add_proposal(key, BoundedVec::truncate_from(data), proposal_data)
     }
 }
 
 pub fn tick_proposals<T: Config>(block_number: u64) {
-    let not_delegating = NotDelegatingVotingPower::<T>::get().into_inner();
-
-    let proposals = Proposals::<T>::iter().filter(|(_, p)| p.is_active());
-
     if block_number % 100 != 0 {
         return;
     }
 
+    let not_delegating = NotDelegatingVotingPower::<T>::get().into_inner();
+
+    // Only open proposals are kept in `Proposals`, closed ones are moved to the archive.
+    let proposals: Vec<_> = Proposals::<T>::iter().collect();
+
     for (id, proposal) in proposals {
         let res = with_storage_layer(|| tick_proposal(&not_delegating, block_number, proposal));
         if let Err(err) = res {
@@ -373,6 +820,22 @@ pub fn tick_proposals<T: Config>(block_number: u64) {
     }
 }
 
+/// Enacts the accepted proposals scheduled for this block. A failing proposal doesn't prevent the
+/// others from being enacted.
+pub fn tick_enactments<T: Config>(block_number: u64) {
+    let scheduled: Vec<_> = ScheduledEnactments::<T>::drain_prefix(block_number).collect();
+
+    for (id, data) in scheduled {
+        match with_storage_layer(|| data.execute()) {
+            Ok(()) => Pallet::<T>::deposit_event(Event::ProposalEnacted(id)),
+            Err(err) => {
+                log::error!("failed to enact proposal {id}: {err:?}");
+                Pallet::<T>::deposit_event(Event::ProposalEnactmentFailed(id, err));
+            }
+        }
+    }
+}
+
 pub fn get_minimal_stake_to_execute_with_percentage<T: Config>(
     threshold: Percent,
     subnet_id: Option<u16>,
@@ -397,75 +860,52 @@ fn tick_proposal<T: Config>(
 ) -> DispatchResult {
     let subnet_id = proposal.subnet_id();
 
-    let ProposalStatus::Open {
-        votes_for,
-        votes_against,
-        ..
-    } = &proposal.status
-    else {
-        return Err(Error::<T>::ProposalIsFinished.into());
-    };
+    ensure!(proposal.is_active(), Error::<T>::ProposalIsFinished);
 
-    let votes_for: Vec<(T::AccountId, u64)> = votes_for
-        .iter()
-        .cloned()
-        .map(|id| {
-            let stake = calc_stake::<T>(not_delegating, &id);
-            (id, stake)
-        })
-        .collect();
-    let votes_against: Vec<(T::AccountId, u64)> = votes_against
-        .iter()
-        .cloned()
-        .map(|id| {
-            let stake = calc_stake::<T>(not_delegating, &id);
-            (id, stake)
-        })
-        .collect();
+    let (votes_for, votes_against): (Vec<_>, Vec<_>) =
+        ProposalVotes::<T>::iter_prefix(proposal.id).partition(|(_, agree)| *agree);
+
+    let with_stake = |(id, _): (T::AccountId, bool)| {
+        let stake = calc_stake::<T>(not_delegating, &id);
+        (id, stake)
+    };
+    let votes_for: Vec<(T::AccountId, u64)> = votes_for.into_iter().map(with_stake).collect();
+    let votes_against: Vec<(T::AccountId, u64)> =
+        votes_against.into_iter().map(with_stake).collect();
 
     let stake_for_sum: u64 = votes_for.iter().map(|(_, stake)| stake).sum();
     let stake_against_sum: u64 = votes_against.iter().map(|(_, stake)| stake).sum();
 
-    if block_number < proposal.expiration_block {
-        if let ProposalStatus::Open {
-            stake_for,
-            stake_against,
-            ..
-        } = &mut proposal.status
-        {
-            *stake_for = stake_for_sum;
-            *stake_against = stake_against_sum;
-        }
-        Proposals::<T>::set(proposal.id, Some(proposal));
-        return Ok(());
-    }
-
-    let total_stake = stake_for_sum.saturating_add(stake_against_sum);
     let minimal_stake_to_execute = get_minimal_stake_to_execute_with_percentage::<T>(
         proposal.data.required_stake(),
         subnet_id,
     );
 
-    let mut reward_votes_for = BoundedBTreeMap::new();
-    for (key, value) in votes_for {
-        reward_votes_for.try_insert(key, value).expect("this wont exceed u32::MAX");
+    // Emergency proposals don't wait for the expiration once their support alone is enough.
+    let fast_tracked = proposal.data.is_fast_tracked()
+        && stake_for_sum > stake_against_sum
+        && stake_for_sum >= minimal_stake_to_execute;
+
+    if block_number < proposal.expiration_block && !fast_tracked {
+        proposal.status = ProposalStatus::Open {
+            stake_for: stake_for_sum,
+            stake_against: stake_against_sum,
+        };
+        Proposals::<T>::insert(proposal.id, proposal);
+        return Ok(());
     }
 
-    let mut reward_votes_against: BoundedBTreeMap<T::AccountId, u64, ConstU32<{ u32::MAX }>> =
-        BoundedBTreeMap::new();
-    for (key, value) in votes_against {
-        reward_votes_against
-            .try_insert(key, value)
-            .expect("this probably wont exceed u32::MAX");
+    let total_stake = stake_for_sum.saturating_add(stake_against_sum);
+
+    for (key, stake) in votes_for.into_iter().chain(votes_against) {
+        UnrewardedProposalVotes::<T>::insert(proposal.id, key, stake);
     }
 
     UnrewardedProposals::<T>::insert(
         proposal.id,
-        UnrewardedProposal::<T> {
-            subnet_id: proposal.subnet_id(),
+        UnrewardedProposal {
+            subnet_id,
             block: block_number,
-            votes_for: reward_votes_for,
-            votes_against: reward_votes_against,
         },
     );
 
@@ -539,11 +979,7 @@ pub fn execute_proposal_rewards<T: Config>(
             continue;
         }
 
-        for (acc_id, stake) in unrewarded_proposal
-            .votes_for
-            .into_iter()
-            .chain(unrewarded_proposal.votes_against.into_iter())
-        {
+        for (acc_id, stake) in UnrewardedProposalVotes::<T>::iter_prefix(proposal_id) {
             let curr_stake = *account_stakes.get(&acc_id).unwrap_or(&0u64);
             account_stakes
                 .try_insert(acc_id, curr_stake.saturating_add(stake))
@@ -561,6 +997,7 @@ pub fn execute_proposal_rewards<T: Config>(
         }
 
         UnrewardedProposals::<T>::remove(proposal_id);
+        let _ = UnrewardedProposalVotes::<T>::clear_prefix(proposal_id, u32::MAX, None);
         n = n.saturating_add(1);
     }
 
@@ -0,0 +1,251 @@
+use crate::*;
+use frame_support::pallet_prelude::{BoundedVec, ConstU32, DispatchResult};
+use frame_system::ensure_signed;
+use pallet_chain::{Founder, Pallet as PalletChain};
+use parity_scale_codec::{Decode, Encode, MaxEncodedLen};
+use scale_info::TypeInfo;
+
+/// A pending application to register modules on a subnet that runs its own application process.
+#[derive(Clone, TypeInfo, Decode, Encode, MaxEncodedLen, frame_support::DebugNoBound)]
+#[scale_info(skip_type_params(T))]
+pub struct SubnetApplication<T: Config> {
+    pub paying_for: T::AccountId,
+    pub data: BoundedVec<u8, ConstU32<256>>,
+    pub application_cost: u64,
+    pub block_number: u64,
+}
+
+impl<T: Config> Pallet<T> {
+    /// Subnets without an application process accept every key.
+    #[must_use]
+    pub fn is_approved_for_subnet(subnet_id: SubnetId, module_key: &T::AccountId) -> bool {
+        !SubnetApplicationCost::<T>::contains_key(subnet_id)
+            || SubnetWhitelist::<T>::contains_key(subnet_id, module_key)
+    }
+
+    /// Subnet proposals can only be made once the subnet switched to vote mode.
+    pub fn ensure_subnet_vote_mode(subnet_id: SubnetId) -> DispatchResult {
+        ensure!(
+            matches!(
+                SubnetGovernanceConfig::<T>::get(subnet_id).vote_mode,
+                VoteMode::Vote
+            ),
+            Error::<T>::NotVoteMode
+        );
+        Ok(())
+    }
+
+    /// Only the founder of a subnet on authority mode can decide on its applications directly.
+    fn ensure_subnet_authority(origin: T::RuntimeOrigin, subnet_id: SubnetId) -> DispatchResult {
+        let key = ensure_signed(origin)?;
+        ensure!(
+            PalletChain::<T>::if_subnet_exist(subnet_id),
+            Error::<T>::SubnetNotFound
+        );
+        ensure!(
+            Founder::<T>::get(subnet_id) == key,
+            Error::<T>::NotSubnetFounder
+        );
+        ensure!(
+            matches!(
+                SubnetGovernanceConfig::<T>::get(subnet_id).vote_mode,
+                VoteMode::Authority
+            ),
+            Error::<T>::VoteModeIsNotAuthority
+        );
+        Ok(())
+    }
+
+    pub fn do_set_subnet_application_cost(
+        origin: T::RuntimeOrigin,
+        subnet_id: SubnetId,
+        cost: Option<u64>,
+    ) -> DispatchResult {
+        Self::ensure_subnet_authority(origin, subnet_id)?;
+        Self::set_subnet_application_cost(subnet_id, cost);
+        Ok(())
+    }
+
+    /// Opts the subnet in or out of its application process. Opting out keeps the whitelist, so
+    /// it applies again if the subnet opts back in.
+    pub fn set_subnet_application_cost(subnet_id: SubnetId, cost: Option<u64>) {
+        SubnetApplicationCost::<T>::set(subnet_id, cost);
+        Self::deposit_event(Event::SubnetApplicationCostChanged(subnet_id, cost));
+    }
+
+    pub fn do_add_subnet_application(
+        origin: T::RuntimeOrigin,
+        subnet_id: SubnetId,
+        module_key: T::AccountId,
+        data: Vec<u8>,
+    ) -> DispatchResult {
+        let key = ensure_signed(origin)?;
+        ensure!(
+            (1..=256).contains(&data.len()),
+            Error::<T>::InvalidApplicationSize
+        );
+        ensure!(
+            sp_std::str::from_utf8(&data).is_ok(),
+            Error::<T>::InvalidApplication
+        );
+
+        let application_cost = SubnetApplicationCost::<T>::get(subnet_id)
+            .ok_or(Error::<T>::SubnetApplicationsDisabled)?;
+        ensure!(
+            !SubnetWhitelist::<T>::contains_key(subnet_id, &module_key),
+            Error::<T>::AlreadyWhitelisted
+        );
+        ensure!(
+            !SubnetApplications::<T>::contains_key(subnet_id, &module_key),
+            Error::<T>::ApplicationKeyAlreadyUsed
+        );
+        ensure!(
+            PalletChain::<T>::has_enough_balance(&key, application_cost),
+            Error::<T>::NotEnoughBalanceToApply
+        );
+
+        let removed_balance = PalletChain::<T>::u64_to_balance(application_cost)
+            .ok_or(Error::<T>::InvalidCurrencyConversionValue)?;
+        PalletChain::<T>::remove_balance_from_account(&key, removed_balance)?;
+
+        let application = SubnetApplication {
+            paying_for: key,
+            data: BoundedVec::truncate_from(data),
+            application_cost,
+            block_number: PalletChain::<T>::get_current_block_number(),
+        };
+        SubnetApplications::<T>::insert(subnet_id, &module_key, application);
+
+        Self::deposit_event(Event::SubnetApplicationCreated(subnet_id, module_key));
+        Ok(())
+    }
+
+    pub fn do_set_subnet_whitelisted(
+        origin: T::RuntimeOrigin,
+        subnet_id: SubnetId,
+        module_key: T::AccountId,
+        whitelisted: bool,
+    ) -> DispatchResult {
+        Self::ensure_subnet_authority(origin, subnet_id)?;
+        Self::validate_subnet_whitelist_change(subnet_id, &module_key, whitelisted)?;
+        Self::set_subnet_whitelisted(subnet_id, module_key, whitelisted)
+    }
+
+    /// Whitelisting requires a pending application, while removing a key either takes it out of
+    /// the whitelist or refuses its pending application.
+    pub fn validate_subnet_whitelist_change(
+        subnet_id: SubnetId,
+        module_key: &T::AccountId,
+        whitelisted: bool,
+    ) -> DispatchResult {
+        let pending = SubnetApplications::<T>::contains_key(subnet_id, module_key);
+        if whitelisted {
+            ensure!(pending, Error::<T>::ApplicationNotFound);
+        } else {
+            ensure!(
+                pending || SubnetWhitelist::<T>::contains_key(subnet_id, module_key),
+                Error::<T>::NotWhitelisted
+            );
+        }
+        Ok(())
+    }
+
+    pub fn set_subnet_whitelisted(
+        subnet_id: SubnetId,
+        module_key: T::AccountId,
+        whitelisted: bool,
+    ) -> DispatchResult {
+        let application = SubnetApplications::<T>::take(subnet_id, &module_key);
+
+        if whitelisted {
+            let application = application.ok_or(Error::<T>::ApplicationNotFound)?;
+            // Give the applicant back their tokens, as the application passed
+            PalletChain::<T>::add_balance_to_account(
+                &application.paying_for,
+                PalletChain::<T>::u64_to_balance(application.application_cost)
+                    .ok_or(Error::<T>::InvalidCurrencyConversionValue)?,
+            );
+            SubnetWhitelist::<T>::insert(subnet_id, &module_key, ());
+            Self::deposit_event(Event::SubnetWhitelistModuleAdded(subnet_id, module_key));
+        } else if application.is_some() {
+            Self::deposit_event(Event::SubnetApplicationRefused(subnet_id, module_key));
+        } else {
+            ensure!(
+                SubnetWhitelist::<T>::contains_key(subnet_id, &module_key),
+                Error::<T>::NotWhitelisted
+            );
+            SubnetWhitelist::<T>::remove(subnet_id, &module_key);
+            Self::deposit_event(Event::SubnetWhitelistModuleRemoved(subnet_id, module_key));
+        }
+
+        Ok(())
+    }
+
+    /// Founder transfers can only be proposed on existing subnets in vote mode.
+    pub fn validate_subnet_founder_transfer(subnet_id: SubnetId) -> DispatchResult {
+        ensure!(
+            PalletChain::<T>::if_subnet_exist(subnet_id),
+            Error::<T>::SubnetNotFound
+        );
+        Self::ensure_subnet_vote_mode(subnet_id)
+    }
+
+    pub fn do_add_subnet_application_cost_proposal(
+        origin: T::RuntimeOrigin,
+        data: Vec<u8>,
+        subnet_id: SubnetId,
+        cost: Option<u64>,
+    ) -> DispatchResult {
+        let key = ensure_signed(origin)?;
+        ensure!(!data.is_empty(), Error::<T>::ProposalDataTooSmall);
+        ensure!(data.len() <= 256, Error::<T>::ProposalDataTooLarge);
+        sp_std::str::from_utf8(&data).map_err(|_| Error::<T>::InvalidProposalData)?;
+
+        let proposal_data = ProposalData::SubnetApplicationCost { subnet_id, cost };
+        proposal_data.validate()?;
+
+        Self::add_proposal(key, BoundedVec::truncate_from(data), proposal_data)
+    }
+
+    pub fn do_add_subnet_whitelist_proposal(
+        origin: T::RuntimeOrigin,
+        data: Vec<u8>,
+        subnet_id: SubnetId,
+        module_key: T::AccountId,
+        whitelisted: bool,
+    ) -> DispatchResult {
+        let key = ensure_signed(origin)?;
+        ensure!(!data.is_empty(), Error::<T>::ProposalDataTooSmall);
+        ensure!(data.len() <= 256, Error::<T>::ProposalDataTooLarge);
+        sp_std::str::from_utf8(&data).map_err(|_| Error::<T>::InvalidProposalData)?;
+
+        let proposal_data = ProposalData::SubnetWhitelist {
+            subnet_id,
+            module_key,
+            whitelisted,
+        };
+        proposal_data.validate()?;
+
+        Self::add_proposal(key, BoundedVec::truncate_from(data), proposal_data)
+    }
+
+    pub fn do_add_subnet_founder_transfer_proposal(
+        origin: T::RuntimeOrigin,
+        data: Vec<u8>,
+        subnet_id: SubnetId,
+        new_founder: T::AccountId,
+    ) -> DispatchResult {
+        let key = ensure_signed(origin)?;
+        ensure!(!data.is_empty(), Error::<T>::ProposalDataTooSmall);
+        ensure!(data.len() <= 256, Error::<T>::ProposalDataTooLarge);
+        sp_std::str::from_utf8(&data).map_err(|_| Error::<T>::InvalidProposalData)?;
+
+        let proposal_data = ProposalData::SubnetFounderTransfer {
+            subnet_id,
+            new_founder,
+        };
+        proposal_data.validate()?;
+
+        Self::add_proposal(key, BoundedVec::truncate_from(data), proposal_data)
+    }
+}
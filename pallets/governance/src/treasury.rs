@@ -0,0 +1,149 @@
+use crate::*;
+use frame_support::{
+    ensure, pallet_prelude::DispatchResult, storage::with_storage_layer, DebugNoBound,
+};
+use pallet_chain::Pallet as PalletChain;
+use parity_scale_codec::{Decode, Encode, MaxEncodedLen};
+use scale_info::TypeInfo;
+use sp_std::vec::Vec;
+
+pub type TreasuryGrantId = u64;
+
+/// A payout from the DAO treasury split into equal tranches, paid every `interval` blocks.
+#[derive(Clone, DebugNoBound, TypeInfo, Decode, Encode, MaxEncodedLen, PartialEq, Eq)]
+#[scale_info(skip_type_params(T))]
+pub struct TreasuryGrant<T: Config> {
+    pub id: TreasuryGrantId,
+    pub account: T::AccountId,
+    /// The total amount granted, across all tranches.
+    pub amount: u64,
+    /// The amount already paid out.
+    pub paid: u64,
+    pub tranches: u32,
+    pub paid_tranches: u32,
+    pub interval: u64,
+    pub next_payout_block: u64,
+}
+
+impl<T: Config> TreasuryGrant<T> {
+    /// The amount paid out by the next tranche. The last tranche carries the rounding remainder.
+    #[must_use]
+    pub fn next_tranche_amount(&self) -> u64 {
+        let remaining_tranches = self.tranches.saturating_sub(self.paid_tranches);
+        if remaining_tranches <= 1 {
+            self.amount.saturating_sub(self.paid)
+        } else {
+            self.amount.checked_div(self.tranches as u64).unwrap_or_default()
+        }
+    }
+
+    #[must_use]
+    pub fn is_completed(&self) -> bool {
+        self.paid_tranches >= self.tranches
+    }
+}
+
+impl<T: Config> Pallet<T> {
+    pub fn validate_treasury_grant(amount: u64, tranches: u32, interval: u64) -> DispatchResult {
+        ensure!(
+            tranches > 0 && interval > 0 && amount >= tranches as u64,
+            Error::<T>::InvalidTreasuryGrant
+        );
+        ensure!(
+            PalletChain::<T>::has_enough_balance(&DaoTreasuryAddress::<T>::get(), amount),
+            Error::<T>::InsufficientDaoTreasuryFunds
+        );
+
+        Ok(())
+    }
+
+    /// Schedules a new grant, with the first tranche being paid `interval` blocks from now.
+    pub fn create_treasury_grant(
+        account: T::AccountId,
+        amount: u64,
+        tranches: u32,
+        interval: u64,
+    ) -> TreasuryGrantId {
+        let id = NextTreasuryGrantId::<T>::get();
+        NextTreasuryGrantId::<T>::set(id.saturating_add(1));
+
+        let current_block = PalletChain::<T>::get_current_block_number();
+        let grant = TreasuryGrant::<T> {
+            id,
+            account,
+            amount,
+            paid: 0,
+            tranches,
+            paid_tranches: 0,
+            interval,
+            next_payout_block: current_block.saturating_add(interval),
+        };
+
+        TreasuryGrants::<T>::insert(id, grant);
+        Self::deposit_event(Event::TreasuryGrantCreated(id));
+
+        id
+    }
+
+    /// Removes a grant, dropping all tranches that were not paid yet.
+    pub fn cancel_treasury_grant(grant_id: TreasuryGrantId) -> DispatchResult {
+        ensure!(
+            TreasuryGrants::<T>::contains_key(grant_id),
+            Error::<T>::TreasuryGrantNotFound
+        );
+
+        TreasuryGrants::<T>::remove(grant_id);
+        Self::deposit_event(Event::TreasuryGrantCancelled(grant_id));
+
+        Ok(())
+    }
+}
+
+/// Pays out every tranche that is due. Grants follow the proposal tick, so payouts happen at most
+/// once every 100 blocks.
+pub fn tick_treasury_grants<T: Config>(block_number: u64) {
+    if block_number % 100 != 0 {
+        return;
+    }
+
+    let due = TreasuryGrants::<T>::iter_values()
+        .filter(|grant| grant.next_payout_block <= block_number)
+        .collect::<Vec<_>>();
+
+    for grant in due {
+        let id = grant.id;
+        let res = with_storage_layer(|| pay_tranche::<T>(grant, block_number));
+        if let Err(err) = res {
+            log::error!("failed to pay treasury grant {id}: {err:?}, retrying later...");
+        }
+    }
+}
+
+fn pay_tranche<T: Config>(mut grant: TreasuryGrant<T>, block_number: u64) -> DispatchResult {
+    let amount = grant.next_tranche_amount();
+
+    PalletChain::<T>::transfer_balance_to_account(
+        &DaoTreasuryAddress::<T>::get(),
+        &grant.account,
+        amount,
+    )?;
+
+    grant.paid = grant.paid.saturating_add(amount);
+    grant.paid_tranches = grant.paid_tranches.saturating_add(1);
+    grant.next_payout_block = block_number.saturating_add(grant.interval);
+
+    Pallet::<T>::deposit_event(Event::TreasuryGrantTranchePaid(
+        grant.id,
+        grant.account.clone(),
+        amount,
+    ));
+
+    if grant.is_completed() {
+        TreasuryGrants::<T>::remove(grant.id);
+        Pallet::<T>::deposit_event(Event::TreasuryGrantCompleted(grant.id));
+    } else {
+        TreasuryGrants::<T>::insert(grant.id, grant);
+    }
+
+    Ok(())
+}
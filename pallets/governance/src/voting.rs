@@ -1,8 +1,21 @@
-use crate::{proposal::ProposalStatus, *};
+use crate::*;
 use frame_support::pallet_prelude::DispatchResult;
 use frame_system::ensure_signed;
 
 impl<T: Config> Pallet<T> {
+    /// Closed proposals are archived, so only open proposals are kept in `Proposals`.
+    fn ensure_proposal_is_open(proposal_id: ProposalId) -> DispatchResult {
+        if Proposals::<T>::contains_key(proposal_id) {
+            return Ok(());
+        }
+
+        if ProposalArchive::<T>::contains_key(proposal_id) {
+            Err(Error::<T>::ProposalClosed.into())
+        } else {
+            Err(Error::<T>::ProposalNotFound.into())
+        }
+    }
+
     /// Votes on proposals,
     pub fn do_vote_proposal(
         origin: T::RuntimeOrigin,
@@ -11,21 +24,10 @@ impl<T: Config> Pallet<T> {
     ) -> DispatchResult {
         let key = ensure_signed(origin)?;
 
-        let Ok(mut proposal) = Proposals::<T>::try_get(proposal_id) else {
-            return Err(Error::<T>::ProposalNotFound.into());
-        };
-
-        let ProposalStatus::Open {
-            votes_for,
-            votes_against,
-            ..
-        } = &mut proposal.status
-        else {
-            return Err(Error::<T>::ProposalClosed.into());
-        };
+        Self::ensure_proposal_is_open(proposal_id)?;
 
         ensure!(
-            !votes_for.contains(&key) && !votes_against.contains(&key),
+            !ProposalVotes::<T>::contains_key(proposal_id, &key),
             Error::<T>::AlreadyVoted
         );
 
@@ -41,17 +43,7 @@ impl<T: Config> Pallet<T> {
             return Err(Error::<T>::VoterIsDelegatingVotingPower.into());
         }
 
-        if agree {
-            votes_for
-                .try_insert(key.clone())
-                .map_err(|_| Error::<T>::InvalidProposalVotingParameters)?;
-        } else {
-            votes_against
-                .try_insert(key.clone())
-                .map_err(|_| Error::<T>::InvalidProposalVotingParameters)?;
-        }
-
-        Proposals::<T>::insert(proposal_id, proposal);
+        ProposalVotes::<T>::insert(proposal_id, &key, agree);
         Self::deposit_event(Event::<T>::ProposalVoted(proposal_id, key, agree));
         Ok(())
     }
@@ -60,26 +52,13 @@ impl<T: Config> Pallet<T> {
     pub fn do_remove_vote_proposal(origin: T::RuntimeOrigin, proposal_id: u64) -> DispatchResult {
         let key = ensure_signed(origin)?;
 
-        let Ok(mut proposal) = Proposals::<T>::try_get(proposal_id) else {
-            return Err(Error::<T>::ProposalNotFound.into());
-        };
-
-        let ProposalStatus::Open {
-            votes_for,
-            votes_against,
-            ..
-        } = &mut proposal.status
-        else {
-            return Err(Error::<T>::ProposalClosed.into());
-        };
+        Self::ensure_proposal_is_open(proposal_id)?;
 
-        let removed = votes_for.remove(&key) || votes_against.remove(&key);
+        let removed = ProposalVotes::<T>::take(proposal_id, &key).is_some();
 
         // Check if the voter has actually voted on the proposal
         ensure!(removed, Error::<T>::NotVoted);
 
-        // Update the proposal in storage
-        Proposals::<T>::insert(proposal.id, proposal);
         Self::deposit_event(Event::<T>::ProposalVoteUnregistered(proposal_id, key));
         Ok(())
     }
@@ -48,6 +48,25 @@ pub trait WeightInfo {
 	fn refuse_dao_application() -> Weight;
 	fn add_to_whitelist() -> Weight;
 	fn remove_from_whitelist() -> Weight;
+	fn add_batch_proposal(a: u32, ) -> Weight;
+	fn add_treasury_grant_proposal() -> Weight;
+	fn add_cancel_treasury_grant_proposal() -> Weight;
+	fn add_subnet_consensus_change_proposal() -> Weight;
+	fn pause_operation() -> Weight;
+	fn resume_operation() -> Weight;
+	fn add_emergency_pause_proposal() -> Weight;
+	fn add_emergency_resume_proposal() -> Weight;
+	fn add_pause_guardian_proposal() -> Weight;
+	fn renew_whitelist() -> Weight;
+	fn set_subnet_application_cost() -> Weight;
+	fn add_subnet_application() -> Weight;
+	fn set_subnet_whitelisted() -> Weight;
+	fn add_subnet_application_cost_proposal() -> Weight;
+	fn add_subnet_whitelist_proposal() -> Weight;
+	fn veto_enactment() -> Weight;
+	fn add_veto_enactment_proposal() -> Weight;
+	fn add_subnet_founder_transfer_proposal() -> Weight;
+	fn add_override_veto_proposal() -> Weight;
 }
 
 /// Weights for `pallet_governance` using the Substrate node and recommended hardware.
@@ -340,6 +359,121 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(T::DbWeight::get().reads(4_u64))
 			.saturating_add(T::DbWeight::get().writes(2_u64))
 	}
+	/// Hand-written estimate, not benchmarked yet. Regenerate with `make run-benchmarking`.
+	fn add_batch_proposal(a: u32, ) -> Weight {
+		Weight::from_parts(46_022_000, 3569)
+			.saturating_add(Weight::from_parts(55_571_000, 0).saturating_mul(a.into()))
+			.saturating_add(T::DbWeight::get().reads(18_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	/// Hand-written estimate, not benchmarked yet. Regenerate with `make run-benchmarking`.
+	fn add_treasury_grant_proposal() -> Weight {
+		Weight::from_parts(46_912_000, 3569)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Hand-written estimate, not benchmarked yet. Regenerate with `make run-benchmarking`.
+	fn add_cancel_treasury_grant_proposal() -> Weight {
+		Weight::from_parts(45_803_000, 3569)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Hand-written estimate, not benchmarked yet. Regenerate with `make run-benchmarking`.
+	fn add_subnet_consensus_change_proposal() -> Weight {
+		Weight::from_parts(49_217_000, 3569)
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Hand-written estimate, not benchmarked yet. Regenerate with `make run-benchmarking`.
+	fn pause_operation() -> Weight {
+		Weight::from_parts(21_304_000, 1517)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Hand-written estimate, not benchmarked yet. Regenerate with `make run-benchmarking`.
+	fn resume_operation() -> Weight {
+		Weight::from_parts(21_560_000, 3474)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Hand-written estimate, not benchmarked yet. Regenerate with `make run-benchmarking`.
+	fn add_emergency_pause_proposal() -> Weight {
+		Weight::from_parts(42_117_000, 3569)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Hand-written estimate, not benchmarked yet. Regenerate with `make run-benchmarking`.
+	fn add_emergency_resume_proposal() -> Weight {
+		Weight::from_parts(41_893_000, 3569)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Hand-written estimate, not benchmarked yet. Regenerate with `make run-benchmarking`.
+	fn add_pause_guardian_proposal() -> Weight {
+		Weight::from_parts(42_350_000, 3569)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Hand-written estimate, not benchmarked yet. Regenerate with `make run-benchmarking`.
+	fn renew_whitelist() -> Weight {
+		Weight::from_parts(31_482_000, 3507)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Hand-written estimate, not benchmarked yet. Regenerate with `make run-benchmarking`.
+	fn set_subnet_application_cost() -> Weight {
+		Weight::from_parts(14_273_000, 3497)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Hand-written estimate, not benchmarked yet. Regenerate with `make run-benchmarking`.
+	fn add_subnet_application() -> Weight {
+		Weight::from_parts(38_614_000, 3798)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Hand-written estimate, not benchmarked yet. Regenerate with `make run-benchmarking`.
+	fn set_subnet_whitelisted() -> Weight {
+		Weight::from_parts(41_907_000, 3798)
+			.saturating_add(T::DbWeight::get().reads(6_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	/// Hand-written estimate, not benchmarked yet. Regenerate with `make run-benchmarking`.
+	fn add_subnet_application_cost_proposal() -> Weight {
+		Weight::from_parts(32_118_000, 3569)
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Hand-written estimate, not benchmarked yet. Regenerate with `make run-benchmarking`.
+	fn add_subnet_whitelist_proposal() -> Weight {
+		Weight::from_parts(34_506_000, 3798)
+			.saturating_add(T::DbWeight::get().reads(6_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Hand-written estimate, not benchmarked yet. Regenerate with `make run-benchmarking`.
+	fn veto_enactment() -> Weight {
+		Weight::from_parts(20_311_000, 3474)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Hand-written estimate, not benchmarked yet. Regenerate with `make run-benchmarking`.
+	fn add_veto_enactment_proposal() -> Weight {
+		Weight::from_parts(33_204_000, 3579)
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Hand-written estimate, not benchmarked yet. Regenerate with `make run-benchmarking`.
+	fn add_subnet_founder_transfer_proposal() -> Weight {
+		Weight::from_parts(33_000_000, 3720)
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Hand-written estimate, not benchmarked yet. Regenerate with `make run-benchmarking`.
+	fn add_override_veto_proposal() -> Weight {
+		Weight::from_parts(33_287_000, 3583)
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
 }
 
 // For backwards compatibility and tests.
@@ -631,4 +765,119 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().reads(4_u64))
 			.saturating_add(RocksDbWeight::get().writes(2_u64))
 	}
+	/// Hand-written estimate, not benchmarked yet. Regenerate with `make run-benchmarking`.
+	fn add_batch_proposal(a: u32, ) -> Weight {
+		Weight::from_parts(46_022_000, 3569)
+			.saturating_add(Weight::from_parts(55_571_000, 0).saturating_mul(a.into()))
+			.saturating_add(RocksDbWeight::get().reads(18_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	/// Hand-written estimate, not benchmarked yet. Regenerate with `make run-benchmarking`.
+	fn add_treasury_grant_proposal() -> Weight {
+		Weight::from_parts(46_912_000, 3569)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Hand-written estimate, not benchmarked yet. Regenerate with `make run-benchmarking`.
+	fn add_cancel_treasury_grant_proposal() -> Weight {
+		Weight::from_parts(45_803_000, 3569)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Hand-written estimate, not benchmarked yet. Regenerate with `make run-benchmarking`.
+	fn add_subnet_consensus_change_proposal() -> Weight {
+		Weight::from_parts(49_217_000, 3569)
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Hand-written estimate, not benchmarked yet. Regenerate with `make run-benchmarking`.
+	fn pause_operation() -> Weight {
+		Weight::from_parts(21_304_000, 1517)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Hand-written estimate, not benchmarked yet. Regenerate with `make run-benchmarking`.
+	fn resume_operation() -> Weight {
+		Weight::from_parts(21_560_000, 3474)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Hand-written estimate, not benchmarked yet. Regenerate with `make run-benchmarking`.
+	fn add_emergency_pause_proposal() -> Weight {
+		Weight::from_parts(42_117_000, 3569)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Hand-written estimate, not benchmarked yet. Regenerate with `make run-benchmarking`.
+	fn add_emergency_resume_proposal() -> Weight {
+		Weight::from_parts(41_893_000, 3569)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Hand-written estimate, not benchmarked yet. Regenerate with `make run-benchmarking`.
+	fn add_pause_guardian_proposal() -> Weight {
+		Weight::from_parts(42_350_000, 3569)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Hand-written estimate, not benchmarked yet. Regenerate with `make run-benchmarking`.
+	fn renew_whitelist() -> Weight {
+		Weight::from_parts(31_482_000, 3507)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Hand-written estimate, not benchmarked yet. Regenerate with `make run-benchmarking`.
+	fn set_subnet_application_cost() -> Weight {
+		Weight::from_parts(14_273_000, 3497)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Hand-written estimate, not benchmarked yet. Regenerate with `make run-benchmarking`.
+	fn add_subnet_application() -> Weight {
+		Weight::from_parts(38_614_000, 3798)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Hand-written estimate, not benchmarked yet. Regenerate with `make run-benchmarking`.
+	fn set_subnet_whitelisted() -> Weight {
+		Weight::from_parts(41_907_000, 3798)
+			.saturating_add(RocksDbWeight::get().reads(6_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	/// Hand-written estimate, not benchmarked yet. Regenerate with `make run-benchmarking`.
+	fn add_subnet_application_cost_proposal() -> Weight {
+		Weight::from_parts(32_118_000, 3569)
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Hand-written estimate, not benchmarked yet. Regenerate with `make run-benchmarking`.
+	fn add_subnet_whitelist_proposal() -> Weight {
+		Weight::from_parts(34_506_000, 3798)
+			.saturating_add(RocksDbWeight::get().reads(6_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Hand-written estimate, not benchmarked yet. Regenerate with `make run-benchmarking`.
+	fn veto_enactment() -> Weight {
+		Weight::from_parts(20_311_000, 3474)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Hand-written estimate, not benchmarked yet. Regenerate with `make run-benchmarking`.
+	fn add_veto_enactment_proposal() -> Weight {
+		Weight::from_parts(33_204_000, 3579)
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Hand-written estimate, not benchmarked yet. Regenerate with `make run-benchmarking`.
+	fn add_subnet_founder_transfer_proposal() -> Weight {
+		Weight::from_parts(33_000_000, 3720)
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Hand-written estimate, not benchmarked yet. Regenerate with `make run-benchmarking`.
+	fn add_override_veto_proposal() -> Weight {
+		Weight::from_parts(33_287_000, 3583)
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
 }
\ No newline at end of file
@@ -24,7 +24,7 @@ use pallet_grandpa::{
 };
 
 // Governance pallets
-use pallet_governance::{Curator, GeneralSubnetApplicationCost, LegitWhitelist};
+use pallet_governance::GeneralSubnetApplicationCost;
 use pallet_governance_api::GovernanceConfiguration;
 
 // EVM pallets
@@ -84,7 +84,9 @@ use sp_version::NativeVersion;
 use sp_version::RuntimeVersion;
 
 // Chain runtime API
-use chain_runtime_api::{ModuleInfo, ModuleParams, ModuleStats};
+use chain_runtime_api::{
+    ModuleAddress, ModuleInfo, ModuleParams, ModuleStats, PendingSubnetParams,
+};
 
 // Frontier EVM imports
 #[cfg(feature = "testnet")]
@@ -190,11 +192,34 @@ pub mod opaque {
 }
 
 #[cfg(feature = "testnet")]
-pub type Migrations = ();
+pub type Migrations = (
+    pallet_governance::migrations::v3::MigrateToV3<Runtime>, // curator council
+    pallet_governance::migrations::v4::MigrateToV4<Runtime>, // proposal storage redesign
+    pallet_governance::migrations::v5::MigrateToV5<Runtime>, // whitelist expiry
+    pallet_governance::migrations::v6::MigrateToV6<Runtime>, // proposal enactment delay
+    pallet_chain::migrations::v16::MigrateToV16<Runtime>,    // burn refund curve
+    pallet_chain::migrations::v17::MigrateToV17<Runtime>,    // burn destination
+    pallet_chain::migrations::v18::MigrateToV18<Runtime>,    // module heartbeats
+    pallet_chain::migrations::v19::MigrateToV19<Runtime>,    // typed module addresses
+    pallet_chain::migrations::v20::MigrateToV20<Runtime>,    // structured metadata
+    pallet_chain::migrations::v21::MigrateToV21<Runtime>,    // module name index
+    pallet_chain::migrations::v22::MigrateToV22<Runtime>,    // storage deposits
+);
 
 #[cfg(not(feature = "testnet"))]
 pub type Migrations = (
     pallet_emission::migrations::v2::MigrateToV2<Runtime>, // set lower block emission
+    pallet_governance::migrations::v3::MigrateToV3<Runtime>, // curator council
+    pallet_governance::migrations::v4::MigrateToV4<Runtime>, // proposal storage redesign
+    pallet_governance::migrations::v5::MigrateToV5<Runtime>, // whitelist expiry
+    pallet_governance::migrations::v6::MigrateToV6<Runtime>, // proposal enactment delay
+    pallet_chain::migrations::v16::MigrateToV16<Runtime>,  // burn refund curve
+    pallet_chain::migrations::v17::MigrateToV17<Runtime>,  // burn destination
+    pallet_chain::migrations::v18::MigrateToV18<Runtime>,  // module heartbeats
+    pallet_chain::migrations::v19::MigrateToV19<Runtime>,  // typed module addresses
+    pallet_chain::migrations::v20::MigrateToV20<Runtime>,  // structured metadata
+    pallet_chain::migrations::v21::MigrateToV21<Runtime>,  // module name index
+    pallet_chain::migrations::v22::MigrateToV22<Runtime>,  // storage deposits
 );
 
 #[sp_version::runtime_version]
@@ -720,7 +745,7 @@ impl EnsureAddressOrigin<RuntimeOrigin> for EnsureCuratorAddressTruncated {
                     }
 
                     if pallet_governance::RestrictContractDeploy::<Runtime>::get()
-                        && Some(who.clone()) != Some(pallet_governance::Curator::<Runtime>::get())
+                        && !pallet_governance::Pallet::<Runtime>::is_curator(&who)
                     {
                         return Err(RuntimeOrigin::from(RawOrigin::Signed(who)));
                     }
@@ -1053,12 +1078,48 @@ impl_runtime_apis! {
                 },
                 params: ModuleParams {
                     name: params.name,
-                    address: params.address,
+                    address: params.address.map(|address| match address {
+                        pallet_chain::ModuleAddress::Ipv4 { ip, port } => {
+                            ModuleAddress::Ipv4 { ip, port }
+                        }
+                        pallet_chain::ModuleAddress::Ipv6 { ip, port } => {
+                            ModuleAddress::Ipv6 { ip, port }
+                        }
+                        pallet_chain::ModuleAddress::Dns { host, port } => {
+                            ModuleAddress::Dns { host, port }
+                        }
+                        pallet_chain::ModuleAddress::Multiaddr(addr) => {
+                            ModuleAddress::Multiaddr(addr)
+                        }
+                    }),
                     delegation_fee: params.fees.stake_delegation_fee,
-                    metadata: params.metadata,
+                    metadata: params
+                        .metadata
+                        .into_iter()
+                        .map(|(key, value)| (key.name().to_vec(), value.into_inner()))
+                        .collect(),
                 }
             }
         }
+
+        fn get_subnet_generation(netuid: u16) -> u64 {
+            pallet_chain::SubnetGeneration::<Runtime>::get(netuid)
+        }
+
+        fn get_uid_for_name(netuid: u16, name: Vec<u8>) -> Option<u16> {
+            ChainModule::get_uid_for_name(netuid, &name)
+        }
+
+        fn get_pending_subnet_params(netuid: u16) -> Option<PendingSubnetParams> {
+            use parity_scale_codec::Encode;
+
+            pallet_chain::PendingSubnetParams::<Runtime>::get(netuid).map(
+                |(params, apply_block)| PendingSubnetParams {
+                    params: params.encode(),
+                    apply_block,
+                },
+            )
+        }
     }
 
 
@@ -1351,6 +1412,10 @@ impl pallet_emission_api::SubnetEmissionApi<<Runtime as frame_system::Config>::A
         SubnetEmissionModule::get_lowest_emission_netuid(ignore_subnet_immunity)
     }
 
+    fn get_lowest_evictable_netuid() -> Option<u16> {
+        SubnetEmissionModule::get_lowest_evictable_netuid()
+    }
+
     fn set_emission_storage(netuid: u16, emission: u64) {
         SubnetEmissionModule::set_emission_storage(netuid, emission)
     }
@@ -1384,14 +1449,23 @@ impl pallet_emission_api::SubnetEmissionApi<<Runtime as frame_system::Config>::A
         pallet_emission::SubnetConsensusType::<Runtime>::set(netuid, subnet_consensus)
     }
 
+    fn change_subnet_consensus_type(
+        netuid: u16,
+        subnet_consensus: pallet_emission_api::SubnetConsensus,
+    ) {
+        SubnetEmissionModule::change_subnet_consensus_type(netuid, subnet_consensus)
+    }
+
     fn get_weights(netuid: u16, uid: u16) -> Option<Vec<(u16, u16)>> {
         pallet_emission::Weights::<Runtime>::get(netuid, uid)
     }
 
-    fn clear_subnet_includes(netuid: u16) {
-        for storage_type in pallet_emission::SubnetIncludes::all() {
-            storage_type.remove_storage::<Runtime>(netuid);
-        }
+    fn distribute_pending_emission(netuid: u16) {
+        SubnetEmissionModule::distribute_pending_emission(netuid)
+    }
+
+    fn clear_subnet_includes(netuid: u16, limit: u32) -> (u32, bool) {
+        pallet_emission::SubnetIncludes::clear_limited::<Runtime>(netuid, limit)
     }
 
     fn clear_module_includes(
@@ -1471,25 +1545,27 @@ impl pallet_governance_api::GovernanceApi<<Runtime as frame_system::Config>::Acc
     }
 
     fn whitelisted_keys() -> BTreeSet<AccountId> {
-        LegitWhitelist::<Runtime>::iter_keys().collect()
+        GovernanceModule::whitelisted_keys()
     }
 
-    fn get_curator() -> AccountId {
-        Curator::<Runtime>::get()
+    fn is_approved_for_subnet(subnet_id: u16, module_key: &AccountId) -> bool {
+        GovernanceModule::is_approved_for_subnet(subnet_id, module_key)
     }
 
-    fn set_curator(curator: &AccountId) {
-        Curator::<Runtime>::put(curator)
+    fn get_curator_council() -> (BTreeSet<AccountId>, u32) {
+        GovernanceModule::curator_council()
+    }
+
+    fn set_curator_council(members: BTreeSet<AccountId>, threshold: u32) -> DispatchResult {
+        GovernanceModule::set_curator_council(members, threshold)
     }
 
     fn set_general_subnet_application_cost(amount: u64) {
         GeneralSubnetApplicationCost::<Runtime>::put(amount)
     }
 
-    fn clear_subnet_includes(netuid: u16) {
-        for storage_type in pallet_governance::SubnetIncludes::all() {
-            storage_type.remove_storage::<Runtime>(netuid);
-        }
+    fn clear_subnet_includes(netuid: u16, limit: u32) -> (u32, bool) {
+        pallet_governance::SubnetIncludes::clear_limited::<Runtime>(netuid, limit)
     }
 }
 
@@ -2,6 +2,7 @@ use core::marker::PhantomData;
 use sp_core::{hashing::keccak_256, H160};
 use sp_runtime::AccountId32;
 
+use pallet_chain::PausableOperation;
 use pallet_evm::{
     ExitError, IsPrecompileResult, Precompile, PrecompileFailure, PrecompileHandle,
     PrecompileResult, PrecompileSet,
@@ -81,9 +82,11 @@ where
             a if a == hash(ED25519_PRECOMPILE_INDEX) => Some(Ed25519Verify::execute(handle)),
             // Custom precompiles
             a if a == hash(BALANCE_TRANSFER_INDEX) => {
-                Some(BalanceTransferPrecompile::execute(handle))
+                Some(ensure_not_paused().and_then(|_| BalanceTransferPrecompile::execute(handle)))
+            }
+            a if a == hash(STAKING_PRECOMPILE_INDEX) => {
+                Some(ensure_not_paused().and_then(|_| StakingPrecompile::execute(handle)))
             }
-            a if a == hash(STAKING_PRECOMPILE_INDEX) => Some(StakingPrecompile::execute(handle)),
             _ => None,
         }
     }
@@ -105,6 +108,17 @@ where
     }
 }
 
+/// Custom precompiles dispatch into the runtime, so they are halted while the EVM precompiles are
+/// paused.
+fn ensure_not_paused() -> Result<(), PrecompileFailure> {
+    if pallet_chain::Pallet::<crate::Runtime>::is_paused(PausableOperation::EvmPrecompiles) {
+        return Err(PrecompileFailure::Error {
+            exit_status: ExitError::Other("precompiles are paused".into()),
+        });
+    }
+    Ok(())
+}
+
 fn hash(a: u64) -> PrecompileAddress {
     PrecompileAddress::from_low_u64_be(a)
 }
@@ -1,9 +1,11 @@
 use std::u32;
 
 use crate::mock::*;
-use frame_support::assert_ok;
+use frame_support::{assert_err, assert_ok};
 use pallet_chain::*;
+use pallet_governance_api::GovernanceApi;
 use params::burn::GeneralBurnConfiguration;
+use sp_runtime::Percent;
 
 #[test]
 fn module_registration_burn_increases() {
@@ -119,3 +121,54 @@ fn subnet_registration_burn_increases() {
         );
     });
 }
+
+#[test]
+fn module_registration_burn_goes_to_its_destination() {
+    new_test_ext().execute_with(|| {
+        MinimumAllowedStake::<Test>::set(0);
+
+        let netuid = 1;
+        let founder = 0;
+        assert_ok!(register_subnet(founder, netuid));
+
+        let invalid_destination = BurnDestination {
+            treasury: Percent::from_percent(60),
+            founder: Percent::from_percent(50),
+        };
+        assert_err!(
+            GeneralBurnConfiguration::<Test> {
+                destination: invalid_destination,
+                ..ModuleBurnConfig::<Test>::get(netuid)
+            }
+            .apply_module_burn(netuid),
+            Error::<Test>::InvalidBurnDestination
+        );
+
+        ModuleBurnConfig::<Test>::mutate(netuid, |config| {
+            config.destination = BurnDestination {
+                treasury: Percent::from_percent(20),
+                founder: Percent::from_percent(30),
+            };
+            config.refund_curve = Some(BurnRefundCurve {
+                initial_refund: Percent::from_percent(100),
+                decay_period: 100,
+            });
+        });
+        Burn::<Test>::insert(netuid, to_nano(10));
+        add_balance(1, to_nano(10));
+
+        let treasury = Test::get_dao_treasury_address();
+        let treasury_balance = get_balance(treasury);
+        let founder_balance = get_balance(founder);
+        assert_ok!(register_module(netuid, 1, 0, false));
+
+        assert_eq!(get_balance(treasury), treasury_balance + to_nano(2));
+        assert_eq!(get_balance(founder), founder_balance + to_nano(3));
+        // Only the destroyed part of the burn can be refunded, and it stays held on the payer
+        assert_eq!(
+            RegistrationBurnEscrow::<Test>::get(netuid, 1),
+            Some((1, to_nano(5)))
+        );
+        assert_eq!(Balances::reserved_balance(1), to_nano(5));
+    });
+}
@@ -1,10 +1,14 @@
 use std::collections::BTreeSet;
 
 use crate::mock::*;
-use frame_support::{assert_err, assert_noop, dispatch::DispatchResult};
+use frame_support::{
+    assert_err, assert_noop, dispatch::DispatchResult, storage::with_storage_layer, weights::Weight,
+};
 use pallet_emission::SubnetConsensusType;
 use pallet_emission_api::SubnetConsensus;
 use pallet_chain::*;
+use parity_scale_codec::Encode;
+use sp_core::{hashing::blake2_256, H256};
 use sp_runtime::Percent;
 
 #[test]
@@ -18,13 +22,13 @@ fn module_is_registered_correctly() {
 
         let network = format!("test{netuid}").as_bytes().to_vec();
         let name = b"module".to_vec();
-        let address = "0.0.0.0:30333".as_bytes().to_vec();
+        let address = test_address(30333);
         let network_string = String::from_utf8(network.clone()).expect("Invalid UTF-8");
         assert_ok!(register_named_subnet(0, 0, network_string));
         // Direct the rootnet netuid to something else than 0
         SubnetConsensusType::<Test>::insert(1, SubnetConsensus::Root);
         assert_noop!(
-            ChainMod::do_register(get_origin(0), network, name, address, 0, None),
+            ChainMod::do_register(get_origin(0), network, name, address, 0, vec![]),
             Error::<Test>::NotEnoughBalanceToRegister
         );
         Burn::<Test>::insert(netuid, 0);
@@ -228,6 +232,35 @@ fn deregister_globally_when_global_limit_is_reached() {
     });
 }
 
+#[test]
+fn global_deregistration_follows_the_subnet_pruning_strategy() {
+    new_test_ext().execute_with(|| {
+        zero_min_burn();
+        SubnetImmunityPeriod::<Test>::set(0);
+
+        MaxAllowedModules::<Test>::set(4);
+
+        assert_ok!(register_module(0, 0, to_nano(50), true));
+        assert_ok!(register_module(1, 1, to_nano(5), true));
+        assert_ok!(register_module(2, 2, to_nano(20), true));
+        step_block(1);
+        assert_ok!(register_module(2, 3, to_nano(1), false));
+
+        MaxAllowedUids::<Test>::set(0, 2);
+        SubnetPruningStrategy::<Test>::insert(1, PruningStrategy::NoEviction);
+        SubnetPruningStrategy::<Test>::insert(2, PruningStrategy::OldestFirst);
+
+        assert_ok!(register_module(0, 4, to_nano(15), false));
+
+        // Subnet 1 has the lowest emission but doesn't evict modules, and subnet 2 evicts its
+        // oldest module rather than the one without emission
+        assert!(ChainMod::key_registered(1, &1));
+        assert!(!ChainMod::key_registered(2, &2));
+        assert!(ChainMod::key_registered(2, &3));
+        assert!(ChainMod::key_registered(0, &4));
+    });
+}
+
 #[test]
 fn deregister_subnet_with_dangling_keys() {
     new_test_ext().execute_with(|| {
@@ -247,6 +280,7 @@ fn deregister_subnet_with_dangling_keys() {
 
         let netuid = ChainMod::get_netuid_for_name("test1".as_bytes()).unwrap();
         ChainMod::remove_subnet(netuid);
+        step_block(1);
 
         assert_eq!(StakeFrom::<Test>::get(key_b, key_b), 0);
         assert_eq!(ChainMod::get_balance(&key_b), stake_b + 1)
@@ -256,7 +290,7 @@ fn deregister_subnet_with_dangling_keys() {
 mod module_validation {
     use super::*;
 
-    fn register_custom(netuid: u16, key: u32, name: &[u8], addr: &[u8]) -> DispatchResult {
+    fn register_custom(netuid: u16, key: u32, name: &[u8], addr: ModuleAddress) -> DispatchResult {
         let network: Vec<u8> = format!("test{netuid}").as_bytes().to_vec();
 
         let origin = get_origin(key);
@@ -267,27 +301,61 @@ mod module_validation {
 
         // make sure there is some balance
         add_balance(key, 2);
-        let _ = ChainMod::register_subnet(origin.clone(), network.clone(), None);
-        ChainMod::register(origin, network, name.to_vec(), addr.to_vec(), key, None)
+        let _ = ChainMod::register_subnet(origin.clone(), network.clone(), vec![]);
+        ChainMod::register(origin, network, name.to_vec(), addr, key, vec![])
     }
 
-    fn test_validation_cases(f: impl Fn(&[u8], &[u8]) -> DispatchResult) {
-        assert_err!(f(b"", b""), Error::<Test>::InvalidModuleName);
-        assert_err!(f(b"o", b""), Error::<Test>::ModuleNameTooShort);
+    fn test_validation_cases(f: impl Fn(&[u8], ModuleAddress) -> DispatchResult) {
+        let dns = |host: &[u8]| ModuleAddress::Dns {
+            host: host.to_vec(),
+            port: 30333,
+        };
+
+        assert_err!(f(b"", test_address(1)), Error::<Test>::InvalidModuleName);
+        assert_err!(f(b"o", test_address(1)), Error::<Test>::ModuleNameTooShort);
         assert_err!(
-            f("o".repeat(100).as_bytes(), b""),
+            f("o".repeat(100).as_bytes(), test_address(1)),
             Error::<Test>::ModuleNameTooLong
         );
-        assert_err!(f(b"\xc3\x28", b""), Error::<Test>::InvalidModuleName);
+        assert_err!(
+            f(b"\xc3\x28", test_address(1)),
+            Error::<Test>::InvalidModuleName
+        );
 
-        assert_err!(f(b"test", b""), Error::<Test>::InvalidModuleAddress);
         assert_err!(
-            f(b"test", "o".repeat(100).as_bytes()),
+            f(b"test", test_address(0)),
+            Error::<Test>::InvalidModuleAddress
+        );
+        assert_err!(f(b"test", dns(b"")), Error::<Test>::InvalidModuleAddress);
+        assert_err!(
+            f(b"test", dns(b"-abc.com")),
+            Error::<Test>::InvalidModuleAddress
+        );
+        assert_err!(
+            f(b"test", dns(b"a..com")),
+            Error::<Test>::InvalidModuleAddress
+        );
+        assert_err!(
+            f(b"test", dns("o".repeat(300).as_bytes())),
+            Error::<Test>::ModuleAddressTooLong
+        );
+        assert_err!(
+            f(b"test", ModuleAddress::Multiaddr(b"ip4/1.2.3.4".to_vec())),
+            Error::<Test>::InvalidModuleAddress
+        );
+        assert_err!(
+            f(b"test", ModuleAddress::Multiaddr(b"/ip4//tcp".to_vec())),
+            Error::<Test>::InvalidModuleAddress
+        );
+        assert_err!(
+            f(
+                b"test",
+                ModuleAddress::Multiaddr(format!("/dns4/{}", "o".repeat(300)).into_bytes())
+            ),
             Error::<Test>::ModuleAddressTooLong
         );
-        assert_err!(f(b"test", b"\xc3\x28"), Error::<Test>::InvalidModuleAddress);
 
-        assert_ok!(f(b"test", b"abc"));
+        assert_ok!(f(b"test", dns(b"abc.example.com")));
     }
 
     #[test]
@@ -299,7 +367,7 @@ mod module_validation {
             test_validation_cases(|name, addr| register_custom(0, 0, name, addr));
 
             assert_err!(
-                register_custom(0, 1, b"test", b"0.0.0.0:1"),
+                register_custom(0, 1, b"test", test_address(1)),
                 Error::<Test>::ModuleNameAlreadyExists
             );
         });
@@ -315,50 +383,50 @@ mod module_validation {
             zero_min_burn();
             MinimumAllowedStake::<Test>::set(0);
 
-            assert_ok!(register_custom(subnet, key_0, b"test", b"0.0.0.0:1"));
+            assert_ok!(register_custom(subnet, key_0, b"test", test_address(1)));
 
             test_validation_cases(|name, addr| {
                 ChainMod::update_module(
                     origin_0.clone(),
                     subnet,
                     name.to_vec(),
-                    addr.to_vec(),
-                    None, // stake_delegation_fee
-                    None, // validator_weight_fee
-                    None, // metadata
+                    addr,
+                    None,   // stake_delegation_fee
+                    None,   // validator_weight_fee
+                    vec![], // metadata
                 )
             });
 
             let key_1 = 1;
             let origin_1 = get_origin(key_1);
-            assert_ok!(register_custom(0, key_1, b"test2", b"0.0.0.0:2"));
+            assert_ok!(register_custom(0, key_1, b"test2", test_address(2)));
 
             let stake_fee = Percent::from_percent(5);
             let weight_fee = Percent::from_percent(5);
 
-            let update_module = |name: &[u8], addr: &[u8]| {
+            let update_module = |name: &[u8], addr: ModuleAddress| {
                 ChainMod::update_module(
                     origin_1.clone(),
                     subnet,
                     name.to_vec(),
-                    addr.to_vec(),
+                    addr,
                     Some(stake_fee),
                     Some(weight_fee),
-                    None, // metadata
+                    vec![], // metadata
                 )
             };
 
             assert_err!(
-                update_module(b"test", b""),
+                update_module(b"test", test_address(1)),
                 Error::<Test>::ModuleNameAlreadyExists
             );
-            assert_ok!(update_module(b"test2", b"0.0.0.0:2"));
-            assert_ok!(update_module(b"test3", b"0.0.0.0:3"));
+            assert_ok!(update_module(b"test2", test_address(2)));
+            assert_ok!(update_module(b"test3", test_address(3)));
 
             let uid = ChainMod::get_uid_for_key(subnet, &key_1).unwrap();
             let params = ChainMod::module_params(0, &key_1, uid);
             assert_eq!(params.name, b"test3");
-            assert_eq!(params.address, b"0.0.0.0:3");
+            assert_eq!(params.address, Some(test_address(3)));
 
             // Set higher minimum fees
             MinFees::<Test>::put(MinimumFees {
@@ -368,7 +436,7 @@ mod module_validation {
 
             // Should fail now because fees are too low
             assert_err!(
-                update_module(b"test3", b"0.0.0.0:3"),
+                update_module(b"test3", test_address(3)),
                 Error::<Test>::InvalidMinDelegationFee
             );
 
@@ -377,10 +445,10 @@ mod module_validation {
                 origin_1.clone(),
                 subnet,
                 b"test3".to_vec(),
-                b"0.0.0.0:3".to_vec(),
+                test_address(3),
                 Some(Percent::from_percent(10)), // valid stake_delegation_fee
                 Some(Percent::from_percent(10)), // valid validator_weight_fee
-                None,                            // metadata
+                vec![],                          // metadata
             ));
 
             // Test updating only stake delegation fee
@@ -388,10 +456,10 @@ mod module_validation {
                 origin_1.clone(),
                 subnet,
                 b"test3".to_vec(),
-                b"0.0.0.0:3".to_vec(),
+                test_address(3),
                 Some(Percent::from_percent(15)), // update only stake fee
                 None,                            // keep existing weight fee
-                None,
+                vec![],
             ));
 
             // Test updating only validator weight fee
@@ -399,13 +467,47 @@ mod module_validation {
                 origin_1.clone(),
                 subnet,
                 b"test3".to_vec(),
-                b"0.0.0.0:3".to_vec(),
+                test_address(3),
                 None,                            // keep existing stake fee
                 Some(Percent::from_percent(15)), // update only weight fee
-                None,
+                vec![],
             ));
         });
     }
+
+    #[test]
+    fn parses_legacy_addresses_best_effort() {
+        let parse = |address: &str| ModuleAddress::from_legacy(address.as_bytes());
+        let dns = |host: &str, port| ModuleAddress::Dns {
+            host: host.as_bytes().to_vec(),
+            port,
+        };
+
+        assert_eq!(parse("0.0.0.0:30333"), Some(test_address(30333)));
+        assert_eq!(
+            parse(" [::1]:8080 "),
+            Some(ModuleAddress::Ipv6 {
+                ip: std::net::Ipv6Addr::LOCALHOST.octets(),
+                port: 8080
+            })
+        );
+        assert_eq!(parse("example.com:9000"), Some(dns("example.com", 9000)));
+        assert_eq!(
+            parse("https://example.com/api"),
+            Some(dns("example.com", 443))
+        );
+        assert_eq!(
+            parse("/dns4/example.com/tcp/30333"),
+            Some(ModuleAddress::Multiaddr(
+                b"/dns4/example.com/tcp/30333".to_vec()
+            ))
+        );
+
+        assert_eq!(parse("example.com"), None);
+        assert_eq!(parse("0.0.0.0:0"), None);
+        assert_eq!(parse("my module:80"), None);
+        assert_eq!(parse(""), None);
+    }
 }
 mod subnet_validation {
     use super::*;
@@ -415,20 +517,20 @@ mod subnet_validation {
         new_test_ext().execute_with(|| {
             zero_min_burn();
 
-            let address = b"0.0.0.0".to_vec();
+            let address = test_address(30333);
             let module_name = b"test".to_vec();
 
             let register_subnet = |key, name: Vec<u8>| {
                 add_balance(key, 1);
 
-                ChainMod::register_subnet(get_origin(key), name.clone(), None)?;
+                ChainMod::register_subnet(get_origin(key), name.clone(), vec![])?;
                 ChainMod::register(
                     get_origin(key),
                     name,
                     module_name.clone(),
                     address.clone(),
                     key,
-                    None,
+                    vec![],
                 )
             };
 
@@ -482,14 +584,14 @@ fn new_subnet_reutilized_removed_netuid_if_total_is_bigger_than_removed() {
         SubnetGaps::<Test>::set(BTreeSet::from([5]));
 
         ChainMod::add_balance_to_account(&0, SubnetBurn::<Test>::get() + 1 + to_nano(10));
-        let _ = ChainMod::register_subnet(get_origin(0), b"test".to_vec(), None);
+        let _ = ChainMod::register_subnet(get_origin(0), b"test".to_vec(), vec![]);
         ChainMod::register(
             get_origin(0),
             b"test".to_vec(),
             b"test".to_vec(),
             b"test".to_vec(),
             0,
-            None,
+            vec![],
         )
         .unwrap();
 
@@ -512,14 +614,14 @@ fn new_subnet_does_not_reuse_removed_netuid_if_total_is_smaller_than_removed() {
         SubnetGaps::<Test>::set(BTreeSet::from([7]));
 
         ChainMod::add_balance_to_account(&0, SubnetBurn::<Test>::get() + 1 + to_nano(10));
-        let _ = ChainMod::register_subnet(get_origin(0), b"test".to_vec(), None);
+        let _ = ChainMod::register_subnet(get_origin(0), b"test".to_vec(), vec![]);
         ChainMod::register(
             get_origin(0),
             b"test".to_vec(),
             b"test".to_vec(),
             b"test".to_vec(),
             0,
-            None,
+            vec![],
         )
         .unwrap();
 
@@ -545,11 +647,11 @@ fn new_subnets_on_removed_uids_register_modules_to_the_correct_netuids() {
 
         let network = format!("test{netuid}").as_bytes().to_vec();
         let name = format!("module{key}").as_bytes().to_vec();
-        let address = "0.0.0.0:30333".as_bytes().to_vec();
+        let address = test_address(30333);
 
         ChainMod::add_balance_to_account(&key, stake + SubnetBurn::<Test>::get() + 1);
-        let _ = ChainMod::register_subnet(origin.clone(), network.clone(), None);
-        ChainMod::register(origin, network.clone(), name, address, key, None).unwrap();
+        let _ = ChainMod::register_subnet(origin.clone(), network.clone(), vec![]);
+        ChainMod::register(origin, network.clone(), name, address, key, vec![]).unwrap();
 
         let netuid = ChainMod::get_netuid_for_name(&network).unwrap();
         let uid = pallet_chain::Uids::<Test>::get(netuid, key).unwrap();
@@ -571,6 +673,7 @@ fn new_subnets_on_removed_uids_register_modules_to_the_correct_netuids() {
         };
 
         MaxAllowedSubnets::<Test>::put(4);
+        NetuidReuseCooldown::<Test>::set(0);
         register_module(0, 0, to_nano(10_000));
 
         register_module(1, 0, to_nano(10));
@@ -578,21 +681,32 @@ fn new_subnets_on_removed_uids_register_modules_to_the_correct_netuids() {
         register_module(3, 2, to_nano(1));
         assert_subnets!(&[(1, "test1"), (2, "test2"), (3, "test3")]);
 
+        // Removed netuids are only reused once their storage is cleaned up
         register_module(4, 3, to_nano(15));
-        assert_subnets!(&[(1, "test1"), (2, "test2"), (3, "test4")]);
+        assert_subnets!(&[(1, "test1"), (2, "test2"), (4, "test4")]);
 
         register_module(5, 4, to_nano(20));
-        assert_subnets!(&[(1, "test1"), (2, "test5"), (3, "test4")]);
+        assert_subnets!(&[(1, "test1"), (4, "test4"), (5, "test5")]);
 
         add_balance(0, to_nano(50));
         add_emission(1, 0, to_nano(10));
 
         register_module(6, 5, to_nano(17));
-        assert_subnets!(&[(1, "test1"), (2, "test5"), (3, "test6")]);
+        assert_subnets!(&[(1, "test1"), (5, "test5"), (6, "test6")]);
 
         assert_eq!(N::<Test>::get(1), 1);
+        assert_eq!(N::<Test>::get(5), 1);
+        assert_eq!(N::<Test>::get(6), 1);
+        assert_eq!(TombstonedSubnets::<Test>::get(), BTreeSet::from([2, 3, 4]));
+
+        ChainMod::cleanup_tombstoned_subnets(Weight::MAX);
+        assert!(TombstonedSubnets::<Test>::get().is_empty());
+        assert_eq!(SubnetGaps::<Test>::get(), BTreeSet::from([2, 3, 4]));
+
+        register_module(7, 6, to_nano(25));
+        assert_subnets!(&[(1, "test1"), (2, "test7"), (5, "test5")]);
         assert_eq!(N::<Test>::get(2), 1);
-        assert_eq!(N::<Test>::get(3), 1);
+        assert_eq!(SubnetGaps::<Test>::get(), BTreeSet::from([3, 4]));
     });
 }
 
@@ -603,22 +717,825 @@ fn test_subnet_immunity() {
         MaxAllowedSubnets::<Test>::set(1);
 
         ChainMod::add_balance_to_account(&0, SubnetBurn::<Test>::get());
-        let _ = ChainMod::register_subnet(get_origin(0), b"net1".to_vec(), None);
+        let _ = ChainMod::register_subnet(get_origin(0), b"net1".to_vec(), vec![]);
         assert_ok!(ChainMod::register(
             get_origin(0),
             b"net1".to_vec(),
             b"mod1".to_vec(),
             b"127.0.0.1".to_vec(),
             0,
-            None,
+            vec![],
         ));
         ChainMod::increase_stake(&0, &0, 100000000000);
 
         ChainMod::add_balance_to_account(&1, 100000000001 + SubnetBurn::<Test>::get());
 
         assert_err!(
-            ChainMod::register_subnet(get_origin(1), b"net2".to_vec(), None),
+            ChainMod::register_subnet(get_origin(1), b"net2".to_vec(), vec![]),
             sp_runtime::DispatchError::Other("No valid netuid to deregister")
         );
     });
 }
+
+fn registration_commitment(
+    key: AccountId,
+    netuid: u16,
+    module_key: AccountId,
+    salt: [u8; 32],
+) -> H256 {
+    let name = b"module".to_vec();
+    let address = test_address(30333);
+    let metadata: MetadataChanges = vec![];
+    H256(blake2_256(
+        &(key, netuid, &name, &address, module_key, &metadata, salt).encode(),
+    ))
+}
+
+fn reveal_registration(
+    key: AccountId,
+    netuid: u16,
+    module_key: AccountId,
+    salt: [u8; 32],
+) -> DispatchResult {
+    ChainMod::reveal_registration(
+        get_origin(key),
+        netuid,
+        b"module".to_vec(),
+        test_address(30333),
+        module_key,
+        vec![],
+        salt,
+    )
+}
+
+#[test]
+fn commit_reveal_registers_module() {
+    new_test_ext().execute_with(|| {
+        let netuid = 0;
+        assert_ok!(register_subnet(0, netuid));
+        // Direct the rootnet netuid to something else than 0
+        SubnetConsensusType::<Test>::insert(1, SubnetConsensus::Root);
+        Burn::<Test>::insert(netuid, to_nano(10));
+        add_balance(0, to_nano(20));
+
+        let salt = [1; 32];
+        let commitment = registration_commitment(0, netuid, 1, salt);
+        assert_ok!(ChainMod::commit_registration(
+            get_origin(0),
+            netuid,
+            commitment
+        ));
+        assert_eq!(get_balance(0), to_nano(10));
+        assert_noop!(
+            ChainMod::commit_registration(get_origin(0), netuid, commitment),
+            Error::<Test>::RegistrationCommitAlreadyExists
+        );
+
+        assert_noop!(
+            reveal_registration(0, netuid, 1, salt),
+            Error::<Test>::RegistrationRevealTooEarly
+        );
+
+        step_block(1);
+
+        assert_noop!(
+            reveal_registration(0, netuid, 1, [2; 32]),
+            Error::<Test>::RegistrationCommitNotFound
+        );
+        assert_noop!(
+            reveal_registration(1, netuid, 1, salt),
+            Error::<Test>::RegistrationCommitNotFound
+        );
+
+        assert_ok!(reveal_registration(0, netuid, 1, salt));
+        assert!(ChainMod::key_registered(netuid, &1));
+        assert_eq!(get_balance(0), to_nano(10));
+        assert!(!RegistrationCommits::<Test>::contains_key(commitment));
+        assert_eq!(RegistrationCommitExpiries::<Test>::iter().count(), 0);
+    });
+}
+
+#[test]
+fn unrevealed_registration_commit_is_refunded_minus_fee() {
+    new_test_ext().execute_with(|| {
+        let netuid = 0;
+        assert_ok!(register_subnet(0, netuid));
+        // Direct the rootnet netuid to something else than 0
+        SubnetConsensusType::<Test>::insert(1, SubnetConsensus::Root);
+        Burn::<Test>::insert(netuid, to_nano(10));
+        RegistrationRevealPeriod::<Test>::set(5);
+        RegistrationCommitFee::<Test>::set(Percent::from_percent(10));
+        add_balance(0, to_nano(20));
+
+        let commitment = registration_commitment(0, netuid, 1, [1; 32]);
+        assert_ok!(ChainMod::commit_registration(
+            get_origin(0),
+            netuid,
+            commitment
+        ));
+
+        step_block(5);
+        assert!(RegistrationCommits::<Test>::contains_key(commitment));
+        assert_eq!(get_balance(0), to_nano(10));
+        assert_eq!(Balances::reserved_balance(0), to_nano(10));
+
+        step_block(1);
+        assert!(!RegistrationCommits::<Test>::contains_key(commitment));
+        assert_eq!(get_balance(0), to_nano(19));
+        assert_eq!(Balances::reserved_balance(0), 0);
+        assert_noop!(
+            reveal_registration(0, netuid, 1, [1; 32]),
+            Error::<Test>::RegistrationCommitNotFound
+        );
+        assert!(!ChainMod::key_registered(netuid, &1));
+    });
+}
+
+#[test]
+fn second_commit_on_the_same_name_does_not_take_it() {
+    new_test_ext().execute_with(|| {
+        let netuid = 0;
+        assert_ok!(register_subnet(0, netuid));
+        // Direct the rootnet netuid to something else than 0
+        SubnetConsensusType::<Test>::insert(1, SubnetConsensus::Root);
+        Burn::<Test>::insert(netuid, to_nano(10));
+        add_balance(0, to_nano(20));
+        add_balance(2, to_nano(20));
+
+        // The commitments don't disclose the name, so neither blocks the other
+        let commitment = registration_commitment(0, netuid, 1, [1; 32]);
+        let competing = registration_commitment(2, netuid, 3, [2; 32]);
+        assert_ok!(ChainMod::commit_registration(
+            get_origin(0),
+            netuid,
+            commitment
+        ));
+        assert_ok!(ChainMod::commit_registration(
+            get_origin(2),
+            netuid,
+            competing
+        ));
+
+        step_block(1);
+        assert_ok!(reveal_registration(0, netuid, 1, [1; 32]));
+        assert_ok!(reveal_registration(2, netuid, 3, [2; 32]));
+
+        let uid = ChainMod::get_uid_for_key(netuid, &1).unwrap();
+        assert_eq!(ChainMod::get_uid_for_name(netuid, b"module"), Some(uid));
+        assert!(!ChainMod::key_registered(netuid, &3));
+        assert_eq!(get_balance(2), to_nano(20));
+        System::assert_last_event(RuntimeEvent::ChainMod(Event::RegistrationCommitRefunded(
+            2,
+            competing,
+            to_nano(10),
+        )));
+    });
+}
+
+#[test]
+fn registration_reveal_losing_its_name_is_refunded() {
+    new_test_ext().execute_with(|| {
+        let netuid = 0;
+        assert_ok!(register_subnet(0, netuid));
+        // Direct the rootnet netuid to something else than 0
+        SubnetConsensusType::<Test>::insert(1, SubnetConsensus::Root);
+        Burn::<Test>::insert(netuid, 0);
+        add_balance(2, to_nano(1));
+        assert_ok!(ChainMod::register(
+            get_origin(2),
+            b"test0".to_vec(),
+            b"module".to_vec(),
+            test_address(30333),
+            2,
+            vec![]
+        ));
+
+        Burn::<Test>::insert(netuid, to_nano(10));
+        add_balance(0, to_nano(20));
+        let commitment = registration_commitment(0, netuid, 1, [1; 32]);
+        assert_ok!(ChainMod::commit_registration(
+            get_origin(0),
+            netuid,
+            commitment
+        ));
+        assert_eq!(get_balance(0), to_nano(10));
+
+        step_block(1);
+        assert_ok!(reveal_registration(0, netuid, 1, [1; 32]));
+        assert!(!ChainMod::key_registered(netuid, &1));
+        assert_eq!(get_balance(0), to_nano(20));
+        assert!(!RegistrationCommits::<Test>::contains_key(commitment));
+    });
+}
+
+#[test]
+fn registration_mode_restricts_who_can_register() {
+    new_test_ext().execute_with(|| {
+        zero_min_burn();
+        MinimumAllowedStake::<Test>::set(0);
+
+        let netuid = 0;
+        assert_ok!(register_module(netuid, 0, 0, false));
+
+        update_params!(netuid => { registration_mode: RegistrationMode::Allowlist });
+        assert_err!(
+            register_module(netuid, 1, 0, false),
+            Error::<Test>::ModuleNotAllowlisted
+        );
+
+        assert_noop!(
+            ChainMod::add_to_allowlist(get_origin(1), netuid, 1),
+            Error::<Test>::NotFounder
+        );
+        assert_ok!(ChainMod::add_to_allowlist(get_origin(0), netuid, 1));
+        assert_noop!(
+            ChainMod::add_to_allowlist(get_origin(0), netuid, 1),
+            Error::<Test>::ModuleAlreadyAllowlisted
+        );
+        assert_ok!(register_module(netuid, 1, 0, false));
+
+        // Modules already registered are kept when taken off the allowlist
+        assert_ok!(ChainMod::remove_from_allowlist(get_origin(0), netuid, 1));
+        assert!(ChainMod::key_registered(netuid, &1));
+        assert_noop!(
+            ChainMod::remove_from_allowlist(get_origin(0), netuid, 1),
+            Error::<Test>::ModuleNotAllowlisted
+        );
+
+        update_params!(netuid => { registration_mode: RegistrationMode::FounderApproval });
+        assert_err!(
+            register_module(netuid, 2, 0, false),
+            Error::<Test>::ModuleRegistrationNotApproved
+        );
+        assert_ok!(ChainMod::register(
+            get_origin(0),
+            b"test0".to_vec(),
+            b"module2".to_vec(),
+            test_address(30333),
+            2,
+            vec![],
+        ));
+        assert!(ChainMod::key_registered(netuid, &2));
+
+        // Approved module keys register on their own, using the approval up
+        assert_noop!(
+            ChainMod::approve_module_registration(get_origin(1), netuid, 3),
+            Error::<Test>::NotFounder
+        );
+        assert_ok!(ChainMod::approve_module_registration(
+            get_origin(0),
+            netuid,
+            3
+        ));
+        assert_noop!(
+            ChainMod::approve_module_registration(get_origin(0), netuid, 3),
+            Error::<Test>::ModuleRegistrationAlreadyApproved
+        );
+        assert_ok!(register_module(netuid, 3, 0, false));
+        assert!(ChainMod::key_registered(netuid, &3));
+        assert!(!ModuleRegistrationApprovals::<Test>::contains_key(
+            netuid, 3
+        ));
+
+        assert_ok!(ChainMod::approve_module_registration(
+            get_origin(0),
+            netuid,
+            4
+        ));
+        assert_ok!(ChainMod::revoke_module_registration_approval(
+            get_origin(0),
+            netuid,
+            4
+        ));
+        assert_noop!(
+            ChainMod::revoke_module_registration_approval(get_origin(0), netuid, 4),
+            Error::<Test>::ModuleRegistrationNotApproved
+        );
+        assert_err!(
+            register_module(netuid, 4, 0, false),
+            Error::<Test>::ModuleRegistrationNotApproved
+        );
+    });
+}
+
+#[test]
+fn pruning_strategy_decides_evicted_module() {
+    new_test_ext().execute_with(|| {
+        zero_min_burn();
+        MinimumAllowedStake::<Test>::set(0);
+
+        let netuid = 0;
+        assert_ok!(register_module(netuid, 0, to_nano(10), false));
+        step_block(1);
+        assert_ok!(register_module(netuid, 1, to_nano(5), false));
+        MaxAllowedUids::<Test>::set(netuid, 2);
+
+        update_params!(netuid => { pruning_strategy: PruningStrategy::LowestStake });
+        assert_ok!(register_module(netuid, 2, to_nano(20), false));
+        assert!(ChainMod::key_registered(netuid, &0));
+        assert!(!ChainMod::key_registered(netuid, &1));
+
+        update_params!(netuid => { pruning_strategy: PruningStrategy::OldestFirst });
+        step_block(1);
+        assert_ok!(register_module(netuid, 3, to_nano(30), false));
+        assert!(!ChainMod::key_registered(netuid, &0));
+        assert!(ChainMod::key_registered(netuid, &2));
+
+        update_params!(netuid => { pruning_strategy: PruningStrategy::NoEviction });
+        assert_err!(
+            register_module(netuid, 4, to_nano(40), false),
+            Error::<Test>::SubnetIsFull
+        );
+        assert_eq!(N::<Test>::get(netuid), 2);
+    });
+}
+
+#[test]
+fn heartbeats_keep_modules_from_being_pruned() {
+    new_test_ext().execute_with(|| {
+        zero_min_burn();
+        MinimumAllowedStake::<Test>::set(0);
+
+        let netuid = 0;
+        assert_ok!(register_module(netuid, 0, to_nano(5), false));
+        assert_ok!(register_module(netuid, 1, to_nano(10), false));
+        MaxAllowedUids::<Test>::set(netuid, 2);
+        update_params!(netuid => {
+            tempo: 25,
+            max_weight_age: 50,
+            pruning_strategy: PruningStrategy::LowestStake,
+            activity_source: ActivitySource::Heartbeats
+        });
+
+        // Modules can send one heartbeat per epoch
+        step_block(1);
+        assert_ok!(ChainMod::heartbeat(get_origin(0), netuid));
+        assert_err!(
+            ChainMod::heartbeat(get_origin(0), netuid),
+            Error::<Test>::HeartbeatTooSoon
+        );
+        assert_err!(
+            ChainMod::heartbeat(get_origin(2), netuid),
+            Error::<Test>::ModuleDoesNotExist
+        );
+
+        step_block(30);
+        assert_ok!(ChainMod::heartbeat(get_origin(0), netuid));
+
+        // The module without heartbeats is past the activity cutoff, so it goes first even
+        // though it has more stake
+        step_block(30);
+        assert_ok!(register_module(netuid, 2, to_nano(20), false));
+        assert!(ChainMod::key_registered(netuid, &0));
+        assert!(!ChainMod::key_registered(netuid, &1));
+    });
+}
+
+#[test]
+fn register_multiple_registers_on_every_subnet_or_none() {
+    new_test_ext().execute_with(|| {
+        MinimumAllowedStake::<Test>::set(0);
+        // Direct the rootnet netuid to something else than the test subnets
+        SubnetConsensusType::<Test>::insert(10, SubnetConsensus::Root);
+
+        for netuid in 0..3 {
+            assert_ok!(register_subnet(0, netuid));
+            Burn::<Test>::insert(netuid, to_nano(10));
+        }
+        add_balance(0, to_nano(25));
+
+        let registrations = |netuids: &[u16]| {
+            let registrations: Vec<_> = netuids
+                .iter()
+                .map(|netuid| {
+                    (
+                        format!("test{netuid}").as_bytes().to_vec(),
+                        b"module".to_vec(),
+                        test_address(30333),
+                        vec![],
+                    )
+                })
+                .collect();
+            MultipleRegistrations::truncate_from(registrations)
+        };
+
+        assert_noop!(
+            ChainMod::register_multiple(get_origin(0), 1, registrations(&[])),
+            Error::<Test>::EmptyRegistrations
+        );
+        assert_noop!(
+            ChainMod::register_multiple(get_origin(0), 1, registrations(&[0, 1, 2])),
+            Error::<Test>::NotEnoughBalanceToRegister
+        );
+
+        // The second registration fails, so the first one is rolled back too, as extrinsics run in
+        // their own storage layer
+        add_balance(1, to_nano(10));
+        assert_ok!(register_module(1, 1, 0, false));
+        assert_noop!(
+            with_storage_layer(|| ChainMod::register_multiple(
+                get_origin(0),
+                1,
+                registrations(&[0, 1])
+            )),
+            Error::<Test>::KeyAlreadyRegistered
+        );
+        assert!(!ChainMod::key_registered(0, &1));
+
+        MaxRegistrationsPerBlock::<Test>::set(2);
+        assert_noop!(
+            ChainMod::register_multiple(get_origin(0), 2, registrations(&[0, 2])),
+            Error::<Test>::TooManyRegistrationsPerBlock
+        );
+
+        MaxRegistrationsPerBlock::<Test>::set(3);
+        assert_ok!(ChainMod::register_multiple(
+            get_origin(0),
+            2,
+            registrations(&[0, 2])
+        ));
+        assert!(ChainMod::key_registered(0, &2));
+        assert!(ChainMod::key_registered(2, &2));
+        assert_eq!(get_balance(0), to_nano(5));
+    });
+}
+
+#[test]
+fn voluntary_deregistration_refunds_part_of_the_burn() {
+    new_test_ext().execute_with(|| {
+        MinimumAllowedStake::<Test>::set(0);
+
+        let netuid = 1;
+        assert_ok!(register_subnet(0, netuid));
+        ModuleBurnConfig::<Test>::mutate(netuid, |config| {
+            config.refund_curve = Some(BurnRefundCurve {
+                initial_refund: Percent::from_percent(50),
+                decay_period: 100,
+            });
+        });
+        update_params!(netuid => { pruning_strategy: PruningStrategy::OldestFirst });
+
+        for key in 1..=3 {
+            Burn::<Test>::insert(netuid, to_nano(10));
+            add_balance(key, to_nano(10));
+            assert_ok!(register_module(netuid, key, 0, false));
+            assert_eq!(
+                RegistrationBurnEscrow::<Test>::get(netuid, key),
+                Some((key, to_nano(5)))
+            );
+            step_block(1);
+        }
+
+        // Half of the decay period went by, so half of the escrowed burn is refunded
+        step_block(49);
+        let balance_before = get_balance(3);
+        assert_ok!(ChainMod::deregister(get_origin(3), netuid));
+        assert_eq!(get_balance(3), balance_before + to_nano(5) / 2);
+        assert_eq!(RegistrationBurnEscrow::<Test>::get(netuid, 3), None);
+        assert_eq!(Balances::reserved_balance(3), 0);
+
+        // Pruned modules forfeit their escrow
+        MaxAllowedUids::<Test>::set(netuid, 2);
+        Burn::<Test>::insert(netuid, to_nano(10));
+        add_balance(4, to_nano(10));
+        let balance_before = get_balance(1);
+        assert_ok!(register_module(netuid, 4, 0, false));
+        assert!(!ChainMod::key_registered(netuid, &1));
+        assert_eq!(RegistrationBurnEscrow::<Test>::get(netuid, 1), None);
+        assert_eq!(get_balance(1), balance_before);
+        assert_eq!(Balances::reserved_balance(1), 0);
+    });
+}
+
+#[test]
+fn registration_escrow_is_destroyed_with_its_subnet() {
+    new_test_ext().execute_with(|| {
+        MinimumAllowedStake::<Test>::set(0);
+
+        let netuid = 1;
+        assert_ok!(register_subnet(0, netuid));
+        ModuleBurnConfig::<Test>::mutate(netuid, |config| {
+            config.refund_curve = Some(BurnRefundCurve {
+                initial_refund: Percent::from_percent(50),
+                decay_period: 100,
+            });
+        });
+        Burn::<Test>::insert(netuid, to_nano(10));
+        add_balance(1, to_nano(10));
+
+        assert_ok!(register_module(netuid, 1, 0, false));
+        assert_eq!(Balances::reserved_balance(1), to_nano(5));
+
+        let balance_before = get_balance(1);
+        let issuance_before = Balances::total_issuance();
+        ChainMod::remove_subnet(netuid);
+        ChainMod::cleanup_tombstoned_subnets(Weight::MAX);
+
+        assert_eq!(RegistrationBurnEscrow::<Test>::get(netuid, 1), None);
+        assert_eq!(Balances::reserved_balance(1), 0);
+        assert_eq!(get_balance(1), balance_before);
+        assert_eq!(Balances::total_issuance(), issuance_before - to_nano(5));
+    });
+}
+
+#[test]
+fn module_metadata_keys_are_set_and_cleared_against_a_deposit() {
+    new_test_ext().execute_with(|| {
+        zero_min_burn();
+        MinimumAllowedStake::<Test>::set(0);
+
+        let netuid = 1;
+        let key = 1;
+        assert_ok!(register_subnet(0, netuid));
+        Burn::<Test>::insert(netuid, 0);
+        assert_ok!(register_module(netuid, key, 0, false));
+        StorageDepositPerByte::<Test>::set(1_000);
+        add_balance(key, to_nano(1));
+        let balance = get_balance(key);
+        let module_deposit = || {
+            let uid = ChainMod::get_uid_for_key(netuid, &key).unwrap();
+            let bytes = ChainMod::module_storage_bytes(netuid, uid, &key);
+            ChainMod::storage_deposit(bytes)
+        };
+
+        let update_metadata = |metadata: MetadataChanges| {
+            ChainMod::update_module(
+                get_origin(key),
+                netuid,
+                b"module1".to_vec(),
+                test_address(30333),
+                None,
+                None,
+                metadata,
+            )
+        };
+
+        let cid = b"QmYwAPJzv5CZsnA625s3Xf2nemtYgPpHdWEz79ojWnPbdG".to_vec();
+        assert_ok!(update_metadata(vec![
+            (MetadataKey::IpfsCid, Some(cid.clone())),
+            (MetadataKey::Website, Some(b"https://example.com".to_vec())),
+        ]));
+        assert_eq!(Metadata::<Test>::get(netuid, key).unwrap().len(), 2);
+        let deposit = module_deposit();
+        assert_eq!(
+            ModuleStorageDeposit::<Test>::get(netuid, key),
+            Some((key, deposit))
+        );
+        assert_eq!(get_balance(key), balance - deposit);
+        assert_eq!(Balances::reserved_balance(key), deposit);
+
+        // Clearing a key keeps the others and releases the difference
+        assert_ok!(update_metadata(vec![(MetadataKey::Website, None)]));
+        let metadata = Metadata::<Test>::get(netuid, key).unwrap();
+        assert_eq!(
+            metadata.into_inner().into_iter().collect::<Vec<_>>(),
+            vec![(MetadataKey::IpfsCid, cid.try_into().unwrap())]
+        );
+        let deposit = module_deposit();
+        assert_eq!(get_balance(key), balance - deposit);
+        assert_eq!(Balances::reserved_balance(key), deposit);
+
+        assert_noop!(
+            update_metadata(vec![(
+                MetadataKey::Custom(b"website".to_vec().try_into().unwrap()),
+                Some(b"https://example.com".to_vec())
+            )]),
+            Error::<Test>::InvalidModuleMetadata
+        );
+        assert_noop!(
+            update_metadata(vec![(MetadataKey::Version, Some(vec![b'1'; 33]))]),
+            Error::<Test>::ModuleMetadataTooLong
+        );
+
+        // Leaving the subnet releases the deposit
+        assert_ok!(ChainMod::deregister(get_origin(key), netuid));
+        assert_eq!(Metadata::<Test>::get(netuid, key), None);
+        assert_eq!(ModuleStorageDeposit::<Test>::get(netuid, key), None);
+        assert_eq!(get_balance(key), balance);
+        assert_eq!(Balances::reserved_balance(key), 0);
+    });
+}
+
+#[test]
+fn module_names_are_indexed_and_unique_up_to_look_alikes() {
+    new_test_ext().execute_with(|| {
+        zero_min_burn();
+        MinimumAllowedStake::<Test>::set(0);
+        MaxRegistrationsPerBlock::<Test>::set(10);
+
+        let netuid = 0;
+        assert_ok!(register_module(netuid, 0, 0, false));
+        Burn::<Test>::insert(netuid, 0);
+
+        // Failed registrations are rolled back, as extrinsics run in their own storage layer
+        let register = |key: u32, name: &[u8]| {
+            with_storage_layer(|| {
+                ChainMod::register(
+                    get_origin(key),
+                    b"test0".to_vec(),
+                    name.to_vec(),
+                    test_address(30333),
+                    key,
+                    vec![],
+                )
+            })
+        };
+
+        assert_ok!(register(1, b"My-Module"));
+        assert_err!(
+            register(2, b"my_module"),
+            Error::<Test>::ModuleNameAlreadyExists
+        );
+        assert_err!(
+            register(2, b"MY-MODULE"),
+            Error::<Test>::ModuleNameAlreadyExists
+        );
+        assert_err!(register(2, b"my module"), Error::<Test>::InvalidModuleName);
+        assert_err!(
+            register(2, "my_modulé".as_bytes()),
+            Error::<Test>::InvalidModuleName
+        );
+        assert_ok!(register(2, b"other.module"));
+
+        assert_eq!(ChainMod::get_uid_for_name(netuid, b"module0"), Some(0));
+        assert_eq!(ChainMod::get_uid_for_name(netuid, b"my_module"), Some(1));
+        assert_eq!(ChainMod::get_uid_for_name(netuid, b"OTHER.module"), Some(2));
+        assert_eq!(ChainMod::get_uid_for_name(netuid, b"missing"), None);
+
+        // Renaming frees the old name
+        assert_ok!(ChainMod::update_module(
+            get_origin(1),
+            netuid,
+            b"renamed".to_vec(),
+            test_address(30333),
+            None,
+            None,
+            vec![],
+        ));
+        assert_eq!(ChainMod::get_uid_for_name(netuid, b"my-module"), None);
+        assert_eq!(ChainMod::get_uid_for_name(netuid, b"renamed"), Some(1));
+        assert_ok!(register(3, b"my_module"));
+
+        // The last module takes the uid of the deregistered one, and its name follows
+        assert_ok!(ChainMod::deregister(get_origin(0), netuid));
+        assert_eq!(ChainMod::get_uid_for_name(netuid, b"module0"), None);
+        assert_eq!(ChainMod::get_uid_for_name(netuid, b"my_module"), Some(0));
+        assert_eq!(ChainMod::get_uid_for_name(netuid, b"other.module"), Some(2));
+        assert_eq!(Name::<Test>::get(netuid, 0), b"my_module".to_vec());
+    });
+}
+
+#[test]
+fn registration_reserves_the_storage_deposit_from_the_caller() {
+    new_test_ext().execute_with(|| {
+        zero_min_burn();
+        MinimumAllowedStake::<Test>::set(0);
+        StorageDepositPerByte::<Test>::set(1_000);
+
+        let netuid = 1;
+        add_balance(0, to_nano(1));
+        assert_ok!(register_subnet(0, netuid));
+        Burn::<Test>::insert(netuid, 0);
+        let subnet_deposit = ChainMod::storage_deposit(ChainMod::subnet_storage_bytes(netuid));
+        assert_eq!(
+            SubnetStorageDeposit::<Test>::get(netuid),
+            Some((0, subnet_deposit))
+        );
+        assert_eq!(Balances::reserved_balance(0), subnet_deposit);
+
+        let register = |key: u32, module_key: u32| {
+            with_storage_layer(|| {
+                ChainMod::register(
+                    get_origin(key),
+                    b"test1".to_vec(),
+                    format!("module{module_key}").into_bytes(),
+                    test_address(30333),
+                    module_key,
+                    vec![],
+                )
+            })
+        };
+
+        // The caller pays, not the module key
+        assert_noop!(
+            register(3, 4),
+            Error::<Test>::NotEnoughBalanceForStorageDeposit
+        );
+        add_balance(1, to_nano(1));
+        assert_ok!(register(1, 2));
+        let uid = ChainMod::get_uid_for_key(netuid, &2).unwrap();
+        let deposit = ChainMod::storage_deposit(ChainMod::module_storage_bytes(netuid, uid, &2));
+        assert!(deposit > 0);
+        assert_eq!(
+            ModuleStorageDeposit::<Test>::get(netuid, 2),
+            Some((1, deposit))
+        );
+        assert_eq!(Balances::reserved_balance(1), deposit);
+        assert_eq!(Balances::reserved_balance(2), 0);
+
+        // Deregistering releases the deposit back to whoever paid it
+        assert_ok!(ChainMod::deregister(get_origin(2), netuid));
+        assert_eq!(ModuleStorageDeposit::<Test>::get(netuid, 2), None);
+        assert_eq!(Balances::reserved_balance(1), 0);
+    });
+}
+
+#[test]
+fn name_index_migration_renames_look_alike_modules() {
+    new_test_ext().execute_with(|| {
+        use frame_support::traits::{OnRuntimeUpgrade, StorageVersion};
+        use pallet_chain::migrations::v21;
+
+        let netuid = 0;
+        let _ = ModuleNameIndex::<Test>::clear(u32::MAX, None);
+        Name::<Test>::insert(netuid, 0, b"Alice".to_vec());
+        Name::<Test>::insert(netuid, 1, b"alice".to_vec());
+        Name::<Test>::insert(netuid, 2, b"my_module".to_vec());
+        Name::<Test>::insert(netuid, 3, b"my-module".to_vec());
+
+        StorageVersion::new(20).put::<ChainMod>();
+        v21::MigrateToV21::<Test>::on_runtime_upgrade();
+        assert_eq!(StorageVersion::get::<ChainMod>(), 21);
+
+        // The lower uid keeps its name, the look-alike gets its uid appended
+        assert_eq!(Name::<Test>::get(netuid, 0), b"Alice".to_vec());
+        assert_eq!(Name::<Test>::get(netuid, 1), b"alice-1".to_vec());
+        assert_eq!(Name::<Test>::get(netuid, 3), b"my-module-3".to_vec());
+        for uid in 0..4 {
+            let name = canonical_module_name(&Name::<Test>::get(netuid, uid));
+            assert_eq!(ModuleNameIndex::<Test>::get(netuid, name), Some(uid));
+        }
+    });
+}
+
+#[test]
+fn deposit_migration_charges_existing_entries_on_their_next_update() {
+    new_test_ext().execute_with(|| {
+        use frame_support::traits::{OnRuntimeUpgrade, StorageVersion};
+        use pallet_chain::migrations::v22;
+
+        zero_min_burn();
+        MinimumAllowedStake::<Test>::set(0);
+
+        // Registered while storage was free, so nothing holds a deposit
+        let netuid = 1;
+        add_balance(0, to_nano(1));
+        add_balance(1, to_nano(1));
+        assert_ok!(register_subnet(0, netuid));
+        Burn::<Test>::insert(netuid, 0);
+        for module_key in [2, 3] {
+            assert_ok!(ChainMod::register(
+                get_origin(1),
+                b"test1".to_vec(),
+                format!("module{module_key}").into_bytes(),
+                test_address(30333),
+                module_key,
+                vec![],
+            ));
+        }
+
+        StorageDepositPerByte::<Test>::set(1_000);
+        add_balance(2, to_nano(1));
+
+        StorageVersion::new(21).put::<ChainMod>();
+        v22::MigrateToV22::<Test>::on_runtime_upgrade();
+        assert_eq!(StorageVersion::get::<ChainMod>(), 22);
+
+        // The upgrade itself doesn't reserve anything
+        assert_eq!(SubnetStorageDeposit::<Test>::get(netuid), None);
+        assert_eq!(ModuleStorageDeposit::<Test>::get(netuid, 2), None);
+        assert_eq!(Balances::reserved_balance(2), 0);
+
+        let update_module = |key: u32| {
+            with_storage_layer(|| {
+                ChainMod::update_module(
+                    get_origin(key),
+                    netuid,
+                    format!("module{key}").into_bytes(),
+                    test_address(30334),
+                    None,
+                    None,
+                    vec![],
+                )
+            })
+        };
+
+        // The next update pays the deposit
+        assert_ok!(update_module(2));
+        let uid = ChainMod::get_uid_for_key(netuid, &2).unwrap();
+        let deposit = ChainMod::storage_deposit(ChainMod::module_storage_bytes(netuid, uid, &2));
+        assert_eq!(
+            ModuleStorageDeposit::<Test>::get(netuid, 2),
+            Some((2, deposit))
+        );
+        assert_eq!(Balances::reserved_balance(2), deposit);
+
+        // A key without balance stays exempt, but can't update the module until it can pay
+        assert_noop!(
+            update_module(3),
+            Error::<Test>::NotEnoughBalanceForStorageDeposit
+        );
+        assert_eq!(ModuleStorageDeposit::<Test>::get(netuid, 3), None);
+        assert!(ChainMod::get_uid_for_key(netuid, &3).is_some());
+    });
+}
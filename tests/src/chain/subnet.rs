@@ -1,5 +1,8 @@
+use std::collections::BTreeMap;
+
 use crate::mock::*;
-use frame_support::assert_err;
+use frame_support::{assert_err, assert_noop};
+use pallet_emission::PendingEmission;
 use pallet_governance::{GovernanceConfiguration, SubnetGovernanceConfig, VoteMode};
 use pallet_chain::*;
 use params::{burn::GeneralBurnConfiguration, subnet::SubnetChangeset};
@@ -46,7 +49,7 @@ fn subnet_update_changes_all_parameter_values() {
         assert_ok!(register_module(netuid, 0, to_nano(10), false));
 
         let params = SubnetParams::<Test> {
-            founder: 1,
+            founder: 0,
             founder_share: 65,
             immunity_period: 3,
             incentive_ratio: 4,
@@ -55,12 +58,20 @@ fn subnet_update_changes_all_parameter_values() {
             min_allowed_weights: 6,
             max_weight_age: 600,
             name: b"test".to_vec().try_into().unwrap(),
-            metadata: Some(b"metadata".to_vec().try_into().unwrap()),
+            metadata: BTreeMap::from([(
+                MetadataKey::Website,
+                b"example.com".to_vec().try_into().unwrap(),
+            )])
+            .try_into()
+            .unwrap(),
             tempo: 300,
             maximum_set_weight_calls_per_epoch: Some(12),
             bonds_ma: 13,
             min_validator_stake: to_nano(50_000),
             max_allowed_validators: Some(18),
+            registration_mode: RegistrationMode::Allowlist,
+            pruning_strategy: PruningStrategy::LowestStake,
+            activity_source: ActivitySource::Heartbeats,
             governance_config: GovernanceConfiguration {
                 proposal_cost: 18,
                 proposal_expiration: 19,
@@ -68,6 +79,7 @@ fn subnet_update_changes_all_parameter_values() {
                 proposal_reward_treasury_allocation: Percent::from_parts(20),
                 max_proposal_reward_treasury_allocation: 21,
                 proposal_reward_interval: 22,
+                enactment_delay: 23,
             },
             module_burn_config: GeneralBurnConfiguration {
                 min_burn: to_nano(15),
@@ -97,9 +109,15 @@ fn subnet_update_changes_all_parameter_values() {
             module_burn_config,
             min_validator_stake,
             max_allowed_validators,
+            registration_mode,
+            pruning_strategy,
+            activity_source,
             governance_config,
         } = params.clone();
 
+        // The founder pays the deposit for the new name and metadata
+        StorageDepositPerByte::<Test>::set(1_000);
+        add_balance(founder, to_nano(1));
         SubnetChangeset::<Test>::update(netuid, params).unwrap().apply(netuid).unwrap();
         assert_eq!(Founder::<Test>::get(netuid), founder);
         assert_eq!(FounderShare::<Test>::get(netuid), founder_share);
@@ -125,13 +143,26 @@ fn subnet_update_changes_all_parameter_values() {
             MaxAllowedValidators::<Test>::get(netuid),
             max_allowed_validators
         );
+        assert_eq!(
+            SubnetRegistrationMode::<Test>::get(netuid),
+            registration_mode
+        );
+        assert_eq!(SubnetPruningStrategy::<Test>::get(netuid), pruning_strategy);
+        assert_eq!(SubnetActivitySource::<Test>::get(netuid), activity_source);
         assert_eq!(
             SubnetGovernanceConfig::<Test>::get(netuid),
             governance_config
         );
         assert_eq!(ChainMod::get_total_subnets(), 1);
         assert_eq!(N::<Test>::get(netuid), 1);
-        assert_eq!(SubnetMetadata::<Test>::get(netuid), metadata);
+        assert_eq!(
+            SubnetStorageDeposit::<Test>::get(netuid),
+            Some((
+                founder,
+                ChainMod::storage_deposit(ChainMod::subnet_storage_bytes(netuid))
+            ))
+        );
+        assert_eq!(SubnetMetadata::<Test>::get(netuid), Some(metadata));
     });
 }
 
@@ -202,12 +233,179 @@ fn removes_subnet_from_storage() {
         assert_eq!(ChainMod::get_total_subnets(), 1);
 
         ChainMod::remove_subnet(netuid);
-        params!(not_exists);
         assert_eq!(ChainMod::get_total_subnets(), 0);
+        assert!(TombstonedSubnets::<Test>::get().contains(&netuid));
+        assert!(!SubnetGaps::<Test>::get().contains(&netuid));
+        assert!(Founder::<Test>::contains_key(netuid));
+
+        step_block(1);
+        params!(not_exists);
+        assert!(!TombstonedSubnets::<Test>::get().contains(&netuid));
         assert!(SubnetGaps::<Test>::get().contains(&netuid));
     });
 }
 
+#[test]
+fn freed_netuid_is_reused_after_cooldown() {
+    new_test_ext().execute_with(|| {
+        zero_min_burn();
+        NetuidReuseCooldown::<Test>::set(10);
+
+        let founder = 0;
+        let register = |name: &str| {
+            ChainMod::add_balance_to_account(&founder, SubnetBurn::<Test>::get() + 1);
+            assert_ok!(ChainMod::register_subnet(
+                get_origin(founder),
+                name.as_bytes().to_vec(),
+                vec![]
+            ));
+            ChainMod::get_netuid_for_name(name.as_bytes()).unwrap()
+        };
+
+        assert_ok!(register_subnet(founder, 0));
+        assert_ok!(register_subnet(founder, 1));
+        assert_eq!(SubnetGeneration::<Test>::get(1), 1);
+
+        ChainMod::remove_subnet(1);
+        step_block(1);
+        assert!(SubnetGaps::<Test>::get().contains(&1));
+
+        // The freed netuid is still cooling down
+        assert_eq!(register("cooling"), 2);
+
+        step_block(10);
+        assert_eq!(register("reused"), 1);
+        assert_eq!(SubnetGeneration::<Test>::get(1), 2);
+        assert!(SubnetGaps::<Test>::get().is_empty());
+
+        assert_err!(
+            SubnetEmissionMod::set_weights_at_generation(
+                get_origin(founder),
+                1,
+                1,
+                vec![0],
+                vec![1]
+            ),
+            Error::<Test>::SubnetGenerationMismatch
+        );
+        assert_err!(
+            SubnetEmissionMod::delegate_weight_control_at_generation(
+                get_origin(founder),
+                1,
+                1,
+                founder
+            ),
+            Error::<Test>::SubnetGenerationMismatch
+        );
+    });
+}
+
+#[test]
+fn stake_does_not_follow_a_reused_netuid() {
+    new_test_ext().execute_with(|| {
+        zero_min_burn();
+        MinimumAllowedStake::<Test>::set(0);
+
+        assert_ok!(register_module(1, 5, to_nano(10), false));
+        ChainMod::remove_subnet(1);
+        step_block(1);
+
+        assert_ok!(register_module(1, 6, to_nano(10), false));
+        assert_eq!(SubnetGeneration::<Test>::get(1), 2);
+
+        // Stake targets module keys, so stake meant for the old module can't reach the new one
+        add_balance(0, to_nano(10));
+        assert_err!(
+            ChainMod::add_stake(get_origin(0), 5, to_nano(1)),
+            Error::<Test>::ModuleDoesNotExist
+        );
+        assert_eq!(ChainMod::get_delegated_stake(&6), to_nano(10));
+    });
+}
+
+#[test]
+fn economic_subnet_changes_apply_after_notice_period() {
+    new_test_ext().execute_with(|| {
+        zero_min_burn();
+        SubnetParamsNoticePeriod::<Test>::set(10);
+
+        let netuid = 1;
+        let founder = 0;
+        assert_ok!(register_subnet(founder, netuid));
+
+        let current = ChainMod::subnet_params(netuid);
+        let founder_share = current.founder_share + 10;
+        let params = SubnetParams {
+            founder_share,
+            tempo: 200,
+            name: b"renamed".to_vec().try_into().unwrap(),
+            ..current.clone()
+        };
+        assert_ok!(ChainMod::do_update_subnet(
+            get_origin(founder),
+            netuid,
+            SubnetChangeset::update(netuid, params).unwrap()
+        ));
+
+        // The name applies at once, the economics only after the notice period
+        assert_eq!(SubnetNames::<Test>::get(netuid), b"renamed".to_vec());
+        assert_eq!(FounderShare::<Test>::get(netuid), current.founder_share);
+        assert_eq!(Tempo::<Test>::get(netuid), current.tempo);
+        assert!(PendingSubnetParams::<Test>::contains_key(netuid));
+
+        step_block(9);
+        assert_eq!(Tempo::<Test>::get(netuid), current.tempo);
+
+        step_block(1);
+        assert_eq!(FounderShare::<Test>::get(netuid), founder_share);
+        assert_eq!(Tempo::<Test>::get(netuid), 200);
+        assert_eq!(SubnetNames::<Test>::get(netuid), b"renamed".to_vec());
+        assert!(!PendingSubnetParams::<Test>::contains_key(netuid));
+    });
+}
+
+#[test]
+fn keeping_the_current_economics_cancels_announced_subnet_changes() {
+    new_test_ext().execute_with(|| {
+        zero_min_burn();
+        SubnetParamsNoticePeriod::<Test>::set(10);
+
+        let netuid = 1;
+        let founder = 0;
+        assert_ok!(register_subnet(founder, netuid));
+
+        let current = ChainMod::subnet_params(netuid);
+        let params = SubnetParams {
+            tempo: 200,
+            ..current.clone()
+        };
+        assert_ok!(ChainMod::do_update_subnet(
+            get_origin(founder),
+            netuid,
+            SubnetChangeset::update(netuid, params).unwrap()
+        ));
+        assert!(PendingSubnetParams::<Test>::contains_key(netuid));
+
+        let params = SubnetParams {
+            name: b"renamed".to_vec().try_into().unwrap(),
+            ..current.clone()
+        };
+        assert_ok!(ChainMod::do_update_subnet(
+            get_origin(founder),
+            netuid,
+            SubnetChangeset::update(netuid, params).unwrap()
+        ));
+        assert_eq!(SubnetNames::<Test>::get(netuid), b"renamed".to_vec());
+        assert!(!PendingSubnetParams::<Test>::contains_key(netuid));
+        System::assert_last_event(RuntimeEvent::ChainMod(
+            pallet_chain::Event::SubnetParamsChangeCancelled(netuid),
+        ));
+
+        step_block(10);
+        assert_eq!(Tempo::<Test>::get(netuid), current.tempo);
+    });
+}
+
 #[test]
 fn update_subnet_verifies_names_uniquiness_integrity() {
     new_test_ext().execute_with(|| {
@@ -221,7 +419,11 @@ fn update_subnet_verifies_names_uniquiness_integrity() {
                 params.founder,
                 params.founder_share,
                 params.name,
-                params.metadata,
+                params
+                    .metadata
+                    .into_iter()
+                    .map(|(key, value)| (key, Some(value.into_inner())))
+                    .collect(),
                 params.immunity_period,
                 params.incentive_ratio,
                 params.max_allowed_uids,
@@ -235,6 +437,9 @@ fn update_subnet_verifies_names_uniquiness_integrity() {
                 params.module_burn_config,
                 params.min_validator_stake,
                 params.max_allowed_validators,
+                params.registration_mode,
+                params.pruning_strategy,
+                params.activity_source,
             )
         };
 
@@ -297,3 +502,138 @@ fn subnet_is_replaced_on_reaching_max_allowed_modules() {
         assert_eq!(max_netuid, 5);
     });
 }
+
+#[test]
+fn founder_transfer_must_be_accepted_by_the_new_founder() {
+    new_test_ext().execute_with(|| {
+        zero_min_burn();
+
+        let netuid = 1;
+        let founder = 0;
+        let new_founder = 1;
+        assert_ok!(register_subnet(founder, netuid));
+        FounderTransferPeriod::<Test>::set(10);
+
+        assert_err!(
+            SubnetChangeset::<Test>::update(
+                netuid,
+                SubnetParams {
+                    founder: new_founder,
+                    ..ChainMod::subnet_params(netuid)
+                }
+            ),
+            Error::<Test>::FounderChangeRequiresTransfer
+        );
+
+        assert_noop!(
+            ChainMod::propose_founder_transfer(get_origin(new_founder), netuid, new_founder),
+            Error::<Test>::NotFounder
+        );
+        assert_ok!(ChainMod::propose_founder_transfer(
+            get_origin(founder),
+            netuid,
+            new_founder
+        ));
+        assert_eq!(Founder::<Test>::get(netuid), founder);
+
+        assert_noop!(
+            ChainMod::accept_founder_transfer(get_origin(2), netuid),
+            Error::<Test>::NotPendingFounder
+        );
+
+        step_block(11);
+        assert_noop!(
+            ChainMod::accept_founder_transfer(get_origin(new_founder), netuid),
+            Error::<Test>::FounderTransferExpired
+        );
+
+        assert_ok!(ChainMod::propose_founder_transfer(
+            get_origin(founder),
+            netuid,
+            new_founder
+        ));
+        assert_ok!(ChainMod::accept_founder_transfer(
+            get_origin(new_founder),
+            netuid
+        ));
+        assert_eq!(Founder::<Test>::get(netuid), new_founder);
+        assert_eq!(PendingFounder::<Test>::get(netuid), None);
+    });
+}
+
+#[test]
+fn dissolved_subnet_is_removed_after_grace_period() {
+    new_test_ext().execute_with(|| {
+        zero_min_burn();
+        MinimumAllowedStake::<Test>::set(0);
+        SubnetDissolutionPeriod::<Test>::set(10);
+
+        let netuid = 1;
+        let founder = 0;
+        let module = 1;
+        assert_ok!(register_module(netuid, founder, to_nano(10), false));
+        assert_ok!(register_module(netuid, module, to_nano(5), false));
+
+        assert_noop!(
+            ChainMod::dissolve_subnet(get_origin(module), netuid),
+            Error::<Test>::NotFounder
+        );
+        assert_ok!(ChainMod::dissolve_subnet(get_origin(founder), netuid));
+        assert_eq!(
+            SubnetStates::<Test>::get(netuid),
+            SubnetState::Dissolving {
+                removal_block: System::block_number() + 10
+            }
+        );
+
+        assert_err!(
+            register_module(netuid, 2, to_nano(5), false),
+            Error::<Test>::SubnetDissolving
+        );
+        assert_noop!(
+            SubnetEmissionMod::set_weights(get_origin(founder), netuid, vec![1], vec![1]),
+            Error::<Test>::SubnetDissolving
+        );
+
+        let balance_before = get_balance(module);
+        step_block(9);
+        assert!(ChainMod::if_subnet_exist(netuid));
+
+        step_block(1);
+        assert!(!ChainMod::if_subnet_exist(netuid));
+        assert_eq!(SubnetStates::<Test>::get(netuid), SubnetState::Active);
+        // The stake of modules only registered on the subnet goes back to the stakers
+        assert!(get_balance(module) >= balance_before + to_nano(5));
+    });
+}
+
+#[test]
+fn dissolving_subnet_pays_out_pending_emission() {
+    new_test_ext().execute_with(|| {
+        zero_min_burn();
+        MinimumAllowedStake::<Test>::set(0);
+        SubnetDissolutionPeriod::<Test>::set(10);
+
+        let netuid = 1;
+        let founder = 0;
+        let module = 1;
+        assert_ok!(register_module(netuid, founder, to_nano(10), false));
+        assert_ok!(register_module(netuid, module, to_nano(5), false));
+        step_block(1);
+
+        let module_uid = ChainMod::get_uid_for_key(netuid, &module).unwrap();
+        assert_ok!(SubnetEmissionMod::set_weights(
+            get_origin(founder),
+            netuid,
+            vec![module_uid],
+            vec![u16::MAX],
+        ));
+
+        // The partial epoch is paid out on the weights set before the dissolution
+        let total_stake = TotalStake::<Test>::get();
+        PendingEmission::<Test>::set(netuid, to_nano(3));
+        assert_ok!(ChainMod::dissolve_subnet(get_origin(founder), netuid));
+        assert_eq!(PendingEmission::<Test>::get(netuid), 0);
+        assert!(TotalStake::<Test>::get() > total_stake);
+    });
+}
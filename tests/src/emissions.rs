@@ -962,16 +962,16 @@ fn test_emission_exploit() {
         network.extend(new_netuid.to_string().as_bytes().to_vec());
         let mut name: Vec<u8> = "module".as_bytes().to_vec();
         name.extend(key.to_string().as_bytes().to_vec());
-        let address: Vec<u8> = "0.0.0.0:30333".as_bytes().to_vec();
+        let address = test_address(30333);
         let origin = get_origin(yuma_badactor_key);
-        let _ = ChainMod::<Test>::register_subnet(origin.clone(), network.clone(), None);
+        let _ = ChainMod::<Test>::register_subnet(origin.clone(), network.clone(), vec![]);
         assert_ok!(ChainMod::<Test>::register(
             origin.clone(),
             network,
             name,
             address,
             yuma_badactor_key,
-            None
+            vec![]
         ));
         assert_ok!(ChainMod::<Test>::add_stake(
             origin,
@@ -1190,7 +1190,7 @@ fn test_subnet_deregistration_based_on_emission() {
 
         let universal_vec = "subnet4".to_string().as_bytes().to_vec();
         add_balance(3, to_nano(3000));
-        let _ = ChainMod::<Test>::do_register_subnet(get_origin(3), universal_vec.clone(), None);
+        let _ = ChainMod::<Test>::do_register_subnet(get_origin(3), universal_vec.clone(), vec![]);
         assert_ok!(ChainMod::<Test>::do_register(
             get_origin(3),
             universal_vec.clone(),
@@ -1359,7 +1359,7 @@ fn weight_setting_delegation() {
             "test:2020".as_bytes().to_vec(),
             None,
             Some(Percent::from_percent(5)),
-            None,
+            vec![],
         )
         .unwrap();
 
@@ -3,13 +3,32 @@
 // ---------
 use crate::mock::*;
 pub use frame_support::{assert_err, assert_noop, assert_ok};
+use frame_support::{
+    traits::{Hooks, OnRuntimeUpgrade, StorageVersion},
+    weights::Weight,
+};
 use pallet_governance::{
-    dao::ApplicationStatus, proposal::get_reward_allocation, Curator, CuratorApplications,
-    DaoTreasuryAddress, Error, GeneralSubnetApplicationCost, GlobalGovernanceConfig, GovernanceApi,
-    ProposalStatus, Proposals, SubnetGovernanceConfig, VoteMode,
+    dao::{ApplicationStatus, CuratorAction},
+    emergency::{MAX_EMERGENCY_PAUSE_DURATION, MAX_GUARDIAN_PAUSE_DURATION},
+    migrations::{v3, v4, v5, v6},
+    proposal::get_reward_allocation,
+    CuratorApplications, CuratorApprovals, DaoTreasuryAddress, Error, GeneralSubnetApplicationCost,
+    GlobalGovernanceConfig, GovernanceApi, GuardianVetoedEnactments, LegitWhitelist,
+    NextProposalId, PauseGuardian, ProposalArchive, ProposalData, ProposalStatus, ProposalVotes,
+    Proposals, ScheduledEnactments, SubnetApplicationCost, SubnetApplications,
+    SubnetGovernanceConfig, SubnetWhitelist, TreasuryGrants, UnrewardedProposalVotes, VoteMode,
+    WhitelistDuration,
 };
+use pallet_emission::SubnetConsensusType;
+use pallet_emission_api::SubnetConsensus;
 use pallet_governance_api::GovernanceConfiguration;
-use pallet_chain::{params::subnet::SubnetChangeset, GlobalParams, SubnetParams};
+use pallet_chain::{
+    params::{metadata::legacy_metadata, subnet::SubnetChangeset},
+    Bonds, Founder, GlobalParams, ModuleAddress, PausableOperation, PausedOperations,
+    PendingFounder, SubnetParams,
+};
+use sp_runtime::Percent;
+use sp_std::collections::btree_set::BTreeSet;
 use substrate_fixed::{types::extra::U32, FixedI128};
 
 fn register(account: AccountId, subnet_id: u16, module: AccountId, stake: u64) {
@@ -20,20 +39,30 @@ fn register(account: AccountId, subnet_id: u16, module: AccountId, stake: u64) {
     let _ = ChainMod::do_register_subnet(
         get_origin(account),
         format!("subnet-{subnet_id}").as_bytes().to_vec(),
-        None,
+        vec![],
     );
 
     assert_ok!(ChainMod::do_register(
         get_origin(account),
         format!("subnet-{subnet_id}").as_bytes().to_vec(),
         format!("module-{module}").as_bytes().to_vec(),
-        format!("address-{account}-{module}").as_bytes().to_vec(),
+        ModuleAddress::Dns {
+            host: format!("address-{account}-{module}").into_bytes(),
+            port: 30333,
+        },
         module,
-        None,
+        vec![],
     ));
     ChainMod::increase_stake(&account, &module, stake);
 }
 
+fn set_curators(members: &[AccountId], threshold: u32) {
+    assert_ok!(GovernanceMod::set_curator_council(
+        members.iter().copied().collect(),
+        threshold
+    ));
+}
+
 #[test]
 fn global_governance_config_validates_parameters_correctly() {
     new_test_ext().execute_with(|| {
@@ -76,7 +105,8 @@ fn global_proposal_validates_parameters() {
                 floor_validator_weight_fee,
                 floor_founder_share,
                 min_weight_stake,
-                curator,
+                curator_council,
+                curator_threshold,
                 general_subnet_application_cost,
                 governance_config,
                 kappa,
@@ -97,7 +127,8 @@ fn global_proposal_validates_parameters() {
                 floor_validator_weight_fee,
                 floor_founder_share,
                 min_weight_stake,
-                curator,
+                curator_council,
+                curator_threshold,
                 governance_config.proposal_cost,
                 governance_config.proposal_expiration,
                 general_subnet_application_cost,
@@ -148,7 +179,7 @@ fn global_custom_proposal_is_accepted_correctly() {
         step_block(100);
 
         assert_eq!(
-            Proposals::<Test>::get(0).unwrap().status,
+            ProposalArchive::<Test>::get(0).unwrap().status,
             ProposalStatus::Accepted {
                 block: 100,
                 stake_for: 10_000_000_000,
@@ -186,7 +217,7 @@ fn subnet_custom_proposal_is_accepted_correctly() {
         step_block(100);
 
         assert_eq!(
-            Proposals::<Test>::get(0).unwrap().status,
+            ProposalArchive::<Test>::get(0).unwrap().status,
             ProposalStatus::Accepted {
                 block: 100,
                 stake_for: 20_000_000_000,
@@ -222,7 +253,7 @@ fn global_proposal_is_refused_correctly() {
         step_block(100);
 
         assert_eq!(
-            Proposals::<Test>::get(0).unwrap().status,
+            ProposalArchive::<Test>::get(0).unwrap().status,
             ProposalStatus::Refused {
                 block: 100,
                 stake_for: 5_000_000_000,
@@ -232,6 +263,71 @@ fn global_proposal_is_refused_correctly() {
     });
 }
 
+#[test]
+fn closed_proposals_are_archived() {
+    new_test_ext().execute_with(|| {
+        zero_min_burn();
+
+        const FOR: u32 = 0;
+        const AGAINST: u32 = 1;
+
+        register(FOR, 0, 0, to_nano(10));
+        register(AGAINST, 0, 1, to_nano(5));
+
+        config(1, 100);
+
+        for _ in 0..2 {
+            assert_ok!(GovernanceMod::do_add_global_custom_proposal(
+                get_origin(0),
+                vec![b'0'; 64]
+            ));
+        }
+        assert_eq!(NextProposalId::<Test>::get(), 2);
+
+        vote(FOR, 0, true);
+        vote(AGAINST, 0, false);
+        assert_eq!(ProposalVotes::<Test>::get(0, FOR), Some(true));
+        assert_eq!(ProposalVotes::<Test>::get(0, AGAINST), Some(false));
+        assert_err!(
+            GovernanceMod::do_vote_proposal(get_origin(FOR), 0, false),
+            Error::<Test>::AlreadyVoted
+        );
+
+        step_block(100);
+
+        assert!(Proposals::<Test>::get(0).is_none());
+        assert_eq!(ProposalVotes::<Test>::iter_prefix(0).count(), 0);
+        assert_eq!(UnrewardedProposalVotes::<Test>::get(0, FOR), to_nano(10));
+        assert_eq!(UnrewardedProposalVotes::<Test>::get(0, AGAINST), to_nano(5));
+
+        let archived = ProposalArchive::<Test>::get(0).unwrap();
+        assert_eq!(archived.proposer, 0);
+        assert_eq!(
+            archived.status,
+            ProposalStatus::Accepted {
+                block: 100,
+                stake_for: to_nano(10),
+                stake_against: to_nano(5),
+            }
+        );
+
+        assert_err!(
+            GovernanceMod::do_vote_proposal(get_origin(FOR), 0, true),
+            Error::<Test>::ProposalClosed
+        );
+        assert_err!(
+            GovernanceMod::do_vote_proposal(get_origin(FOR), 2, true),
+            Error::<Test>::ProposalNotFound
+        );
+
+        assert_ok!(GovernanceMod::do_add_global_custom_proposal(
+            get_origin(0),
+            vec![b'0'; 64]
+        ));
+        assert!(Proposals::<Test>::contains_key(2));
+    });
+}
+
 #[test]
 fn global_params_proposal_accepted() {
     new_test_ext().execute_with(|| {
@@ -253,7 +349,8 @@ fn global_params_proposal_accepted() {
             floor_validator_weight_fee,
             floor_founder_share,
             min_weight_stake,
-            curator,
+            curator_council,
+            curator_threshold,
             general_subnet_application_cost,
             mut governance_config,
             rho,
@@ -276,7 +373,8 @@ fn global_params_proposal_accepted() {
             floor_validator_weight_fee,
             floor_founder_share,
             min_weight_stake,
-            curator,
+            curator_council,
+            curator_threshold,
             governance_config.proposal_cost,
             governance_config.proposal_expiration,
             general_subnet_application_cost,
@@ -324,13 +422,15 @@ fn subnet_params_proposal_accepted() {
             min_allowed_weights,
             max_weight_age,
             name,
-            metadata,
             tempo,
             maximum_set_weight_calls_per_epoch,
             bonds_ma,
             module_burn_config,
             min_validator_stake,
             max_allowed_validators,
+            registration_mode,
+            pruning_strategy,
+            activity_source,
             mut governance_config,
             ..
         } = ChainMod::subnet_params(0);
@@ -344,7 +444,7 @@ fn subnet_params_proposal_accepted() {
             founder,
             founder_share,
             name,
-            metadata,
+            vec![],
             immunity_period,
             incentive_ratio,
             max_allowed_uids,
@@ -358,6 +458,9 @@ fn subnet_params_proposal_accepted() {
             module_burn_config,
             min_validator_stake,
             max_allowed_validators,
+            registration_mode,
+            pruning_strategy,
+            activity_source,
         )
         .unwrap();
 
@@ -371,6 +474,38 @@ fn subnet_params_proposal_accepted() {
     });
 }
 
+#[test]
+fn subnet_params_proposal_requires_an_affordable_storage_deposit() {
+    new_test_ext().execute_with(|| {
+        zero_min_burn();
+
+        const KEY: u32 = 0;
+        const FOUNDER: u32 = 5;
+
+        register(KEY, 0, 0, to_nano(10));
+        config(1, 100);
+        pallet_chain::StorageDepositPerByte::<Test>::set(1_000);
+
+        // The new founder pays the deposit for the new name when the proposal is enacted
+        let proposal = ProposalData::<Test>::SubnetParams {
+            subnet_id: 0,
+            params: SubnetParams {
+                founder: FOUNDER,
+                name: b"renamed-subnet".to_vec().try_into().unwrap(),
+                governance_config: Default::default(),
+                ..ChainMod::subnet_params(0)
+            },
+        };
+        assert_err!(
+            proposal.validate(),
+            pallet_chain::Error::<Test>::NotEnoughBalanceForStorageDeposit
+        );
+
+        add_balance(FOUNDER, to_nano(1));
+        assert_ok!(proposal.validate());
+    });
+}
+
 #[test]
 fn global_proposals_counts_delegated_stake() {
     new_test_ext().execute_with(|| {
@@ -405,7 +540,7 @@ fn global_proposals_counts_delegated_stake() {
         step_block(100);
 
         assert_eq!(
-            Proposals::<Test>::get(0).unwrap().status,
+            ProposalArchive::<Test>::get(0).unwrap().status,
             ProposalStatus::Accepted {
                 block: 100,
                 stake_for: 15_000_000_000,
@@ -458,7 +593,7 @@ fn subnet_proposals_counts_delegated_stake() {
         step_block(100);
 
         assert_eq!(
-            Proposals::<Test>::get(0).unwrap().status,
+            ProposalArchive::<Test>::get(0).unwrap().status,
             ProposalStatus::Accepted {
                 block: 100,
                 stake_for: 30_000_000_000,
@@ -498,129 +633,1241 @@ fn creates_treasury_transfer_proposal_and_transfers() {
     });
 }
 
-/// This test, observes the distribution of governance reward logic over time.
 #[test]
-fn rewards_wont_exceed_treasury() {
+fn batch_proposal_executes_all_actions() {
     new_test_ext().execute_with(|| {
         zero_min_burn();
 
-        // Fill the governance address with 1 mil so we are not limited by the max allocation
-        let amount = to_nano(1_000_000_000);
-        let key = DaoTreasuryAddress::<Test>::get();
-        add_balance(key, amount);
+        add_balance(DaoTreasuryAddress::<Test>::get(), to_nano(10));
+        add_balance(0, to_nano(3));
+        register(0, 0, 0, to_nano(1));
+        config(to_nano(1), 100);
 
-        let governance_config: GovernanceConfiguration = GlobalGovernanceConfig::<Test>::get();
-        let n = 0;
-        let allocation = get_reward_allocation::<Test>(&governance_config, n).unwrap();
+        let mut params = ChainMod::global_params();
+        params.governance_config.proposal_cost = 69_420;
+
+        let actions = vec![
+            ProposalData::GlobalParams(params),
+            ProposalData::TransferDaoTreasury {
+                account: 0,
+                amount: to_nano(5),
+            },
+        ];
+
+        GovernanceMod::add_batch_proposal(get_origin(0), vec![b'0'; 64], actions)
+            .expect("proposal should be created");
         assert_eq!(
-            FixedI128::<U32>::saturating_from_num(allocation),
-            governance_config.max_proposal_reward_treasury_allocation
+            Proposals::<Test>::get(0).unwrap().data.required_stake(),
+            Percent::from_parts(50)
         );
+
+        vote(0, 0, true);
+        step_block(100);
+
+        assert_eq!(GlobalGovernanceConfig::<Test>::get().proposal_cost, 69_420);
+        assert_eq!(get_balance(DaoTreasuryAddress::<Test>::get()), to_nano(5));
+        assert_eq!(get_balance(0), to_nano(8));
     });
 }
 
 #[test]
-fn whitelist_executes_application_correctly() {
+fn batch_proposal_is_atomic() {
     new_test_ext().execute_with(|| {
-        let key = 0;
-        let adding_key = 1;
+        zero_min_burn();
+
+        let treasury = DaoTreasuryAddress::<Test>::get();
+        add_balance(treasury, to_nano(10));
+        add_balance(0, to_nano(3));
+        register(0, 0, 0, to_nano(1));
+        config(to_nano(1), 100);
+
         let mut params = ChainMod::global_params();
-        params.curator = key;
-        assert_ok!(ChainMod::set_global_params(params));
+        params.governance_config.proposal_cost = 69_420;
 
-        let proposal_cost = GeneralSubnetApplicationCost::<Test>::get();
-        let data = "test".as_bytes().to_vec();
+        let actions = vec![
+            ProposalData::GlobalParams(params),
+            ProposalData::TransferDaoTreasury {
+                account: 0,
+                amount: to_nano(5),
+            },
+        ];
 
-        add_balance(key, proposal_cost + 1);
-        // first submit an application
-        let balance_before = ChainMod::get_balance_u64(&key);
+        GovernanceMod::add_batch_proposal(get_origin(0), vec![b'0'; 64], actions)
+            .expect("proposal should be created");
+        vote(0, 0, true);
 
-        assert_ok!(GovernanceMod::add_dao_application(
-            get_origin(key),
-            adding_key,
-            data.clone(),
+        // Drain the treasury so the transfer can't go through when the batch executes.
+        assert_ok!(ChainMod::transfer_balance_to_account(
+            &treasury,
+            &1,
+            to_nano(9)
         ));
 
-        let balance_after = ChainMod::get_balance_u64(&key);
-        assert_eq!(balance_after, balance_before - proposal_cost);
+        step_block(100);
 
-        // Assert that the proposal is initially in the Pending status
-        for (_, value) in CuratorApplications::<Test>::iter() {
-            assert_eq!(value.status, ApplicationStatus::Pending);
-            assert_eq!(value.user_id, adding_key);
-            assert_eq!(value.data, data);
-        }
+        assert_ne!(GlobalGovernanceConfig::<Test>::get().proposal_cost, 69_420);
+        assert_eq!(get_balance(treasury), to_nano(1));
+    });
+}
 
-        // add key to whitelist
-        assert_ok!(GovernanceMod::add_to_whitelist(get_origin(key), adding_key,));
+#[test]
+fn batch_proposal_rejects_invalid_batches() {
+    new_test_ext().execute_with(|| {
+        zero_min_burn();
 
-        let balance_after_accept = ChainMod::get_balance_u64(&key);
+        add_balance(0, to_nano(3));
+        config(to_nano(1), 100);
 
-        assert_eq!(balance_after_accept, balance_before);
+        assert_err!(
+            GovernanceMod::add_batch_proposal(get_origin(0), vec![b'0'; 64], vec![]),
+            Error::<Test>::EmptyBatchProposal
+        );
 
-        // Assert that the proposal is now in the Accepted status
-        for (_, value) in CuratorApplications::<Test>::iter() {
-            assert_eq!(value.status, ApplicationStatus::Accepted);
-            assert_eq!(value.user_id, adding_key);
-            assert_eq!(value.data, data);
-        }
+        let nested = ProposalData::Batch(vec![ProposalData::GlobalCustom].try_into().unwrap());
+        assert_err!(
+            GovernanceMod::add_batch_proposal(
+                get_origin(0),
+                vec![b'0'; 64],
+                vec![ProposalData::GlobalCustom, nested]
+            ),
+            Error::<Test>::NestedBatchProposal
+        );
 
-        assert!(GovernanceMod::is_in_legit_whitelist(&adding_key));
+        assert_err!(
+            GovernanceMod::add_batch_proposal(
+                get_origin(0),
+                vec![b'0'; 64],
+                vec![ProposalData::GlobalCustom; 17]
+            ),
+            Error::<Test>::BatchProposalTooLarge
+        );
     });
 }
 
-// ----------------
-// Registration
-// ----------------
+#[test]
+fn treasury_grant_is_paid_in_tranches() {
+    new_test_ext().execute_with(|| {
+        zero_min_burn();
+
+        let treasury = DaoTreasuryAddress::<Test>::get();
+        add_balance(treasury, to_nano(20));
+        add_balance(0, to_nano(3));
+        register(0, 0, 0, to_nano(1));
+        config(to_nano(1), 100);
+
+        assert_err!(
+            GovernanceMod::add_treasury_grant_proposal(
+                get_origin(0),
+                vec![b'0'; 64],
+                1,
+                to_nano(10),
+                0,
+                100
+            ),
+            Error::<Test>::InvalidTreasuryGrant
+        );
+
+        GovernanceMod::add_treasury_grant_proposal(
+            get_origin(0),
+            vec![b'0'; 64],
+            1,
+            to_nano(10),
+            3,
+            100,
+        )
+        .expect("proposal should be created");
+        vote(0, 0, true);
+
+        step_block(100);
+        assert!(TreasuryGrants::<Test>::contains_key(0));
+        assert_eq!(get_balance(1), 0);
+
+        step_block(100);
+        assert_eq!(get_balance(1), 3_333_333_333);
+
+        step_block(200);
+        assert_eq!(get_balance(1), to_nano(10));
+        assert_eq!(get_balance(treasury), to_nano(10));
+        assert!(!TreasuryGrants::<Test>::contains_key(0));
+    });
+}
 
 #[test]
-fn user_is_removed_from_whitelist() {
+fn treasury_grant_can_be_cancelled() {
     new_test_ext().execute_with(|| {
-        let whitelist_key = 0;
-        let module_key = 1;
-        Curator::<Test>::put(whitelist_key);
+        zero_min_burn();
 
-        let proposal_cost = Test::get_global_governance_configuration().proposal_cost;
-        let data = "test".as_bytes().to_vec();
+        let treasury = DaoTreasuryAddress::<Test>::get();
+        add_balance(treasury, to_nano(20));
+        add_balance(0, to_nano(3));
+        register(0, 0, 0, to_nano(1));
+        config(to_nano(1), 100);
 
-        // apply
-        add_balance(whitelist_key, proposal_cost + 1);
-        // first submit an application
-        assert_ok!(GovernanceMod::add_dao_application(
-            get_origin(whitelist_key),
-            module_key,
-            data.clone(),
-        ));
+        assert_err!(
+            GovernanceMod::add_cancel_treasury_grant_proposal(get_origin(0), vec![b'0'; 64], 0),
+            Error::<Test>::TreasuryGrantNotFound
+        );
 
-        // Add the module_key to the whitelist
-        assert_ok!(GovernanceMod::add_to_whitelist(
-            get_origin(whitelist_key),
-            module_key,
-        ));
-        assert!(GovernanceMod::is_in_legit_whitelist(&module_key));
+        GovernanceMod::add_treasury_grant_proposal(
+            get_origin(0),
+            vec![b'0'; 64],
+            1,
+            to_nano(10),
+            2,
+            100,
+        )
+        .expect("proposal should be created");
+        vote(0, 0, true);
 
-        // Remove the module_key from the whitelist
-        assert_ok!(GovernanceMod::remove_from_whitelist(
-            get_origin(whitelist_key),
-            module_key
-        ));
-        assert!(!GovernanceMod::is_in_legit_whitelist(&module_key));
+        step_block(200);
+        assert_eq!(get_balance(1), to_nano(5));
+
+        GovernanceMod::add_cancel_treasury_grant_proposal(get_origin(0), vec![b'0'; 64], 0)
+            .expect("proposal should be created");
+        vote(0, 1, true);
+
+        step_block(200);
+        assert_eq!(get_balance(1), to_nano(5));
+        assert_eq!(get_balance(treasury), to_nano(15));
+        assert!(!TreasuryGrants::<Test>::contains_key(0));
     });
 }
 
 #[test]
-fn whitelist_curator_must_be_a_valid_key() {
+fn subnet_consensus_change_proposal_migrates_state() {
     new_test_ext().execute_with(|| {
-        let whitelist_key = 0;
-        let invalid_key = 1;
-        let module_key = 2;
-        Curator::<Test>::put(whitelist_key);
+        zero_min_burn();
 
-        // Try to add to whitelist with an invalid curator key
-        assert_noop!(
-            GovernanceMod::add_to_whitelist(get_origin(invalid_key), module_key),
-            Error::<Test>::NotCurator
+        register(0, 0, 0, to_nano(10));
+        register(0, 1, 1, to_nano(10));
+        config(1, 100);
+
+        SubnetConsensusType::<Test>::insert(0, SubnetConsensus::Treasury);
+        Bonds::<Test>::insert(1, 0, vec![(0, 1)]);
+
+        let propose = |subnet_id, consensus| {
+            GovernanceMod::add_subnet_consensus_change_proposal(
+                get_origin(0),
+                vec![b'0'; 64],
+                subnet_id,
+                consensus,
+            )
+        };
+
+        assert_err!(
+            propose(0, SubnetConsensus::Linear),
+            Error::<Test>::CannotRemoveTreasurySubnet
+        );
+        assert_err!(
+            propose(1, SubnetConsensus::Yuma),
+            Error::<Test>::SubnetConsensusUnchanged
+        );
+        assert_err!(
+            propose(2, SubnetConsensus::Linear),
+            Error::<Test>::SubnetConsensusNotFound
+        );
+
+        assert_ok!(propose(1, SubnetConsensus::Linear));
+        vote(0, 0, true);
+        step_block(100);
+
+        assert_eq!(
+            SubnetConsensusType::<Test>::get(1),
+            Some(SubnetConsensus::Linear)
+        );
+        assert_eq!(Bonds::<Test>::iter_prefix(1).count(), 0);
+    });
+}
+
+#[test]
+fn subnet_consensus_change_keeps_a_single_rootnet() {
+    new_test_ext().execute_with(|| {
+        zero_min_burn();
+
+        register(0, 0, 0, to_nano(10));
+        register(0, 1, 1, to_nano(10));
+        config(1, 100);
+
+        SubnetConsensusType::<Test>::insert(0, SubnetConsensus::Root);
+
+        assert_err!(
+            GovernanceMod::add_subnet_consensus_change_proposal(
+                get_origin(0),
+                vec![b'0'; 64],
+                1,
+                SubnetConsensus::Root
+            ),
+            Error::<Test>::RootSubnetAlreadyExists
+        );
+        assert_err!(
+            GovernanceMod::add_subnet_consensus_change_proposal(
+                get_origin(0),
+                vec![b'0'; 64],
+                0,
+                SubnetConsensus::Yuma
+            ),
+            Error::<Test>::CannotRemoveRootSubnet
+        );
+    });
+}
+
+#[test]
+fn subnet_consensus_change_keeps_a_single_treasury_subnet() {
+    new_test_ext().execute_with(|| {
+        zero_min_burn();
+
+        register(0, 0, 0, to_nano(10));
+        register(0, 1, 1, to_nano(10));
+        config(1, 100);
+
+        SubnetConsensusType::<Test>::insert(0, SubnetConsensus::Treasury);
+
+        assert_err!(
+            GovernanceMod::add_subnet_consensus_change_proposal(
+                get_origin(0),
+                vec![b'0'; 64],
+                1,
+                SubnetConsensus::Treasury
+            ),
+            Error::<Test>::TreasurySubnetAlreadyExists
+        );
+    });
+}
+
+#[test]
+fn guardian_can_pause_operations_until_expiry() {
+    new_test_ext().execute_with(|| {
+        zero_min_burn();
+
+        register(0, 0, 0, to_nano(10));
+
+        assert_err!(
+            GovernanceMod::pause_operation(get_origin(1), PausableOperation::Registration, 100),
+            Error::<Test>::NotPauseGuardian
+        );
+
+        PauseGuardian::<Test>::set(Some(1));
+
+        assert_err!(
+            GovernanceMod::pause_operation(get_origin(1), PausableOperation::Registration, 0),
+            Error::<Test>::InvalidPauseDuration
+        );
+        assert_err!(
+            GovernanceMod::pause_operation(
+                get_origin(1),
+                PausableOperation::Registration,
+                MAX_GUARDIAN_PAUSE_DURATION + 1
+            ),
+            Error::<Test>::InvalidPauseDuration
+        );
+
+        assert_ok!(GovernanceMod::pause_operation(
+            get_origin(1),
+            PausableOperation::Registration,
+            100
+        ));
+        assert_err!(
+            ChainMod::do_register(
+                get_origin(0),
+                b"subnet-0".to_vec(),
+                b"module-1".to_vec(),
+                ModuleAddress::Dns {
+                    host: b"address-0-1".to_vec(),
+                    port: 30333,
+                },
+                1,
+                vec![],
+            ),
+            pallet_chain::Error::<Test>::OperationPaused
+        );
+
+        step_block(100);
+        assert!(!ChainMod::is_paused(PausableOperation::Registration));
+        assert!(!PausedOperations::<Test>::contains_key(
+            PausableOperation::Registration
+        ));
+
+        assert_ok!(GovernanceMod::pause_operation(
+            get_origin(1),
+            PausableOperation::Staking,
+            100
+        ));
+        assert_ok!(GovernanceMod::resume_operation(
+            get_origin(1),
+            PausableOperation::Staking
+        ));
+        assert!(!ChainMod::is_paused(PausableOperation::Staking));
+        assert_err!(
+            GovernanceMod::resume_operation(get_origin(1), PausableOperation::Staking),
+            Error::<Test>::OperationNotPaused
+        );
+    });
+}
+
+#[test]
+fn emergency_pause_proposal_is_fast_tracked() {
+    new_test_ext().execute_with(|| {
+        zero_min_burn();
+
+        add_balance(0, to_nano(5));
+        register(0, 0, 0, to_nano(10));
+        config(1, 5_000);
+
+        assert_err!(
+            GovernanceMod::add_emergency_pause_proposal(
+                get_origin(0),
+                vec![b'0'; 64],
+                PausableOperation::Staking,
+                MAX_EMERGENCY_PAUSE_DURATION + 1
+            ),
+            Error::<Test>::InvalidPauseDuration
+        );
+
+        assert_ok!(GovernanceMod::add_emergency_pause_proposal(
+            get_origin(0),
+            vec![b'0'; 64],
+            PausableOperation::Staking,
+            1_000
+        ));
+        assert_ok!(GovernanceMod::add_pause_guardian_proposal(
+            get_origin(0),
+            vec![b'0'; 64],
+            Some(1)
+        ));
+        vote(0, 0, true);
+        vote(0, 1, true);
+
+        step_block(100);
+
+        let pause = ProposalArchive::<Test>::get(0).unwrap();
+        assert!(matches!(pause.status, ProposalStatus::Accepted { .. }));
+        assert!(Proposals::<Test>::get(1).unwrap().is_active());
+        assert_eq!(PauseGuardian::<Test>::get(), None);
+
+        assert!(ChainMod::is_paused(PausableOperation::Staking));
+        assert_err!(
+            ChainMod::do_add_stake(get_origin(0), 0, to_nano(1)),
+            pallet_chain::Error::<Test>::OperationPaused
+        );
+
+        step_block(1_000);
+        assert!(!ChainMod::is_paused(PausableOperation::Staking));
+    });
+}
+
+#[test]
+fn guardian_cannot_resume_governance_pauses() {
+    new_test_ext().execute_with(|| {
+        zero_min_burn();
+
+        add_balance(0, to_nano(5));
+        register(0, 0, 0, to_nano(10));
+        config(1, 5_000);
+        PauseGuardian::<Test>::set(Some(1));
+
+        assert_ok!(GovernanceMod::add_emergency_pause_proposal(
+            get_origin(0),
+            vec![b'0'; 64],
+            PausableOperation::Staking,
+            1_000
+        ));
+        vote(0, 0, true);
+        step_block(100);
+
+        assert!(ChainMod::is_paused(PausableOperation::Staking));
+        assert_noop!(
+            GovernanceMod::resume_operation(get_origin(1), PausableOperation::Staking),
+            Error::<Test>::OperationPausedByGovernance
+        );
+
+        // Once the governance pause is over, the guardian resumes its own extension.
+        assert_ok!(GovernanceMod::pause_operation(
+            get_origin(1),
+            PausableOperation::Staking,
+            MAX_GUARDIAN_PAUSE_DURATION
+        ));
+        step_block(1_000);
+        assert!(ChainMod::is_paused(PausableOperation::Staking));
+        assert_ok!(GovernanceMod::resume_operation(
+            get_origin(1),
+            PausableOperation::Staking
+        ));
+        assert!(!ChainMod::is_paused(PausableOperation::Staking));
+    });
+}
+
+#[test]
+fn accepted_proposals_are_enacted_after_delay() {
+    new_test_ext().execute_with(|| {
+        zero_min_burn();
+
+        add_balance(DaoTreasuryAddress::<Test>::get(), to_nano(10));
+        add_balance(0, to_nano(3));
+        register(0, 0, 0, to_nano(1));
+        config(to_nano(1), 100);
+        GlobalGovernanceConfig::<Test>::mutate(|config| config.enactment_delay = 200);
+
+        assert_ok!(GovernanceMod::add_transfer_dao_treasury_proposal(
+            get_origin(0),
+            vec![b'0'; 64],
+            to_nano(5),
+            0
+        ));
+        vote(0, 0, true);
+
+        step_block(100);
+
+        assert!(matches!(
+            ProposalArchive::<Test>::get(0).unwrap().status,
+            ProposalStatus::Accepted { .. }
+        ));
+        assert!(ScheduledEnactments::<Test>::contains_key(300, 0));
+        assert_eq!(get_balance(DaoTreasuryAddress::<Test>::get()), to_nano(10));
+        assert_eq!(get_balance(0), to_nano(3));
+
+        step_block(199);
+        assert_eq!(get_balance(DaoTreasuryAddress::<Test>::get()), to_nano(10));
+
+        step_block(1);
+        assert!(!ScheduledEnactments::<Test>::contains_key(300, 0));
+        assert_eq!(get_balance(DaoTreasuryAddress::<Test>::get()), to_nano(5));
+        assert_eq!(get_balance(0), to_nano(8));
+    });
+}
+
+#[test]
+fn scheduled_enactments_can_be_vetoed() {
+    new_test_ext().execute_with(|| {
+        zero_min_burn();
+
+        add_balance(DaoTreasuryAddress::<Test>::get(), to_nano(10));
+        add_balance(0, to_nano(3));
+        register(0, 0, 0, to_nano(1));
+        config(to_nano(1), 100);
+        GlobalGovernanceConfig::<Test>::mutate(|config| config.enactment_delay = 200);
+        PauseGuardian::<Test>::set(Some(1));
+
+        assert_ok!(GovernanceMod::add_transfer_dao_treasury_proposal(
+            get_origin(0),
+            vec![b'0'; 64],
+            to_nano(5),
+            0
+        ));
+        vote(0, 0, true);
+        step_block(100);
+
+        assert_noop!(
+            GovernanceMod::veto_enactment(get_origin(0), 0),
+            Error::<Test>::NotPauseGuardian
+        );
+        assert_ok!(GovernanceMod::veto_enactment(get_origin(1), 0));
+        assert_noop!(
+            GovernanceMod::veto_enactment(get_origin(1), 0),
+            Error::<Test>::EnactmentNotScheduled
+        );
+
+        // Vetoes can also be voted on, and are enacted as soon as they pass.
+        assert_ok!(GovernanceMod::add_transfer_dao_treasury_proposal(
+            get_origin(0),
+            vec![b'0'; 64],
+            to_nano(5),
+            0
+        ));
+        vote(0, 1, true);
+        step_block(100);
+        assert!(ScheduledEnactments::<Test>::contains_key(400, 1));
+
+        assert_ok!(GovernanceMod::add_veto_enactment_proposal(
+            get_origin(0),
+            vec![b'0'; 64],
+            1
+        ));
+        vote(0, 2, true);
+        step_block(100);
+        assert!(!ScheduledEnactments::<Test>::contains_key(400, 1));
+
+        step_block(100);
+        assert_eq!(get_balance(DaoTreasuryAddress::<Test>::get()), to_nano(10));
+    });
+}
+
+#[test]
+fn guardian_vetoes_are_held_until_governance_decides() {
+    new_test_ext().execute_with(|| {
+        zero_min_burn();
+
+        add_balance(DaoTreasuryAddress::<Test>::get(), to_nano(10));
+        add_balance(0, to_nano(3));
+        register(0, 0, 0, to_nano(1));
+        config(to_nano(1), 100);
+        GlobalGovernanceConfig::<Test>::mutate(|config| config.enactment_delay = 200);
+        PauseGuardian::<Test>::set(Some(1));
+
+        assert_ok!(GovernanceMod::add_transfer_dao_treasury_proposal(
+            get_origin(0),
+            vec![b'0'; 64],
+            to_nano(5),
+            0
+        ));
+        vote(0, 0, true);
+        step_block(100);
+
+        assert_ok!(GovernanceMod::veto_enactment(get_origin(1), 0));
+        assert!(GuardianVetoedEnactments::<Test>::contains_key(0));
+
+        // Governance can override the veto, and the guardian can't veto the override.
+        assert_ok!(GovernanceMod::add_override_veto_proposal(
+            get_origin(0),
+            vec![b'0'; 64],
+            0
+        ));
+        vote(0, 1, true);
+        step_block(100);
+        assert_noop!(
+            GovernanceMod::veto_enactment(get_origin(1), 1),
+            Error::<Test>::EnactmentNotVetoable
+        );
+
+        // Neither can it veto its own removal.
+        assert_ok!(GovernanceMod::add_pause_guardian_proposal(
+            get_origin(0),
+            vec![b'0'; 64],
+            None
+        ));
+        vote(0, 2, true);
+        step_block(100);
+        assert_noop!(
+            GovernanceMod::veto_enactment(get_origin(1), 2),
+            Error::<Test>::EnactmentNotVetoable
+        );
+
+        step_block(100);
+        assert!(!GuardianVetoedEnactments::<Test>::contains_key(0));
+        assert_eq!(get_balance(DaoTreasuryAddress::<Test>::get()), to_nano(5));
+
+        step_block(100);
+        assert_eq!(PauseGuardian::<Test>::get(), None);
+    });
+}
+
+/// This test, observes the distribution of governance reward logic over time.
+#[test]
+fn rewards_wont_exceed_treasury() {
+    new_test_ext().execute_with(|| {
+        zero_min_burn();
+
+        // Fill the governance address with 1 mil so we are not limited by the max allocation
+        let amount = to_nano(1_000_000_000);
+        let key = DaoTreasuryAddress::<Test>::get();
+        add_balance(key, amount);
+
+        let governance_config: GovernanceConfiguration = GlobalGovernanceConfig::<Test>::get();
+        let n = 0;
+        let allocation = get_reward_allocation::<Test>(&governance_config, n).unwrap();
+        assert_eq!(
+            FixedI128::<U32>::saturating_from_num(allocation),
+            governance_config.max_proposal_reward_treasury_allocation
+        );
+    });
+}
+
+#[test]
+fn whitelist_executes_application_correctly() {
+    new_test_ext().execute_with(|| {
+        let key = 0;
+        let adding_key = 1;
+        let mut params = ChainMod::global_params();
+        params.curator_council = BTreeSet::from([key]).try_into().unwrap();
+        params.curator_threshold = 1;
+        assert_ok!(ChainMod::set_global_params(params));
+
+        let proposal_cost = GeneralSubnetApplicationCost::<Test>::get();
+        let data = "test".as_bytes().to_vec();
+
+        add_balance(key, proposal_cost + 1);
+        // first submit an application
+        let balance_before = ChainMod::get_balance_u64(&key);
+
+        assert_ok!(GovernanceMod::add_dao_application(
+            get_origin(key),
+            adding_key,
+            data.clone(),
+        ));
+
+        let balance_after = ChainMod::get_balance_u64(&key);
+        assert_eq!(balance_after, balance_before - proposal_cost);
+
+        // Assert that the proposal is initially in the Pending status
+        for (_, value) in CuratorApplications::<Test>::iter() {
+            assert_eq!(value.status, ApplicationStatus::Pending);
+            assert_eq!(value.user_id, adding_key);
+            assert_eq!(value.data, data);
+        }
+
+        // add key to whitelist
+        assert_ok!(GovernanceMod::add_to_whitelist(get_origin(key), adding_key,));
+
+        let balance_after_accept = ChainMod::get_balance_u64(&key);
+
+        assert_eq!(balance_after_accept, balance_before);
+
+        // Assert that the proposal is now in the Accepted status
+        for (_, value) in CuratorApplications::<Test>::iter() {
+            assert_eq!(value.status, ApplicationStatus::Accepted);
+            assert_eq!(value.user_id, adding_key);
+            assert_eq!(value.data, data);
+        }
+
+        assert!(GovernanceMod::is_in_legit_whitelist(&adding_key));
+    });
+}
+
+// ----------------
+// Registration
+// ----------------
+
+#[test]
+fn user_is_removed_from_whitelist() {
+    new_test_ext().execute_with(|| {
+        let whitelist_key = 0;
+        let module_key = 1;
+        set_curators(&[whitelist_key], 1);
+
+        let proposal_cost = Test::get_global_governance_configuration().proposal_cost;
+        let data = "test".as_bytes().to_vec();
+
+        // apply
+        add_balance(whitelist_key, proposal_cost + 1);
+        // first submit an application
+        assert_ok!(GovernanceMod::add_dao_application(
+            get_origin(whitelist_key),
+            module_key,
+            data.clone(),
+        ));
+
+        // Add the module_key to the whitelist
+        assert_ok!(GovernanceMod::add_to_whitelist(
+            get_origin(whitelist_key),
+            module_key,
+        ));
+        assert!(GovernanceMod::is_in_legit_whitelist(&module_key));
+
+        // Remove the module_key from the whitelist
+        assert_ok!(GovernanceMod::remove_from_whitelist(
+            get_origin(whitelist_key),
+            module_key
+        ));
+        assert!(!GovernanceMod::is_in_legit_whitelist(&module_key));
+    });
+}
+
+#[test]
+fn whitelist_membership_expires_and_is_swept() {
+    new_test_ext().execute_with(|| {
+        let whitelist_key = 0;
+        let module_key = 1;
+        set_curators(&[whitelist_key], 1);
+        WhitelistDuration::<Test>::set(10);
+
+        let application_cost = GeneralSubnetApplicationCost::<Test>::get();
+        add_balance(whitelist_key, application_cost + 1);
+        assert_ok!(GovernanceMod::add_dao_application(
+            get_origin(whitelist_key),
+            module_key,
+            b"test".to_vec(),
+        ));
+        assert_ok!(GovernanceMod::add_to_whitelist(
+            get_origin(whitelist_key),
+            module_key,
+        ));
+        assert_eq!(LegitWhitelist::<Test>::get(module_key), Some(10));
+        assert!(GovernanceMod::whitelisted_keys().contains(&module_key));
+
+        step_block(10);
+        assert!(!GovernanceMod::is_in_legit_whitelist(&module_key));
+        assert!(!GovernanceMod::whitelisted_keys().contains(&module_key));
+        assert!(LegitWhitelist::<Test>::contains_key(module_key));
+
+        GovernanceMod::on_idle(System::block_number(), Weight::MAX);
+        assert!(!LegitWhitelist::<Test>::contains_key(module_key));
+    });
+}
+
+#[test]
+fn whitelist_membership_can_be_renewed() {
+    new_test_ext().execute_with(|| {
+        let whitelist_key = 0;
+        let module_key = 1;
+        set_curators(&[whitelist_key], 1);
+        WhitelistDuration::<Test>::set(10);
+
+        assert_noop!(
+            GovernanceMod::renew_whitelist(get_origin(whitelist_key), module_key),
+            Error::<Test>::NotWhitelisted
+        );
+
+        let application_cost = GeneralSubnetApplicationCost::<Test>::get();
+        add_balance(whitelist_key, application_cost * 2 + 1);
+        assert_ok!(GovernanceMod::add_dao_application(
+            get_origin(whitelist_key),
+            module_key,
+            b"test".to_vec(),
+        ));
+        assert_ok!(GovernanceMod::add_to_whitelist(
+            get_origin(whitelist_key),
+            module_key,
+        ));
+
+        // curators can extend an active membership
+        step_block(5);
+        assert_ok!(GovernanceMod::renew_whitelist(
+            get_origin(whitelist_key),
+            module_key
+        ));
+        assert_eq!(LegitWhitelist::<Test>::get(module_key), Some(15));
+
+        step_block(10);
+        assert!(!GovernanceMod::is_in_legit_whitelist(&module_key));
+        GovernanceMod::on_idle(System::block_number(), Weight::MAX);
+
+        // once swept, the module has to apply again
+        assert_noop!(
+            GovernanceMod::renew_whitelist(get_origin(whitelist_key), module_key),
+            Error::<Test>::NotWhitelisted
+        );
+        assert_ok!(GovernanceMod::add_dao_application(
+            get_origin(whitelist_key),
+            module_key,
+            b"test".to_vec(),
+        ));
+        assert_ok!(GovernanceMod::add_to_whitelist(
+            get_origin(whitelist_key),
+            module_key,
+        ));
+        assert_eq!(LegitWhitelist::<Test>::get(module_key), Some(25));
+        assert!(GovernanceMod::is_in_legit_whitelist(&module_key));
+    });
+}
+
+#[test]
+fn whitelist_curator_must_be_a_valid_key() {
+    new_test_ext().execute_with(|| {
+        let whitelist_key = 0;
+        let invalid_key = 1;
+        let module_key = 2;
+        set_curators(&[whitelist_key], 1);
+
+        // Try to add to whitelist with an invalid curator key
+        assert_noop!(
+            GovernanceMod::add_to_whitelist(get_origin(invalid_key), module_key),
+            Error::<Test>::NotCurator
+        );
+        assert!(!GovernanceMod::is_in_legit_whitelist(&module_key));
+    });
+}
+
+#[test]
+fn subnet_founder_runs_application_process() {
+    new_test_ext().execute_with(|| {
+        zero_min_burn();
+
+        let founder = 0;
+        let applicant = 1;
+        let module_key = 2;
+        register(founder, 0, founder, to_nano(10));
+
+        assert_noop!(
+            GovernanceMod::set_subnet_application_cost(get_origin(applicant), 0, Some(to_nano(10))),
+            Error::<Test>::NotSubnetFounder
+        );
+        assert_noop!(
+            GovernanceMod::add_subnet_application(
+                get_origin(applicant),
+                0,
+                module_key,
+                b"test".to_vec()
+            ),
+            Error::<Test>::SubnetApplicationsDisabled
+        );
+        assert_ok!(GovernanceMod::set_subnet_application_cost(
+            get_origin(founder),
+            0,
+            Some(to_nano(10))
+        ));
+
+        add_balance(applicant, to_nano(20));
+        assert_err!(
+            ChainMod::do_register(
+                get_origin(applicant),
+                b"subnet-0".to_vec(),
+                b"module-2".to_vec(),
+                ModuleAddress::Dns {
+                    host: b"address-2".to_vec(),
+                    port: 30333,
+                },
+                module_key,
+                vec![],
+            ),
+            pallet_chain::Error::<Test>::ModuleNotApprovedForSubnet
+        );
+
+        let balance_before = get_balance(applicant);
+        assert_ok!(GovernanceMod::add_subnet_application(
+            get_origin(applicant),
+            0,
+            module_key,
+            b"test".to_vec()
+        ));
+        assert_eq!(get_balance(applicant), balance_before - to_nano(10));
+        assert!(SubnetApplications::<Test>::contains_key(0, module_key));
+
+        assert_ok!(GovernanceMod::set_subnet_whitelisted(
+            get_origin(founder),
+            0,
+            module_key,
+            true
+        ));
+        assert_eq!(get_balance(applicant), balance_before);
+        assert!(!SubnetApplications::<Test>::contains_key(0, module_key));
+        assert!(GovernanceMod::is_approved_for_subnet(0, &module_key));
+
+        assert_ok!(ChainMod::do_register(
+            get_origin(applicant),
+            b"subnet-0".to_vec(),
+            b"module-2".to_vec(),
+            ModuleAddress::Dns {
+                host: b"address-2".to_vec(),
+                port: 30333,
+            },
+            module_key,
+            vec![],
+        ));
+
+        assert_ok!(GovernanceMod::set_subnet_whitelisted(
+            get_origin(founder),
+            0,
+            module_key,
+            false
+        ));
+        assert!(!GovernanceMod::is_approved_for_subnet(0, &module_key));
+    });
+}
+
+#[test]
+fn subnet_whitelist_is_voted_on_vote_mode() {
+    new_test_ext().execute_with(|| {
+        zero_min_burn();
+
+        let founder = 0;
+        let applicant = 1;
+        let module_key = 2;
+        register(founder, 0, founder, to_nano(10));
+        SubnetGovernanceConfig::<Test>::mutate(0, |config| config.vote_mode = VoteMode::Vote);
+        config(1, 100);
+
+        assert_noop!(
+            GovernanceMod::set_subnet_application_cost(get_origin(founder), 0, Some(to_nano(10))),
+            Error::<Test>::VoteModeIsNotAuthority
+        );
+
+        assert_ok!(GovernanceMod::add_subnet_application_cost_proposal(
+            get_origin(founder),
+            b"test".to_vec(),
+            0,
+            Some(to_nano(10))
+        ));
+        vote(founder, 0, true);
+        step_block(100);
+        assert_eq!(SubnetApplicationCost::<Test>::get(0), Some(to_nano(10)));
+
+        assert_noop!(
+            GovernanceMod::add_subnet_whitelist_proposal(
+                get_origin(founder),
+                b"test".to_vec(),
+                0,
+                module_key,
+                true
+            ),
+            Error::<Test>::ApplicationNotFound
+        );
+
+        add_balance(applicant, to_nano(20));
+        assert_ok!(GovernanceMod::add_subnet_application(
+            get_origin(applicant),
+            0,
+            module_key,
+            b"test".to_vec()
+        ));
+        assert_ok!(GovernanceMod::add_subnet_whitelist_proposal(
+            get_origin(founder),
+            b"test".to_vec(),
+            0,
+            module_key,
+            true
+        ));
+        vote(founder, 1, true);
+        step_block(100);
+
+        assert!(SubnetWhitelist::<Test>::contains_key(0, module_key));
+        assert!(GovernanceMod::is_approved_for_subnet(0, &module_key));
+    });
+}
+
+#[test]
+fn subnet_founder_transfer_is_voted_on_vote_mode() {
+    new_test_ext().execute_with(|| {
+        zero_min_burn();
+
+        let founder = 0;
+        let new_founder = 1;
+        register(founder, 0, founder, to_nano(10));
+        config(1, 100);
+
+        assert_noop!(
+            GovernanceMod::add_subnet_founder_transfer_proposal(
+                get_origin(founder),
+                b"test".to_vec(),
+                0,
+                new_founder
+            ),
+            Error::<Test>::NotVoteMode
+        );
+
+        SubnetGovernanceConfig::<Test>::mutate(0, |config| config.vote_mode = VoteMode::Vote);
+        assert_ok!(GovernanceMod::add_subnet_founder_transfer_proposal(
+            get_origin(founder),
+            b"test".to_vec(),
+            0,
+            new_founder
+        ));
+        vote(founder, 0, true);
+        step_block(100);
+
+        // The new founder still has to accept the subnet
+        assert_eq!(Founder::<Test>::get(0), founder);
+        assert!(PendingFounder::<Test>::get(0).is_some_and(|(key, _)| key == new_founder));
+
+        assert_ok!(ChainMod::accept_founder_transfer(
+            get_origin(new_founder),
+            0
+        ));
+        assert_eq!(Founder::<Test>::get(0), new_founder);
+    });
+}
+
+#[test]
+fn curator_council_requires_threshold_approvals() {
+    new_test_ext().execute_with(|| {
+        let module_key = 3;
+        set_curators(&[0, 1, 2], 2);
+
+        let application_cost = GeneralSubnetApplicationCost::<Test>::get();
+        add_balance(module_key, application_cost + 1);
+        assert_ok!(GovernanceMod::add_dao_application(
+            get_origin(module_key),
+            module_key,
+            b"test".to_vec(),
+        ));
+
+        assert_ok!(GovernanceMod::add_to_whitelist(get_origin(0), module_key));
+        assert!(!GovernanceMod::is_in_legit_whitelist(&module_key));
+        assert_noop!(
+            GovernanceMod::add_to_whitelist(get_origin(0), module_key),
+            Error::<Test>::CuratorActionAlreadyApproved
+        );
+        assert_noop!(
+            GovernanceMod::add_to_whitelist(get_origin(4), module_key),
+            Error::<Test>::NotCurator
+        );
+
+        assert_ok!(GovernanceMod::add_to_whitelist(get_origin(2), module_key));
+        assert!(GovernanceMod::is_in_legit_whitelist(&module_key));
+        assert!(!CuratorApprovals::<Test>::contains_key(
+            CuratorAction::AddToWhitelist(module_key)
+        ));
+    });
+}
+
+#[test]
+fn curator_council_changes_discard_pending_approvals() {
+    new_test_ext().execute_with(|| {
+        let module_key = 3;
+        set_curators(&[0, 1], 2);
+
+        let application_cost = GeneralSubnetApplicationCost::<Test>::get();
+        add_balance(module_key, application_cost + 1);
+        assert_ok!(GovernanceMod::add_dao_application(
+            get_origin(module_key),
+            module_key,
+            b"test".to_vec(),
+        ));
+        assert_ok!(GovernanceMod::add_to_whitelist(get_origin(0), module_key));
+
+        assert_err!(
+            GovernanceMod::set_curator_council(BTreeSet::from([0, 1]), 3),
+            Error::<Test>::InvalidCuratorCouncil
+        );
+
+        set_curators(&[1, 2], 2);
+        assert!(!CuratorApprovals::<Test>::contains_key(
+            CuratorAction::AddToWhitelist(module_key)
+        ));
+
+        assert_noop!(
+            GovernanceMod::add_to_whitelist(get_origin(0), module_key),
+            Error::<Test>::NotCurator
+        );
+    });
+}
+
+// ----------
+// Migrations
+// ----------
+
+fn old_governance_config() -> v6::old_storage::GovernanceConfiguration {
+    let config = GovernanceConfiguration::default();
+    v6::old_storage::GovernanceConfiguration {
+        proposal_cost: config.proposal_cost,
+        proposal_expiration: config.proposal_expiration,
+        vote_mode: config.vote_mode,
+        proposal_reward_treasury_allocation: config.proposal_reward_treasury_allocation,
+        max_proposal_reward_treasury_allocation: config.max_proposal_reward_treasury_allocation,
+        proposal_reward_interval: config.proposal_reward_interval,
+    }
+}
+
+fn seed_old_proposal(id: u64, data: v4::old_storage::ProposalData<Test>) {
+    v4::old_storage::Proposals::<Test>::insert(
+        id,
+        v4::old_storage::Proposal {
+            id,
+            proposer: 0,
+            expiration_block: 100,
+            data,
+            status: v4::old_storage::ProposalStatus::Open {
+                votes_for: BTreeSet::from([1]).try_into().unwrap(),
+                votes_against: BTreeSet::from([2]).try_into().unwrap(),
+                stake_for: 10,
+                stake_against: 5,
+            },
+            metadata: b"metadata".to_vec().try_into().unwrap(),
+            proposal_cost: 1_000,
+            creation_block: 0,
+        },
+    );
+}
+
+#[test]
+fn migrates_proposals_stored_in_the_old_layout() {
+    new_test_ext().execute_with(|| {
+        let old_params = v4::old_storage::SubnetParams::<Test> {
+            founder: 3,
+            founder_share: 8,
+            immunity_period: 0,
+            incentive_ratio: 50,
+            max_allowed_uids: 420,
+            max_allowed_weights: 420,
+            min_allowed_weights: 1,
+            max_weight_age: 3600,
+            name: b"old-subnet".to_vec().try_into().unwrap(),
+            metadata: Some(b"free-form".to_vec().try_into().unwrap()),
+            tempo: 100,
+            maximum_set_weight_calls_per_epoch: Some(2),
+            bonds_ma: 900_000,
+            module_burn_config: v4::old_storage::GeneralBurnConfiguration {
+                min_burn: 1,
+                max_burn: 2,
+                adjustment_alpha: 3,
+                target_registrations_interval: 4,
+                target_registrations_per_interval: 5,
+                max_registrations_per_interval: 6,
+            },
+            min_validator_stake: 7,
+            max_allowed_validators: None,
+            governance_config: old_governance_config(),
+        };
+        seed_old_proposal(
+            0,
+            v4::old_storage::ProposalData::SubnetParams {
+                subnet_id: 1,
+                params: old_params,
+            },
+        );
+
+        // A proposal that no known layout can decode is dropped without aborting the upgrade
+        frame_support::storage::unhashed::put_raw(
+            &Proposals::<Test>::hashed_key_for(1),
+            &[1, 2, 3],
+        );
+
+        StorageVersion::new(3).put::<GovernanceMod>();
+        v4::MigrateToV4::<Test>::on_runtime_upgrade();
+        v5::MigrateToV5::<Test>::on_runtime_upgrade();
+        v6::MigrateToV6::<Test>::on_runtime_upgrade();
+
+        assert_eq!(StorageVersion::get::<GovernanceMod>(), 6);
+        assert_eq!(NextProposalId::<Test>::get(), 2);
+        assert!(!Proposals::<Test>::contains_key(1));
+
+        let proposal = Proposals::<Test>::get(0).expect("proposal was migrated");
+        assert_eq!(proposal.proposal_cost, 1_000);
+        assert_eq!(ProposalVotes::<Test>::get(0, 1), Some(true));
+        assert_eq!(ProposalVotes::<Test>::get(0, 2), Some(false));
+
+        let ProposalData::SubnetParams { subnet_id, params } = proposal.data else {
+            panic!("proposal data changed kind");
+        };
+        assert_eq!(subnet_id, 1);
+        assert_eq!(params.founder, 3);
+        assert_eq!(params.metadata, legacy_metadata(b"free-form"));
+        assert_eq!(params.module_burn_config.max_registrations_per_interval, 6);
+        assert_eq!(params.module_burn_config.refund_curve, None);
+        assert_eq!(params.registration_mode, Default::default());
+        assert_eq!(params.governance_config, GovernanceConfiguration::default());
+    });
+}
+
+#[test]
+fn migrates_the_curator_of_pending_global_params_proposals() {
+    new_test_ext().execute_with(|| {
+        let params = ChainMod::global_params();
+        let old_params = v4::old_storage::GlobalParams::<Test> {
+            max_name_length: params.max_name_length,
+            min_name_length: params.min_name_length,
+            max_allowed_subnets: params.max_allowed_subnets,
+            max_allowed_modules: params.max_allowed_modules,
+            max_registrations_per_block: params.max_registrations_per_block,
+            max_allowed_weights: params.max_allowed_weights,
+            floor_stake_delegation_fee: params.floor_stake_delegation_fee,
+            floor_validator_weight_fee: params.floor_validator_weight_fee,
+            floor_founder_share: params.floor_founder_share,
+            min_weight_stake: params.min_weight_stake,
+            curator: 7,
+            general_subnet_application_cost: params.general_subnet_application_cost,
+            subnet_immunity_period: params.subnet_immunity_period,
+            governance_config: old_governance_config(),
+            kappa: params.kappa,
+            rho: params.rho,
+        };
+        seed_old_proposal(0, v4::old_storage::ProposalData::GlobalParams(old_params));
+        v3::old_storage::Curator::<Test>::put(7);
+
+        StorageVersion::new(2).put::<GovernanceMod>();
+        v3::MigrateToV3::<Test>::on_runtime_upgrade();
+        v4::MigrateToV4::<Test>::on_runtime_upgrade();
+        v5::MigrateToV5::<Test>::on_runtime_upgrade();
+        v6::MigrateToV6::<Test>::on_runtime_upgrade();
+
+        let proposal = Proposals::<Test>::get(0).expect("proposal was migrated");
+        let ProposalData::GlobalParams(migrated) = proposal.data else {
+            panic!("proposal data changed kind");
+        };
+        assert_eq!(migrated.curator_council.into_inner(), BTreeSet::from([7]));
+        assert_eq!(migrated.curator_threshold, 1);
+        assert_eq!(migrated.kappa, params.kappa);
+        assert_eq!(
+            migrated.governance_config,
+            GovernanceConfiguration::default()
         );
-        assert!(!GovernanceMod::is_in_legit_whitelist(&module_key));
     });
 }
@@ -6,6 +6,7 @@ use frame_support::{
     pallet_prelude::ConstU32,
     parameter_types,
     traits::{ConstBool, ConstU8, Currency, Everything, Get, Hooks},
+    weights::Weight,
     PalletId,
 };
 use frame_system::{
@@ -17,8 +18,8 @@ use pallet_governance_api::*;
 use pallet_emission_api::{SubnetConsensus, SubnetEmissionApi};
 use pallet_chain::{
     params::subnet::SubnetChangeset, Address, DefaultKey, DefaultSubnetParams, Dividends, Emission,
-    Incentive, LastUpdate, MaxRegistrationsPerBlock, Name, StakeFrom, StakeTo, SubnetBurn,
-    SubnetParams, Tempo, TotalStake, Uids, N,
+    Incentive, LastUpdate, MaxRegistrationsPerBlock, ModuleAddress, Name, StakeFrom, StakeTo,
+    StorageDepositPerByte, SubnetBurn, SubnetParams, Tempo, TotalStake, Uids, N,
 };
 use parity_scale_codec::{Decode, Encode};
 use rand::rngs::OsRng;
@@ -184,21 +185,25 @@ impl GovernanceApi<<Test as frame_system::Config>::AccountId> for Test {
     }
 
     fn whitelisted_keys() -> BTreeSet<AccountId> {
-        Default::default()
+        pallet_governance::Pallet::<Test>::whitelisted_keys()
     }
 
-    fn get_curator() -> <Test as frame_system::Config>::AccountId {
-        AccountId::default()
+    fn is_approved_for_subnet(subnet_id: u16, module_key: &AccountId) -> bool {
+        pallet_governance::Pallet::<Test>::is_approved_for_subnet(subnet_id, module_key)
     }
 
-    fn set_curator(_key: &<Test as frame_system::Config>::AccountId) {}
+    fn get_curator_council() -> (BTreeSet<AccountId>, u32) {
+        pallet_governance::Pallet::<Test>::curator_council()
+    }
+
+    fn set_curator_council(members: BTreeSet<AccountId>, threshold: u32) -> DispatchResult {
+        pallet_governance::Pallet::<Test>::set_curator_council(members, threshold)
+    }
 
     fn set_general_subnet_application_cost(_amount: u64) {}
 
-    fn clear_subnet_includes(netuid: u16) {
-        for storage_type in pallet_governance::SubnetIncludes::all() {
-            storage_type.remove_storage::<Test>(netuid);
-        }
+    fn clear_subnet_includes(netuid: u16, limit: u32) -> (u32, bool) {
+        pallet_governance::SubnetIncludes::clear_limited::<Test>(netuid, limit)
     }
 }
 
@@ -207,6 +212,10 @@ impl SubnetEmissionApi<<Test as frame_system::Config>::AccountId> for Test {
         pallet_emission::Pallet::<Test>::get_lowest_emission_netuid(ignore_subnet_immunity)
     }
 
+    fn get_lowest_evictable_netuid() -> Option<u16> {
+        pallet_emission::Pallet::<Test>::get_lowest_evictable_netuid()
+    }
+
     fn set_emission_storage(subnet_id: u16, emission: u64) {
         pallet_emission::Pallet::<Test>::set_emission_storage(subnet_id, emission)
     }
@@ -240,14 +249,23 @@ impl SubnetEmissionApi<<Test as frame_system::Config>::AccountId> for Test {
         pallet_emission::SubnetConsensusType::<Test>::set(netuid, subnet_consensus)
     }
 
+    fn change_subnet_consensus_type(
+        netuid: u16,
+        subnet_consensus: pallet_emission_api::SubnetConsensus,
+    ) {
+        pallet_emission::Pallet::<Test>::change_subnet_consensus_type(netuid, subnet_consensus)
+    }
+
     fn get_weights(netuid: u16, uid: u16) -> Option<Vec<(u16, u16)>> {
         pallet_emission::Weights::<Test>::get(netuid, uid)
     }
 
-    fn clear_subnet_includes(netuid: u16) {
-        for storage_type in pallet_emission::SubnetIncludes::all() {
-            storage_type.remove_storage::<Test>(netuid);
-        }
+    fn distribute_pending_emission(netuid: u16) {
+        pallet_emission::Pallet::<Test>::distribute_pending_emission(netuid)
+    }
+
+    fn clear_subnet_includes(netuid: u16, limit: u32) -> (u32, bool) {
+        pallet_emission::SubnetIncludes::clear_limited::<Test>(netuid, limit)
     }
 
     fn set_weights(
@@ -536,7 +554,8 @@ pub fn new_test_ext() -> sp_io::TestExternalities {
     sp_tracing::try_init_simple();
     let t = frame_system::GenesisConfig::<Test>::default().build_storage().unwrap();
     let mut ext = sp_io::TestExternalities::new(t);
-    ext.execute_with(|| {});
+    // Most tests don't account for storage deposits, the ones that do set the price themselves
+    ext.execute_with(|| StorageDepositPerByte::<Test>::set(0));
     ext
 }
 
@@ -544,7 +563,10 @@ pub fn new_test_ext_with_block(block: u64) -> sp_io::TestExternalities {
     sp_tracing::try_init_simple();
     let t = frame_system::GenesisConfig::<Test>::default().build_storage().unwrap();
     let mut ext = sp_io::TestExternalities::new(t);
-    ext.execute_with(|| System::set_block_number(block));
+    ext.execute_with(|| {
+        System::set_block_number(block);
+        StorageDepositPerByte::<Test>::set(0);
+    });
     ext
 }
 
@@ -552,6 +574,14 @@ pub fn get_origin(key: AccountId) -> RuntimeOrigin {
     <<Test as frame_system::Config>::RuntimeOrigin>::signed(key)
 }
 
+#[allow(dead_code)]
+pub fn test_address(port: u16) -> ModuleAddress {
+    ModuleAddress::Ipv4 {
+        ip: [0, 0, 0, 0],
+        port,
+    }
+}
+
 #[allow(dead_code)]
 pub fn get_total_subnet_balance(netuid: u16) -> u64 {
     let keys = get_keys(netuid);
@@ -568,6 +598,7 @@ pub(crate) fn step_block(n: u16) {
         ChainMod::on_initialize(System::block_number());
         SubnetEmissionMod::on_initialize(System::block_number());
         GovernanceMod::on_initialize(System::block_number());
+        ChainMod::on_idle(System::block_number(), Weight::MAX);
     }
 }
 
@@ -581,6 +612,7 @@ pub(crate) fn run_to_block(n: u64) {
         ChainMod::on_initialize(System::block_number());
         SubnetEmissionMod::on_initialize(System::block_number());
         GovernanceMod::on_initialize(System::block_number());
+        ChainMod::on_idle(System::block_number(), Weight::MAX);
     }
 }
 
@@ -671,7 +703,7 @@ pub fn delegate_register_module(
     let origin = get_origin(key);
     let network = format!("test{netuid}").as_bytes().to_vec();
     let name = format!("module{module_key}").as_bytes().to_vec();
-    let address = "0.0.0.0:30333".as_bytes().to_vec();
+    let address = test_address(30333);
 
     let is_new_subnet = !ChainMod::if_subnet_exist(netuid);
     if is_new_subnet {
@@ -683,8 +715,8 @@ pub fn delegate_register_module(
         ChainMod::add_balance_to_account(&key, stake + 1);
     }
 
-    let _ = ChainMod::register_subnet(origin.clone(), network.clone(), None);
-    let result = ChainMod::register(origin, network, name.clone(), address, module_key, None);
+    let _ = ChainMod::register_subnet(origin.clone(), network.clone(), vec![]);
+    let result = ChainMod::register(origin, network, name.clone(), address, module_key, vec![]);
     ChainMod::increase_stake(&key, &module_key, stake);
 
     log::info!("Register ok module: network: {name:?}, module_key: {module_key} key: {key}");
@@ -736,7 +768,7 @@ pub fn check_subnet_storage(netuid: u16) -> bool {
     }
 
     // length of addresss
-    let address_vector: Vec<Vec<u8>> = Address::<Test>::iter_prefix_values(netuid).collect();
+    let address_vector: Vec<ModuleAddress> = Address::<Test>::iter_prefix_values(netuid).collect();
     if (n as usize) != address_vector.len() {
         return false;
     }
@@ -788,13 +820,13 @@ pub fn register_module(
 
     let network = format!("test{netuid}").as_bytes().to_vec();
     let name = format!("module{key}").as_bytes().to_vec();
-    let address = "0.0.0.0:30333".as_bytes().to_vec();
+    let address = test_address(30333);
 
     let _ = register_subnet(key, netuid);
 
     ChainMod::add_balance_to_account(&key, SubnetBurn::<Test>::get() + 1);
-    let _ = ChainMod::register_subnet(origin.clone(), network.clone(), None);
-    ChainMod::register(origin, network.clone(), name, address, key, None)?;
+    let _ = ChainMod::register_subnet(origin.clone(), network.clone(), vec![]);
+    ChainMod::register(origin, network.clone(), name, address, key, vec![])?;
     ChainMod::increase_stake(&key, &key, stake);
 
     let netuid = ChainMod::get_netuid_for_name(&network).ok_or("netuid is missing")?;
@@ -813,10 +845,10 @@ pub fn register_root_validator(key: AccountId, stake: u64) -> Result<u16, Dispat
     let origin = get_origin(key);
     let network = b"Rootnet".to_vec();
     let name = format!("module{key}").as_bytes().to_vec();
-    let address = "0.0.0.0:30333".as_bytes().to_vec();
+    let address = test_address(30333);
 
-    let _ = ChainMod::register_subnet(origin.clone(), network.clone(), None);
-    ChainMod::register(origin, network.clone(), name, address, key, None)?;
+    let _ = ChainMod::register_subnet(origin.clone(), network.clone(), vec![]);
+    ChainMod::register(origin, network.clone(), name, address, key, vec![])?;
     ChainMod::increase_stake(&key, &key, stake);
 
     let netuid = ChainMod::get_netuid_for_name(&network).ok_or("netuid is missing")?;
@@ -845,6 +877,7 @@ pub fn config(proposal_cost: u64, proposal_expiration: u32) {
         proposal_cost,
         proposal_expiration,
         vote_mode: pallet_governance_api::VoteMode::Vote,
+        enactment_delay: 0,
         ..Default::default()
     });
 }
@@ -111,9 +111,9 @@ fn test_emission() {
                 key_origin,
                 b"Rootnet".to_vec(),
                 format!("test{}", i).as_bytes().to_vec(),
-                b"0.0.0.0:30333".to_vec(),
+                test_address(30333),
                 key_id,
-                None,
+                vec![],
             ));
             ChainMod::increase_stake(&key_id, &key_id, 1000);
         }
@@ -125,15 +125,15 @@ fn test_emission() {
             let _ = ChainMod::register_subnet(
                 key_origin.clone(),
                 format!("net{}", i).as_bytes().to_vec(),
-                None,
+                vec![],
             );
             assert_ok!(ChainMod::register(
                 key_origin,
                 format!("net{}", i).as_bytes().to_vec(),
                 format!("test{}", i).as_bytes().to_vec(),
-                b"0.0.0.0:30333".to_vec(),
+                test_address(30333),
                 key_id,
-                None,
+                vec![],
             ));
             ChainMod::increase_stake(&key_id, &key_id, 1000);
         }